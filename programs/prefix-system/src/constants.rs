@@ -2,25 +2,107 @@ use anchor_lang::prelude::*;
 
 // Seed bytes used for PDA derivations
 pub const FEE_REGISTRY_SEED: &[u8] = b"fee_registry";
-pub const VERIFIERS_SEED: &[u8] = b"verifiers";
+pub const VERIFIERS_SEED: &[u8] = b"verifiers"; // legacy VerifiersList, superseded by VerifierRecord
+pub const VERIFIER_SEED: &[u8] = b"verifier";
+pub const VERIFIER_VAULT_SEED: &[u8] = b"verifier_vault";
 pub const TREASURY_SEED: &[u8] = b"treasury";
 pub const PREFIX_SEED: &[u8] = b"prefix";
+pub const REVIEW_CONFIG_SEED: &[u8] = b"review_config";
+pub const PROTOCOL_CONFIG_SEED: &[u8] = b"protocol_config";
+pub const REVIEW_SEED: &[u8] = b"review";
+pub const APPEAL_SEED: &[u8] = b"appeal";
+pub const RESERVED_SEED: &[u8] = b"reserved";
+pub const SKELETON_SEED: &[u8] = b"skeleton";
+pub const LISTING_SEED: &[u8] = b"listing";
+pub const AUCTION_SEED: &[u8] = b"auction";
+pub const COMMITMENT_SEED: &[u8] = b"commitment";
+pub const TOMBSTONE_SEED: &[u8] = b"tombstone";
+pub const AUDIT_LOG_SEED: &[u8] = b"audit_log";
 
 // Domain limits and sizing constants
+// Prefix length defaults for ProtocolConfig; MAX_PREFIX_LEN is also the storage cap
 pub const MAX_PREFIX_LEN: usize = 12; // A-Z0-9 up to 12
 pub const MIN_PREFIX_LEN: usize = 3;
 pub const MAX_URI_LEN: usize = 255; // conservative cap
 pub const MAX_AUTH_KEYS: usize = 10;
-pub const MAX_VERIFIERS: usize = 256;
+pub const MAX_VERIFIER_LABEL_LEN: usize = 32;
+pub const MAX_REJECTION_NOTE_LEN: usize = 200;
+pub const MAX_SLASH_REASON_LEN: usize = 200;
+pub const MAX_SCOPE_PATTERNS: usize = 4;
+pub const MAX_REVOCATION_REASON_LEN: usize = 100;
+pub const MAX_RESERVATION_REASON_LEN: usize = 100;
 
-// Maximum expiry duration in seconds
+// Sub-prefixes: `PARENT-LABEL` namespaces created by an active parent's owner
+pub const SUB_PREFIX_SEPARATOR: char = '-';
+pub const MAX_SUB_PREFIX_DEPTH: u8 = 2;
+pub const MAX_SUB_PREFIXES: u16 = 16; // direct children per parent
+
+// Intent category bits declared on a prefix (mirrors metadata `intentCategories`)
+pub const INTENT_PAYMENT: u32 = 1 << 0;
+pub const INTENT_STAKE: u32 = 1 << 1;
+pub const INTENT_VOTE: u32 = 1 << 2;
+pub const INTENT_SWAP: u32 = 1 << 3;
+pub const INTENT_MINT: u32 = 1 << 4;
+pub const INTENT_OTHER: u32 = 1 << 5;
+pub const INTENT_ALL: u32 =
+    INTENT_PAYMENT | INTENT_STAKE | INTENT_VOTE | INTENT_SWAP | INTENT_MINT | INTENT_OTHER;
+pub const MAX_QUORUM: usize = 8; // max verifier votes required to decide a prefix
+pub const MAX_QUORUM_TIERS: usize = 4;
+pub const DEFAULT_QUORUM: u8 = 1;
+
+// Review claim window bounds in seconds
+pub const DEFAULT_CLAIM_WINDOW: i64 = 24 * 60 * 60; // 1 day
+pub const MAX_CLAIM_WINDOW: i64 = 7 * 24 * 60 * 60; // 7 days
+
+// Bond in lamports an owner posts to appeal a rejection
+pub const DEFAULT_APPEAL_BOND: u64 = 100_000_000; // 0.1 SOL
+
+// Verifier bonding: minimum stake in lamports and wait before a resigned verifier can withdraw
+pub const DEFAULT_MIN_VERIFIER_BOND: u64 = 1_000_000_000; // 1 SOL
+pub const DEFAULT_UNBONDING_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days
+
+// Review window extensions granted while a verifier waits on the owner
+pub const DEFAULT_NEEDS_INFO_EXTENSION: i64 = 3 * 24 * 60 * 60; // 3 days per request
+pub const DEFAULT_MAX_EXPIRY_EXTENSION: i64 = 7 * 24 * 60 * 60; // 7 days over a prefix's life
+
+// How long an approval stays trusted before re-verification; 0 disables expiry
+pub const DEFAULT_VERIFICATION_VALIDITY: i64 = 365 * 24 * 60 * 60; // 1 year
+
+// Default maximum expiry duration in seconds (ProtocolConfig::max_expiry_duration)
 pub const MAX_EXPIRY_DURATION: u64 = 14 * 24 * 60 * 60; // 14 days
+pub const DEFAULT_MIN_REVIEW_WINDOW: i64 = 24 * 60 * 60; // 1 day
+
+// Longest an admin suspension may run from the moment it is set or extended
+pub const MAX_SUSPENSION_DURATION: i64 = 30 * 24 * 60 * 60; // 30 days
+
+// Permissionless sweeping of abandoned prefixes
+pub const DEFAULT_SWEEP_REWARD: u64 = 1_000_000; // 0.001 SOL, taken from the refunded fee
+pub const DEFAULT_REJECTED_GRACE_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
+
+// Marketplace royalty on prefix sales, in basis points of the price
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const MAX_ROYALTY_BPS: u16 = 1_000; // 10%
+
+// Commit-reveal registration defaults (ProtocolConfig)
+pub const DEFAULT_COMMIT_DEPOSIT: u64 = 10_000_000; // 0.01 SOL, returned on reveal or refund
+pub const DEFAULT_MIN_REVEAL_DELAY: i64 = 60; // 1 minute
+pub const DEFAULT_COMMITMENT_TTL: i64 = 24 * 60 * 60; // 1 day
+
+// Auctions for reserved prefixes
+pub const MIN_AUCTION_DURATION: i64 = 60 * 60; // 1 hour
+pub const MAX_AUCTION_DURATION: i64 = 30 * 24 * 60 * 60; // 30 days
+pub const AUCTION_SNIPE_WINDOW: i64 = 10 * 60; // bids in the last 10 minutes extend the end to 10 minutes out
+pub const AUCTION_SETTLE_WINDOW: i64 = 7 * 24 * 60 * 60; // after this the admin may cancel an unsettled auction
+
+// Transitions kept per prefix in its AuditLog ring buffer
+pub const MAX_AUDIT_ENTRIES: usize = 16;
 
 // Account sizing helpers
 pub const DISCRIMINATOR_SIZE: usize = 8;
 pub const PUBKEY_SIZE: usize = 32;
 pub const BOOL_SIZE: usize = 1;
 pub const U8_SIZE: usize = 1;
+pub const U32_SIZE: usize = 4;
 pub const U64_SIZE: usize = 8;
 pub const I64_SIZE: usize = 8;
 
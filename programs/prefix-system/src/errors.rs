@@ -52,4 +52,207 @@ pub enum ErrorCode {
 
     #[msg("Invalid Ed25519 signature")]
     InvalidEd25519Signature,
+
+    #[msg("Invalid quorum configuration")]
+    InvalidQuorum,
+
+    #[msg("Verifier already voted on this prefix")]
+    AlreadyVoted,
+
+    #[msg("Prefix account is not in the legacy layout")]
+    PrefixAlreadyMigrated,
+
+    #[msg("Verifier already active")]
+    VerifierAlreadyActive,
+
+    #[msg("Invalid verifier label")]
+    InvalidVerifierLabel,
+
+    #[msg("Verifier record of an earlier voter required")]
+    VoterRecordRequired,
+
+    #[msg("Prefix review claimed by another verifier")]
+    ReviewAlreadyClaimed,
+
+    #[msg("Verifier already holds a review claim")]
+    VerifierClaimActive,
+
+    #[msg("No review claim held by this verifier")]
+    ReviewNotClaimed,
+
+    #[msg("Invalid claim window")]
+    InvalidClaimWindow,
+
+    #[msg("Claimed prefix account required")]
+    ClaimedPrefixRequired,
+
+    #[msg("Rejection note too long")]
+    RejectionNoteTooLong,
+
+    #[msg("Verifier took part in the rejection under appeal")]
+    ConflictedAppealResolver,
+
+    #[msg("Verifier bond below required minimum")]
+    InsufficientVerifierBond,
+
+    #[msg("Slash amount exceeds verifier bond")]
+    InvalidSlashAmount,
+
+    #[msg("Slash reason too long")]
+    SlashReasonTooLong,
+
+    #[msg("Verifier bond still unbonding")]
+    BondStillLocked,
+
+    #[msg("Invalid bond policy")]
+    InvalidBondPolicy,
+
+    #[msg("Invalid expiry extension policy")]
+    InvalidExtensionPolicy,
+
+    #[msg("Review extension budget exhausted")]
+    ExtensionBudgetExhausted,
+    #[msg("Invalid verification validity")]
+    InvalidVerificationValidity,
+
+    #[msg("Invalid intent categories")]
+    InvalidIntentCategories,
+
+    #[msg("Invalid verifier scope")]
+    InvalidVerifierScope,
+
+    #[msg("Prefix outside verifier scope")]
+    OutOfVerifierScope,
+
+    #[msg("Prefix is blocked")]
+    PrefixBlocked,
+
+    #[msg("Prefix is reserved for another claimant")]
+    PrefixReserved,
+
+    #[msg("Reservation reason too long")]
+    ReservationReasonTooLong,
+
+    #[msg("Invalid sub-prefix label")]
+    InvalidSubPrefixLabel,
+
+    #[msg("Sub-prefix depth limit reached")]
+    SubPrefixDepthExceeded,
+
+    #[msg("Sub-prefix count limit reached")]
+    SubPrefixLimitReached,
+
+    #[msg("Parent prefix not trusted")]
+    ParentPrefixNotTrusted,
+
+    #[msg("Ancestor prefix account missing")]
+    MissingAncestorAccount,
+
+    #[msg("Invalid protocol config")]
+    InvalidProtocolConfig,
+
+    #[msg("Part before the hyphen is, or is confusable with, a registered prefix; create a sub-prefix instead")]
+    PrefixRootRegistered,
+
+    #[msg("Prefix cannot be swept yet")]
+    SweepNotAllowed,
+
+    #[msg("Review window too short")]
+    ReviewWindowTooShort,
+
+    #[msg("Review window too long")]
+    ReviewWindowTooLong,
+
+    #[msg("Invalid suspension duration")]
+    InvalidSuspensionDuration,
+
+    #[msg("Prefix already suspended")]
+    PrefixAlreadySuspended,
+
+    #[msg("Prefix not suspended")]
+    PrefixNotSuspended,
+
+    #[msg("Freezing key is still an authority key")]
+    FreezingKeyStillAuthorized,
+
+    #[msg("Signer is not an authority key of this prefix")]
+    UnauthorizedAuthorityKey,
+
+    #[msg("Invalid listing price")]
+    InvalidListingPrice,
+
+    #[msg("Listing no longer matches the prefix owner")]
+    StaleListing,
+
+    #[msg("Buyer already owns this prefix")]
+    BuyerIsSeller,
+
+    #[msg("Invalid royalty")]
+    InvalidRoyalty,
+
+    #[msg("Prefix not trusted: inactive, suspended or verification lapsed")]
+    PrefixNotTrusted,
+
+    #[msg("Prefix is listed for sale; delist it first")]
+    PrefixListed,
+
+    #[msg("Prefix has sub-prefixes")]
+    PrefixHasSubPrefixes,
+
+    #[msg("Invalid auction parameters")]
+    InvalidAuctionParams,
+
+    #[msg("Only reserved prefixes can be auctioned")]
+    PrefixNotReservedForAuction,
+
+    #[msg("Auction has ended")]
+    AuctionEnded,
+
+    #[msg("Auction has not ended")]
+    AuctionNotEnded,
+
+    #[msg("Bid too low")]
+    BidTooLow,
+
+    #[msg("Outbid bidder account mismatch")]
+    InvalidPreviousBidder,
+
+    #[msg("Auction has bids")]
+    AuctionHasBids,
+
+    #[msg("Auction has no winning bid")]
+    AuctionNoBids,
+
+    #[msg("Highest bidder account mismatch")]
+    HighestBidderMismatch,
+
+    #[msg("Direct submission disabled; use commit and reveal")]
+    CommitRevealRequired,
+
+    #[msg("Revealed prefix does not match the commitment")]
+    CommitmentMismatch,
+
+    #[msg("Commitment cannot be revealed yet")]
+    RevealTooEarly,
+
+    #[msg("Commitment expired")]
+    CommitmentExpired,
+
+    #[msg("Commitment has not expired")]
+    CommitmentNotExpired,
+
+    #[msg("Invalid commit policy")]
+    InvalidCommitPolicy,
+
+    #[msg("Prefix was revoked and cannot be registered yet")]
+    PrefixRevoked,
+
+    #[msg("Revocation reason too long")]
+    RevocationReasonTooLong,
+
+    #[msg("Invalid revocation cooldown")]
+    InvalidRevocationCooldown,
+
+    #[msg("Verifier record of the review claim holder required")]
+    ClaimHolderRequired,
 }
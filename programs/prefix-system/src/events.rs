@@ -1,8 +1,10 @@
+use crate::state::{RejectionReason, ReservationKind, SuspensionReason};
 use anchor_lang::prelude::*;
 
 #[event]
 pub struct PrefixSubmitted {
     pub prefix: String,
+    pub skeleton: String,
     pub owner: Pubkey,
     pub metadata_hash: [u8; 32],
     pub metadata_uri: String,
@@ -14,8 +16,10 @@ pub struct PrefixSubmitted {
 #[event]
 pub struct PrefixApproved {
     pub prefix: String,
+    pub skeleton: String,
     pub verifier: Pubkey,
     pub ref_hash: [u8; 32],
+    pub verified_until: i64,
     pub approved_at: i64,
 }
 
@@ -23,6 +27,8 @@ pub struct PrefixApproved {
 pub struct PrefixRejected {
     pub prefix: String,
     pub verifier: Pubkey,
+    pub reason_code: RejectionReason,
+    pub evidence_hash: Option<[u8; 32]>,
     pub reason: String,
     pub rejected_at: i64,
 }
@@ -61,6 +67,16 @@ pub struct PrefixRefunded {
     pub refunded_at: i64,
 }
 
+#[event]
+pub struct PrefixSwept {
+    pub prefix: String,
+    pub owner: Pubkey,
+    pub caller: Pubkey,
+    pub refund: u64,
+    pub reward: u64,
+    pub swept_at: i64,
+}
+
 #[event]
 pub struct TreasuryWithdraw {
     pub admin: Pubkey,
@@ -73,6 +89,7 @@ pub struct TreasuryWithdraw {
 pub struct VerifierAdded {
     pub admin: Pubkey,
     pub verifier: Pubkey,
+    pub label: String,
     pub added_at: i64,
 }
 
@@ -105,6 +122,60 @@ pub struct PrefixReactivated {
     pub at: i64,
 }
 
+#[event]
+pub struct PrefixPausedByOwner {
+    pub prefix: String,
+    pub owner: Pubkey,
+    pub at: i64,
+}
+
+#[event]
+pub struct PrefixResumedByOwner {
+    pub prefix: String,
+    pub owner: Pubkey,
+    pub at: i64,
+}
+
+#[event]
+pub struct PrefixFrozen {
+    pub prefix: String,
+    pub authority: Pubkey,
+    pub at: i64,
+}
+
+#[event]
+pub struct PrefixUnfrozen {
+    pub prefix: String,
+    pub owner: Pubkey,
+    pub frozen_by: Pubkey,
+    pub at: i64,
+}
+
+#[event]
+pub struct PrefixSuspended {
+    pub prefix: String,
+    pub admin: Pubkey,
+    pub reason: SuspensionReason,
+    pub evidence_hash: Option<[u8; 32]>,
+    pub suspended_until: i64,
+    pub at: i64,
+}
+
+#[event]
+pub struct PrefixSuspensionExtended {
+    pub prefix: String,
+    pub admin: Pubkey,
+    pub suspended_until: i64,
+    pub at: i64,
+}
+
+#[event]
+pub struct PrefixSuspensionEnded {
+    pub prefix: String,
+    pub admin: Pubkey,
+    pub at: i64,
+}
+
 #[event]
 pub struct PrefixOwnerRecovered {
     pub prefix: String,
@@ -113,3 +184,383 @@ pub struct PrefixOwnerRecovered {
     pub fee_paid: u64,
     pub updated_at: i64,
 }
+
+#[event]
+pub struct ReviewVoteCast {
+    pub prefix: String,
+    pub verifier: Pubkey,
+    pub approve: bool,
+    pub approvals: u8,
+    pub rejections: u8,
+    pub threshold: u8,
+    pub voted_at: i64,
+}
+
+#[event]
+pub struct ReviewQuorumUpdated {
+    pub admin: Pubkey,
+    pub default_quorum: u8,
+    pub tiers_len: u8,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct ConfigInitialized {
+    pub admin: Pubkey,
+    pub config: Pubkey,
+    pub initialized_at: i64,
+}
+
+#[event]
+pub struct PrefixAccountMigrated {
+    pub prefix: String,
+    pub admin: Pubkey,
+    pub migrated_at: i64,
+}
+
+#[event]
+pub struct VerifierMigrated {
+    pub admin: Pubkey,
+    pub verifier: Pubkey,
+    pub activated: bool, // false when an existing record was kept as is
+    pub migrated_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ClaimReleaseReason {
+    Voluntary,
+    Voted,
+    Expired,
+    VerifierRemoved,
+    PrefixRevoked,
+}
+
+#[event]
+pub struct ReviewClaimed {
+    pub prefix: String,
+    pub verifier: Pubkey,
+    pub expires_at: i64,
+    pub claimed_at: i64,
+}
+
+#[event]
+pub struct ReviewReleased {
+    pub prefix: String,
+    pub verifier: Pubkey,
+    pub reason: ClaimReleaseReason,
+    pub released_at: i64,
+}
+
+#[event]
+pub struct ClaimWindowUpdated {
+    pub admin: Pubkey,
+    pub old_window: i64,
+    pub new_window: i64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct PrefixResubmitted {
+    pub prefix: String,
+    pub owner: Pubkey,
+    pub metadata_hash: [u8; 32],
+    pub metadata_uri: String,
+    pub fee_top_up: u64,
+    pub resubmission_count: u16,
+    pub expiry_at: i64,
+    pub resubmitted_at: i64,
+}
+
+#[event]
+pub struct AppealFiled {
+    pub prefix: String,
+    pub owner: Pubkey,
+    pub bond: u64,
+    pub evidence_hash: [u8; 32],
+    pub filed_at: i64,
+}
+
+#[event]
+pub struct AppealResolved {
+    pub prefix: String,
+    pub resolver: Pubkey,
+    pub upheld: bool,
+    pub bond: u64,
+    pub resolved_at: i64,
+}
+
+#[event]
+pub struct AppealBondUpdated {
+    pub admin: Pubkey,
+    pub old_bond: u64,
+    pub new_bond: u64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct VerifierBondDeposited {
+    pub verifier: Pubkey,
+    pub amount: u64,
+    pub total_bond: u64,
+    pub deposited_at: i64,
+}
+
+#[event]
+pub struct VerifierSlashed {
+    pub admin: Pubkey,
+    pub verifier: Pubkey,
+    pub amount: u64,
+    pub remaining_bond: u64,
+    pub reason: String,
+    pub slashed_at: i64,
+}
+
+#[event]
+pub struct VerifierResigned {
+    pub verifier: Pubkey,
+    pub unbonds_at: i64,
+    pub resigned_at: i64,
+}
+
+#[event]
+pub struct VerifierBondWithdrawn {
+    pub verifier: Pubkey,
+    pub amount: u64,
+    pub withdrawn_at: i64,
+}
+
+#[event]
+pub struct VerifierBondPolicyUpdated {
+    pub admin: Pubkey,
+    pub min_verifier_bond: u64,
+    pub unbonding_period: i64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct PrefixInfoRequested {
+    pub prefix: String,
+    pub verifier: Pubkey,
+    pub request_hash: [u8; 32],
+    pub expiry_at: i64,
+    pub requested_at: i64,
+}
+
+#[event]
+pub struct PrefixInfoProvided {
+    pub prefix: String,
+    pub owner: Pubkey,
+    pub request_hash: [u8; 32],
+    pub new_metadata_hash: [u8; 32],
+    pub provided_at: i64,
+}
+
+#[event]
+pub struct ExpiryExtensionPolicyUpdated {
+    pub admin: Pubkey,
+    pub needs_info_extension: i64,
+    pub max_expiry_extension: i64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct PrefixReverified {
+    pub prefix: String,
+    pub verifier: Pubkey,
+    pub verified_until: i64,
+    pub reverified_at: i64,
+}
+
+#[event]
+pub struct VerificationValidityUpdated {
+    pub admin: Pubkey,
+    pub old_validity: i64,
+    pub new_validity: i64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct VerifierScopeUpdated {
+    pub admin: Pubkey,
+    pub verifier: Pubkey,
+    pub category_mask: u32,
+    pub min_len: u8,
+    pub max_len: u8,
+    pub patterns: Vec<String>,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct PrefixReservationAdded {
+    pub admin: Pubkey,
+    pub prefix: String,
+    pub kind: ReservationKind,
+    pub reason: String,
+    pub claimant: Option<Pubkey>,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct PrefixReservationRemoved {
+    pub admin: Pubkey,
+    pub prefix: String,
+    pub removed_at: i64,
+}
+
+#[event]
+pub struct SubPrefixCreated {
+    pub prefix: String,
+    pub skeleton: String,
+    pub parent_prefix: String,
+    pub parent: Pubkey,
+    pub owner: Pubkey,
+    pub depth: u8,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct ProtocolConfigUpdated {
+    pub admin: Pubkey,
+    pub min_prefix_len: u8,
+    pub max_prefix_len: u8,
+    pub allow_hyphen: bool,
+    pub allow_underscore: bool,
+    pub max_expiry_duration: i64,
+    pub min_review_window: i64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct SweepPolicyUpdated {
+    pub admin: Pubkey,
+    pub sweep_reward: u64,
+    pub rejected_grace_period: i64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct ReviewWindowExtended {
+    pub prefix: String,
+    pub verifier: Pubkey,
+    pub extension: i64,
+    pub expiry_at: i64,
+    pub extended_at: i64,
+}
+
+#[event]
+pub struct PrefixListed {
+    pub prefix: String,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub listed_at: i64,
+}
+
+#[event]
+pub struct PrefixDelisted {
+    pub prefix: String,
+    pub seller: Pubkey,
+    pub delisted_by: Pubkey,
+    pub delisted_at: i64,
+}
+
+#[event]
+pub struct PrefixSold {
+    pub prefix: String,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub royalty: u64,
+    pub sold_at: i64,
+}
+
+#[event]
+pub struct RoyaltyUpdated {
+    pub admin: Pubkey,
+    pub old_bps: u16,
+    pub new_bps: u16,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct AuctionOpened {
+    pub prefix: String,
+    pub admin: Pubkey,
+    pub reserve_price: u64,
+    pub min_increment: u64,
+    pub end_at: i64,
+    pub activate_on_settle: bool,
+    pub opened_at: i64,
+}
+
+#[event]
+pub struct AuctionBidPlaced {
+    pub prefix: String,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub outbid: Option<Pubkey>,
+    pub end_at: i64,
+    pub placed_at: i64,
+}
+
+#[event]
+pub struct AuctionSettled {
+    pub prefix: String,
+    pub winner: Pubkey,
+    pub price: u64,
+    pub activated: bool,
+    pub settled_at: i64,
+}
+
+#[event]
+pub struct AuctionCancelled {
+    pub prefix: String,
+    pub admin: Pubkey,
+    pub refunded_bidder: Option<Pubkey>, // winner of an unsettled auction, refunded its bid
+    pub refunded_bid: u64,
+    pub cancelled_at: i64,
+}
+
+#[event]
+pub struct PrefixCommitted {
+    pub owner: Pubkey,
+    pub commitment: [u8; 32],
+    pub deposit: u64,
+    pub reveal_after: i64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct PrefixRevealed {
+    pub prefix: String,
+    pub owner: Pubkey,
+    pub commitment: [u8; 32],
+    pub revealed_at: i64,
+}
+
+#[event]
+pub struct CommitmentRefunded {
+    pub owner: Pubkey,
+    pub commitment: [u8; 32],
+    pub deposit: u64,
+    pub refunded_at: i64,
+}
+
+#[event]
+pub struct CommitPolicyUpdated {
+    pub admin: Pubkey,
+    pub commit_required: bool,
+    pub commit_deposit: u64,
+    pub min_reveal_delay: i64,
+    pub commitment_ttl: i64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct PrefixRevoked {
+    pub prefix: String,
+    pub admin: Pubkey,
+    pub previous_owner: Pubkey,
+    pub reason: String,
+    pub blocked_until: i64,
+    pub revoked_at: i64,
+}
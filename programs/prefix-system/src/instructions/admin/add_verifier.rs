@@ -1,36 +1,55 @@
+use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::state::{FeeRegistry, VerifiersList};
+use crate::state::{FeeRegistry, VerifierRecord};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
+#[instruction(verifier: Pubkey)]
 pub struct AddVerifier<'info> {
+    #[account(mut)]
     pub admin: Signer<'info>,
-    #[account(seeds = [crate::constants::FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
     pub fee_registry: Account<'info, FeeRegistry>,
-    #[account(mut, seeds = [crate::constants::VERIFIERS_SEED], bump = verifiers.bump)]
-    pub verifiers: Account<'info, VerifiersList>,
+    /// Re-adding a removed verifier reuses its existing record
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = VerifierRecord::space(MAX_VERIFIER_LABEL_LEN),
+        seeds = [VERIFIER_SEED, verifier.as_ref()],
+        bump,
+    )]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    pub system_program: Program<'info, System>,
 }
 
-pub fn add_verifier_handler(ctx: Context<AddVerifier>, verifier: Pubkey) -> Result<()> {
+pub fn add_verifier_handler(ctx: Context<AddVerifier>, verifier: Pubkey, label: String) -> Result<()> {
     let fee_registry = &ctx.accounts.fee_registry;
     require_keys_eq!(
         ctx.accounts.admin.key(),
         fee_registry.admin,
         ErrorCode::UnauthorizedAdmin
     );
-
-    let verifiers = &mut ctx.accounts.verifiers;
     require!(
-        !verifiers.verifiers.contains(&verifier),
-        ErrorCode::InvalidPrefixStatus
-    ); // reuse a generic error to keep enum fixed
-    verifiers.verifiers.push(verifier);
-    verifiers.updated_at = Clock::get()?.unix_timestamp;
+        label.len() <= MAX_VERIFIER_LABEL_LEN,
+        ErrorCode::InvalidVerifierLabel
+    );
+
+    let record = &mut ctx.accounts.verifier_record;
+    require!(!record.active, ErrorCode::VerifierAlreadyActive);
+    let now = Clock::get()?.unix_timestamp;
+    record.verifier = verifier;
+    record.label = label;
+    record.active = true;
+    record.added_at = now;
+    record.removed_at = None;
+    record.bump = ctx.bumps.verifier_record;
+    record.clear_claim();
 
     emit!(crate::events::VerifierAdded {
         admin: ctx.accounts.admin.key(),
         verifier,
-        added_at: verifiers.updated_at,
+        label: record.label.clone(),
+        added_at: now,
     });
     Ok(())
 }
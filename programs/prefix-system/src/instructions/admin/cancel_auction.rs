@@ -0,0 +1,66 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::AuctionCancelled;
+use crate::state::{Auction, FeeRegistry, SkeletonGuard};
+use crate::utils::skeleton_prefix;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct CancelAuction<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, close = admin, seeds = [AUCTION_SEED, prefix.as_bytes()], bump = auction.bump)]
+    pub auction: Account<'info, Auction>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [SKELETON_SEED, (skeleton_prefix(&prefix).as_bytes())],
+        bump = skeleton_guard.bump,
+    )]
+    pub skeleton_guard: Account<'info, SkeletonGuard>,
+    /// CHECK: highest bidder, refunded its escrowed bid; required when the auction has bids
+    #[account(mut)]
+    pub bidder: Option<UncheckedAccount<'info>>,
+}
+
+pub fn cancel_auction_handler(ctx: Context<CancelAuction>, prefix: String) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    let now = Clock::get()?.unix_timestamp;
+    let auction = &ctx.accounts.auction;
+    let refunded_bidder = auction.highest_bidder;
+    let refunded_bid = auction.highest_bid;
+    // Once someone has bid, the winner has the settle window to claim the name; only an
+    // auction left unsettled past it can be cancelled, refunding the escrowed bid
+    if let Some(highest_bidder) = refunded_bidder {
+        require!(
+            now > auction.end_at.saturating_add(AUCTION_SETTLE_WINDOW),
+            ErrorCode::AuctionHasBids
+        );
+        let bidder = ctx
+            .accounts
+            .bidder
+            .as_ref()
+            .ok_or(error!(ErrorCode::HighestBidderMismatch))?;
+        require_keys_eq!(bidder.key(), highest_bidder, ErrorCode::HighestBidderMismatch);
+        let auction_info = ctx.accounts.auction.to_account_info();
+        let bidder_info = bidder.to_account_info();
+        **auction_info.lamports.borrow_mut() -= refunded_bid;
+        **bidder_info.lamports.borrow_mut() += refunded_bid;
+    }
+
+    emit!(AuctionCancelled {
+        prefix,
+        admin: ctx.accounts.admin.key(),
+        refunded_bidder,
+        refunded_bid,
+        cancelled_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ProtocolConfig};
+use anchor_lang::prelude::*;
+
+/// Creates the ProtocolConfig PDA on a deployment that was initialized before it existed
+#[derive(Accounts)]
+pub struct InitProtocolConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolConfig::space(),
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_protocol_config_handler(ctx: Context<InitProtocolConfig>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .protocol_config
+        .set_defaults(ctx.bumps.protocol_config, now);
+
+    emit!(crate::events::ConfigInitialized {
+        admin: ctx.accounts.admin.key(),
+        config: ctx.accounts.protocol_config.key(),
+        initialized_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ReviewConfig};
+use anchor_lang::prelude::*;
+
+/// Creates the ReviewConfig PDA on a deployment that was initialized before it existed
+#[derive(Accounts)]
+pub struct InitReviewConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(
+        init,
+        payer = admin,
+        space = ReviewConfig::space(MAX_QUORUM_TIERS),
+        seeds = [REVIEW_CONFIG_SEED],
+        bump,
+    )]
+    pub review_config: Account<'info, ReviewConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_review_config_handler(ctx: Context<InitReviewConfig>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .review_config
+        .set_defaults(ctx.bumps.review_config, now);
+
+    emit!(crate::events::ConfigInitialized {
+        admin: ctx.accounts.admin.key(),
+        config: ctx.accounts.review_config.key(),
+        initialized_at: now,
+    });
+    Ok(())
+}
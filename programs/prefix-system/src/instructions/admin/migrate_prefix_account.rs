@@ -0,0 +1,134 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixAccountMigrated;
+use crate::state::{
+    prefix_account::PrefixStatus, AuditAction, AuditLog, FeeRegistry, PrefixAccount, ReviewConfig,
+    ReviewState, SkeletonGuard,
+};
+use crate::utils::skeleton_prefix;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Grows a PrefixAccount created before the review fields existed to the current layout
+/// and creates the per-prefix accounts that registration now creates alongside it
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct MigratePrefixAccount<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Box<Account<'info, FeeRegistry>>,
+    #[account(seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Box<Account<'info, ReviewConfig>>,
+    /// CHECK: legacy layout cannot be deserialized as PrefixAccount; checked by the handler
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump)]
+    pub prefix_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ReviewState::space(MAX_QUORUM),
+        seeds = [REVIEW_SEED, prefix.as_bytes()],
+        bump,
+    )]
+    pub review_state: Box<Account<'info, ReviewState>>,
+    /// Left to its holder if a confusable prefix already claimed the skeleton
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = SkeletonGuard::space(MAX_PREFIX_LEN),
+        seeds = [SKELETON_SEED, (skeleton_prefix(&prefix).as_bytes())],
+        bump,
+    )]
+    pub skeleton_guard: Box<Account<'info, SkeletonGuard>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::space(MAX_PREFIX_LEN, MAX_AUDIT_ENTRIES),
+        seeds = [AUDIT_LOG_SEED, prefix.as_bytes()],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_prefix_account_handler(
+    ctx: Context<MigratePrefixAccount>,
+    prefix: String,
+) -> Result<()> {
+    let admin = ctx.accounts.admin.key();
+    require_keys_eq!(
+        admin,
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+
+    let info = ctx.accounts.prefix_account.to_account_info();
+    require_keys_eq!(*info.owner, *ctx.program_id, ErrorCode::PrefixAlreadyMigrated);
+    let new_len = PrefixAccount::space(MAX_PREFIX_LEN, MAX_URI_LEN, MAX_AUTH_KEYS);
+    require!(
+        info.data_len() == PrefixAccount::v1_space(MAX_PREFIX_LEN, MAX_URI_LEN, MAX_AUTH_KEYS),
+        ErrorCode::PrefixAlreadyMigrated
+    );
+
+    // The admin funds the extra rent; the appended bytes are zeroed
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(info.lamports());
+    if rent_due > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.admin.to_account_info(),
+            to: info.clone(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, rent_due)?;
+    }
+    info.resize(new_len)?;
+
+    // Zeroed fields decode as "no claim, no rejection, top-level, never suspended";
+    // only the ones without a safe zero value are filled in
+    let now = Clock::get()?.unix_timestamp;
+    let mut acct = PrefixAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    acct.intent_categories = INTENT_OTHER;
+    // Legacy accounts last (re)entered review no later than their last update
+    acct.review_started_at = acct.updated_at;
+    if acct.status == PrefixStatus::Rejected {
+        acct.rejected_at = acct.updated_at;
+    }
+    if acct.status == PrefixStatus::Active {
+        acct.verified_until = ctx.accounts.review_config.verified_until(now);
+    }
+    acct.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    let review = &mut ctx.accounts.review_state;
+    if review.created_at == 0 {
+        review.round = acct.review_round;
+        review.approvals = Vec::new();
+        review.rejections = Vec::new();
+        review.bump = ctx.bumps.review_state;
+        review.created_at = now;
+        review.updated_at = now;
+    }
+
+    let guard = &mut ctx.accounts.skeleton_guard;
+    if guard.prefix.is_empty() {
+        guard.prefix = prefix.clone();
+        guard.bump = ctx.bumps.skeleton_guard;
+    }
+
+    let log = &mut ctx.accounts.audit_log;
+    log.open(&prefix, ctx.bumps.audit_log);
+    log.record(
+        admin,
+        AuditAction::Migrated,
+        Some(acct.status.clone()),
+        Some(acct.owner),
+        &acct,
+    )?;
+
+    emit!(PrefixAccountMigrated {
+        prefix,
+        admin,
+        migrated_at: now,
+    });
+    Ok(())
+}
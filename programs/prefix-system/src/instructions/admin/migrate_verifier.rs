@@ -0,0 +1,72 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, VerifierRecord, VerifiersList};
+use anchor_lang::prelude::*;
+
+/// Moves one entry of the legacy `VerifiersList` into its own `VerifierRecord`
+#[derive(Accounts)]
+#[instruction(verifier: Pubkey)]
+pub struct MigrateVerifier<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [VERIFIERS_SEED], bump = verifiers.bump)]
+    pub verifiers: Account<'info, VerifiersList>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = VerifierRecord::space(MAX_VERIFIER_LABEL_LEN),
+        seeds = [VERIFIER_SEED, verifier.as_ref()],
+        bump,
+    )]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_verifier_handler(ctx: Context<MigrateVerifier>, verifier: Pubkey) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+
+    let verifiers = &mut ctx.accounts.verifiers;
+    let pos = verifiers
+        .verifiers
+        .iter()
+        .position(|v| *v == verifier)
+        .ok_or(error!(ErrorCode::UnauthorizedVerifier))?;
+    verifiers.verifiers.remove(pos);
+    let now = Clock::get()?.unix_timestamp;
+    verifiers.updated_at = now;
+
+    // Only a verifier without a record is activated; an existing record, active or removed
+    // through remove_verifier, wins over the legacy entry, which is dropped either way
+    let record = &mut ctx.accounts.verifier_record;
+    let activated = record.added_at == 0;
+    if activated {
+        record.verifier = verifier;
+        record.label = String::new();
+        record.active = true;
+        record.added_at = now;
+        record.removed_at = None;
+        record.bump = ctx.bumps.verifier_record;
+        record.clear_claim();
+    }
+
+    // The last migration retires the legacy list and returns its rent to the admin
+    if ctx.accounts.verifiers.verifiers.is_empty() {
+        ctx.accounts
+            .verifiers
+            .close(ctx.accounts.admin.to_account_info())?;
+    }
+
+    emit!(crate::events::VerifierMigrated {
+        admin: ctx.accounts.admin.key(),
+        verifier,
+        activated,
+        migrated_at: now,
+    });
+    Ok(())
+}
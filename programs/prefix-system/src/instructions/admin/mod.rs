@@ -2,4 +2,25 @@ pub mod update_fee;
 pub mod add_verifier;
 pub mod remove_verifier;
 pub mod withdraw_treasury;
-pub mod set_pause;
\ No newline at end of file
+pub mod set_pause;
+pub mod set_review_quorum;
+pub mod init_review_config;
+pub mod init_protocol_config;
+pub mod migrate_prefix_account;
+pub mod migrate_verifier;
+pub mod set_claim_window;
+pub mod set_appeal_bond;
+pub mod slash_verifier;
+pub mod set_verifier_bond_policy;
+pub mod set_expiry_extension_policy;
+pub mod set_verification_validity;
+pub mod set_verifier_scope;
+pub mod reserve_prefix;
+pub mod release_reserved_prefix;
+pub mod update_protocol_config;
+pub mod set_sweep_policy;
+pub mod set_royalty;
+pub mod open_auction;
+pub mod cancel_auction;
+pub mod set_commit_policy;
+pub mod revoke_prefix;
\ No newline at end of file
@@ -0,0 +1,96 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::AuctionOpened;
+use crate::state::{Auction, FeeRegistry, ReservationKind, ReservedPrefix, SkeletonGuard};
+use crate::utils::{assert_not_tombstoned, skeleton_prefix};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct OpenAuction<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(
+        seeds = [RESERVED_SEED, (skeleton_prefix(&prefix).as_bytes())],
+        bump = reserved_prefix.bump,
+    )]
+    pub reserved_prefix: Account<'info, ReservedPrefix>,
+    #[account(
+        init,
+        payer = admin,
+        space = Auction::space(),
+        seeds = [AUCTION_SEED, prefix.as_bytes()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+    /// Held for the auctioned name so no confusable (or the name itself) is registered meanwhile
+    #[account(
+        init,
+        payer = admin,
+        space = SkeletonGuard::space(MAX_PREFIX_LEN),
+        seeds = [SKELETON_SEED, (skeleton_prefix(&prefix).as_bytes())],
+        bump,
+    )]
+    pub skeleton_guard: Account<'info, SkeletonGuard>,
+    /// CHECK: revocation tombstone for this prefix's skeleton; may be uninitialized
+    #[account(seeds = [TOMBSTONE_SEED, (skeleton_prefix(&prefix).as_bytes())], bump)]
+    pub tombstone: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn open_auction_handler(
+    ctx: Context<OpenAuction>,
+    prefix: String,
+    reserve_price: u64,
+    min_increment: u64,
+    duration: i64,
+    activate_on_settle: bool,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    require!(
+        ctx.accounts.reserved_prefix.kind == ReservationKind::Reserved
+            && ctx.accounts.reserved_prefix.prefix == prefix,
+        ErrorCode::PrefixNotReservedForAuction
+    );
+    require!(
+        reserve_price > 0
+            && min_increment > 0
+            && (MIN_AUCTION_DURATION..=MAX_AUCTION_DURATION).contains(&duration),
+        ErrorCode::InvalidAuctionParams
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    // A winner could not settle a name still under a revocation cooldown
+    assert_not_tombstoned(&ctx.accounts.tombstone.to_account_info(), ctx.program_id, now)?;
+    let auction = &mut ctx.accounts.auction;
+    auction.prefix = prefix.clone();
+    auction.reserve_price = reserve_price;
+    auction.min_increment = min_increment;
+    auction.highest_bidder = None;
+    auction.highest_bid = 0;
+    auction.start_at = now;
+    auction.end_at = now + duration;
+    auction.activate_on_settle = activate_on_settle;
+    auction.bump = ctx.bumps.auction;
+
+    let guard = &mut ctx.accounts.skeleton_guard;
+    guard.prefix = prefix.clone();
+    guard.bump = ctx.bumps.skeleton_guard;
+
+    emit!(AuctionOpened {
+        prefix,
+        admin: ctx.accounts.admin.key(),
+        reserve_price,
+        min_increment,
+        end_at: auction.end_at,
+        activate_on_settle,
+        opened_at: now,
+    });
+    Ok(())
+}
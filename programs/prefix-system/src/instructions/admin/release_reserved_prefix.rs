@@ -0,0 +1,39 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ReservedPrefix};
+use crate::utils::skeleton_prefix;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct ReleaseReservedPrefix<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [RESERVED_SEED, (skeleton_prefix(&prefix).as_bytes())],
+        bump = reserved_prefix.bump,
+    )]
+    pub reserved_prefix: Account<'info, ReservedPrefix>,
+}
+
+pub fn release_reserved_prefix_handler(
+    ctx: Context<ReleaseReservedPrefix>,
+    prefix: String,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+
+    emit!(crate::events::PrefixReservationRemoved {
+        admin: ctx.accounts.admin.key(),
+        prefix,
+        removed_at: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -1,14 +1,20 @@
+use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::state::{FeeRegistry, VerifiersList};
+use crate::events::{ClaimReleaseReason, ReviewReleased};
+use crate::state::{FeeRegistry, PrefixAccount, VerifierRecord};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
+#[instruction(verifier: Pubkey)]
 pub struct RemoveVerifier<'info> {
     pub admin: Signer<'info>,
-    #[account(seeds = [crate::constants::FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
     pub fee_registry: Account<'info, FeeRegistry>,
-    #[account(mut, seeds = [crate::constants::VERIFIERS_SEED], bump = verifiers.bump)]
-    pub verifiers: Account<'info, VerifiersList>,
+    #[account(mut, seeds = [VERIFIER_SEED, verifier.as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    /// Prefix under the verifier's live review claim, if any
+    #[account(mut)]
+    pub claimed_prefix: Option<Account<'info, PrefixAccount>>,
 }
 
 pub fn remove_verifier_handler(ctx: Context<RemoveVerifier>, verifier: Pubkey) -> Result<()> {
@@ -19,19 +25,37 @@ pub fn remove_verifier_handler(ctx: Context<RemoveVerifier>, verifier: Pubkey) -
         ErrorCode::UnauthorizedAdmin
     );
 
-    let verifiers = &mut ctx.accounts.verifiers;
-    let pos = verifiers
-        .verifiers
-        .iter()
-        .position(|v| *v == verifier)
-        .ok_or(error!(ErrorCode::UnauthorizedVerifier))?;
-    verifiers.verifiers.remove(pos);
-    verifiers.updated_at = Clock::get()?.unix_timestamp;
+    let record = &mut ctx.accounts.verifier_record;
+    require!(record.active, ErrorCode::UnauthorizedVerifier);
+    let now = Clock::get()?.unix_timestamp;
+
+    // Hand a claimed review back to the queue
+    if let Some(claimed) = record.live_claim(now) {
+        let acct = ctx
+            .accounts
+            .claimed_prefix
+            .as_mut()
+            .filter(|a| a.key() == claimed)
+            .ok_or(error!(ErrorCode::ClaimedPrefixRequired))?;
+        if acct.live_claim(now) == Some(verifier) {
+            acct.clear_claim();
+            acct.updated_at = now;
+            emit!(ReviewReleased {
+                prefix: acct.prefix.clone(),
+                verifier,
+                reason: ClaimReleaseReason::VerifierRemoved,
+                released_at: now,
+            });
+        }
+    }
+    record.clear_claim();
+    record.active = false;
+    record.removed_at = Some(now);
 
     emit!(crate::events::VerifierRemoved {
         admin: ctx.accounts.admin.key(),
         verifier,
-        removed_at: verifiers.updated_at,
+        removed_at: now,
     });
     Ok(())
 }
@@ -0,0 +1,66 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ProtocolConfig, ReservationKind, ReservedPrefix};
+use crate::utils::{normalize_prefix, skeleton_prefix};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct ReservePrefix<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = ReservedPrefix::space(MAX_PREFIX_LEN, MAX_RESERVATION_REASON_LEN),
+        seeds = [RESERVED_SEED, (skeleton_prefix(&prefix).as_bytes())],
+        bump,
+    )]
+    pub reserved_prefix: Account<'info, ReservedPrefix>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn reserve_prefix_handler(
+    ctx: Context<ReservePrefix>,
+    prefix: String,
+    kind: ReservationKind,
+    reason: String,
+    claimant: Option<Pubkey>,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    let normalized = normalize_prefix(&prefix, &ctx.accounts.protocol_config)?;
+    require!(prefix == normalized, ErrorCode::InvalidPrefixFormat);
+    require!(
+        reason.len() <= MAX_RESERVATION_REASON_LEN,
+        ErrorCode::ReservationReasonTooLong
+    );
+    // A blocked name has nobody to hand it to
+    let claimant = if kind == ReservationKind::Blocked { None } else { claimant };
+
+    let now = Clock::get()?.unix_timestamp;
+    let reserved = &mut ctx.accounts.reserved_prefix;
+    reserved.prefix = normalized;
+    reserved.kind = kind;
+    reserved.reason = reason;
+    reserved.claimant = claimant;
+    reserved.created_at = now;
+    reserved.bump = ctx.bumps.reserved_prefix;
+
+    emit!(crate::events::PrefixReservationAdded {
+        admin: ctx.accounts.admin.key(),
+        prefix: reserved.prefix.clone(),
+        kind,
+        reason: reserved.reason.clone(),
+        claimant,
+        created_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::{ClaimReleaseReason, PrefixRevoked, ReviewReleased};
+use crate::state::{
+    prefix_account::PrefixStatus, AuditAction, AuditLog, FeeRegistry, Listing, PrefixAccount,
+    ReviewState, SkeletonGuard, Tombstone, VerifierRecord,
+};
+use crate::utils::skeleton_prefix;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct RevokePrefix<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: Recorded prefix owner; receives the rent of the closed accounts
+    #[account(mut, address = prefix_account.owner)]
+    pub owner: UncheckedAccount<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, close = owner, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Box<Account<'info, PrefixAccount>>,
+    #[account(mut, close = owner, seeds = [REVIEW_SEED, prefix.as_bytes()], bump = review_state.bump)]
+    pub review_state: Box<Account<'info, ReviewState>>,
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SKELETON_SEED, (skeleton_prefix(&prefix).as_bytes())],
+        bump = skeleton_guard.bump,
+    )]
+    pub skeleton_guard: Box<Account<'info, SkeletonGuard>>,
+    /// Kept open so the revocation stays on record
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    /// Keyed by skeleton so confusable variants are blocked too; re-revoking a name whose
+    /// earlier cooldown lapsed overwrites the old tombstone
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = Tombstone::space(MAX_PREFIX_LEN, MAX_REVOCATION_REASON_LEN),
+        seeds = [TOMBSTONE_SEED, (skeleton_prefix(&prefix).as_bytes())],
+        bump,
+    )]
+    pub tombstone: Box<Account<'info, Tombstone>>,
+    /// Required for a sub-prefix; its child count is decremented
+    #[account(mut)]
+    pub parent_account: Option<Box<Account<'info, PrefixAccount>>>,
+    /// Required while a verifier holds a live review claim on the prefix; the claim is released
+    #[account(mut, seeds = [VERIFIER_SEED, claim_holder.verifier.as_ref()], bump = claim_holder.bump)]
+    pub claim_holder: Option<Box<Account<'info, VerifierRecord>>>,
+    /// Required while the prefix is listed; closed to its seller
+    #[account(mut, seeds = [LISTING_SEED, prefix.as_bytes()], bump = listing.bump)]
+    pub listing: Option<Box<Account<'info, Listing>>>,
+    /// CHECK: Seller recorded on the listing; receives the listing rent
+    #[account(mut)]
+    pub seller: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn revoke_prefix_handler(
+    ctx: Context<RevokePrefix>,
+    prefix: String,
+    reason: String,
+    cooldown: Option<i64>,
+) -> Result<()> {
+    let admin = ctx.accounts.admin.key();
+    require_keys_eq!(
+        admin,
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    require!(
+        reason.len() <= MAX_REVOCATION_REASON_LEN,
+        ErrorCode::RevocationReasonTooLong
+    );
+    // An open appeal escrows the owner's bond; it has to be resolved first
+    require!(
+        ctx.accounts.prefix_account.status != PrefixStatus::UnderAppeal,
+        ErrorCode::InvalidPrefixStatus
+    );
+
+    // Children would be left pointing at a closed parent; they are revoked first
+    require!(
+        ctx.accounts.prefix_account.sub_prefix_count == 0,
+        ErrorCode::PrefixHasSubPrefixes
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let prefix_key = ctx.accounts.prefix_account.key();
+    if let Some(parent_key) = ctx.accounts.prefix_account.parent {
+        let parent = ctx
+            .accounts
+            .parent_account
+            .as_mut()
+            .ok_or(error!(ErrorCode::MissingAncestorAccount))?;
+        require_keys_eq!(parent.key(), parent_key, ErrorCode::MissingAncestorAccount);
+        parent.sub_prefix_count = parent.sub_prefix_count.saturating_sub(1);
+        parent.updated_at = now;
+    }
+    if let Some(holder) = ctx.accounts.prefix_account.live_claim(now) {
+        let record = ctx
+            .accounts
+            .claim_holder
+            .as_mut()
+            .filter(|r| r.verifier == holder)
+            .ok_or(error!(ErrorCode::ClaimHolderRequired))?;
+        if record.claimed_prefix == Some(prefix_key) {
+            record.clear_claim();
+        }
+        emit!(ReviewReleased {
+            prefix: prefix.clone(),
+            verifier: holder,
+            reason: ClaimReleaseReason::PrefixRevoked,
+            released_at: now,
+        });
+    }
+    if ctx.accounts.prefix_account.listed {
+        let listing = ctx
+            .accounts
+            .listing
+            .as_ref()
+            .ok_or(error!(ErrorCode::PrefixListed))?;
+        let seller = ctx
+            .accounts
+            .seller
+            .as_ref()
+            .ok_or(error!(ErrorCode::PrefixListed))?;
+        require_keys_eq!(seller.key(), listing.seller, ErrorCode::StaleListing);
+        listing.close(seller.to_account_info())?;
+    }
+
+    // None is a permanent block; the fee stays in the treasury either way
+    let blocked_until = match cooldown {
+        Some(cooldown) => {
+            require!(cooldown > 0, ErrorCode::InvalidRevocationCooldown);
+            now.saturating_add(cooldown)
+        }
+        None => i64::MAX,
+    };
+
+    // The prefix account closes; the kept log records it as inactive
+    let acct = &mut ctx.accounts.prefix_account;
+    let old_status = acct.status.clone();
+    let previous_owner = acct.owner;
+    acct.status = PrefixStatus::Inactive;
+    ctx.accounts.audit_log.record(
+        admin,
+        AuditAction::Revoked,
+        Some(old_status),
+        Some(previous_owner),
+        acct,
+    )?;
+
+    let tombstone = &mut ctx.accounts.tombstone;
+    tombstone.prefix = prefix.clone();
+    tombstone.previous_owner = previous_owner;
+    tombstone.reason = reason.clone();
+    tombstone.blocked_until = blocked_until;
+    tombstone.revoked_by = admin;
+    tombstone.created_at = now;
+    tombstone.bump = ctx.bumps.tombstone;
+
+    emit!(PrefixRevoked {
+        prefix,
+        admin,
+        previous_owner,
+        reason,
+        blocked_until,
+        revoked_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ReviewConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetAppealBond<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+}
+
+pub fn set_appeal_bond_handler(ctx: Context<SetAppealBond>, appeal_bond: u64) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+
+    let review_config = &mut ctx.accounts.review_config;
+    let old_bond = review_config.appeal_bond;
+    review_config.appeal_bond = appeal_bond;
+    review_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(crate::events::AppealBondUpdated {
+        admin: ctx.accounts.admin.key(),
+        old_bond,
+        new_bond: appeal_bond,
+        updated_at: review_config.updated_at,
+    });
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ReviewConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetClaimWindow<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+}
+
+pub fn set_claim_window_handler(ctx: Context<SetClaimWindow>, claim_window: i64) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    require!(
+        claim_window > 0 && claim_window <= MAX_CLAIM_WINDOW,
+        ErrorCode::InvalidClaimWindow
+    );
+
+    let review_config = &mut ctx.accounts.review_config;
+    let old_window = review_config.claim_window;
+    review_config.claim_window = claim_window;
+    review_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(crate::events::ClaimWindowUpdated {
+        admin: ctx.accounts.admin.key(),
+        old_window,
+        new_window: claim_window,
+        updated_at: review_config.updated_at,
+    });
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ProtocolConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetCommitPolicy<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn set_commit_policy_handler(
+    ctx: Context<SetCommitPolicy>,
+    commit_required: bool,
+    commit_deposit: u64,
+    min_reveal_delay: i64,
+    commitment_ttl: i64,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    require!(
+        min_reveal_delay >= 0 && commitment_ttl > min_reveal_delay,
+        ErrorCode::InvalidCommitPolicy
+    );
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.commit_required = commit_required;
+    config.commit_deposit = commit_deposit;
+    config.min_reveal_delay = min_reveal_delay;
+    config.commitment_ttl = commitment_ttl;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(crate::events::CommitPolicyUpdated {
+        admin: ctx.accounts.admin.key(),
+        commit_required,
+        commit_deposit,
+        min_reveal_delay,
+        commitment_ttl,
+        updated_at: config.updated_at,
+    });
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ReviewConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetExpiryExtensionPolicy<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+}
+
+pub fn set_expiry_extension_policy_handler(
+    ctx: Context<SetExpiryExtensionPolicy>,
+    needs_info_extension: i64,
+    max_expiry_extension: i64,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    require!(
+        needs_info_extension >= 0
+            && max_expiry_extension >= 0
+            && needs_info_extension <= max_expiry_extension,
+        ErrorCode::InvalidExtensionPolicy
+    );
+
+    let review_config = &mut ctx.accounts.review_config;
+    review_config.needs_info_extension = needs_info_extension;
+    review_config.max_expiry_extension = max_expiry_extension;
+    review_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(crate::events::ExpiryExtensionPolicyUpdated {
+        admin: ctx.accounts.admin.key(),
+        needs_info_extension,
+        max_expiry_extension,
+        updated_at: review_config.updated_at,
+    });
+    Ok(())
+}
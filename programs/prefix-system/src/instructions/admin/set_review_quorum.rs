@@ -0,0 +1,51 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, QuorumTier, ReviewConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetReviewQuorum<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+}
+
+pub fn set_review_quorum_handler(
+    ctx: Context<SetReviewQuorum>,
+    default_quorum: u8,
+    quorum_tiers: Vec<QuorumTier>,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+
+    let valid_threshold = |t: u8| t > 0 && t as usize <= MAX_QUORUM;
+    require!(valid_threshold(default_quorum), ErrorCode::InvalidQuorum);
+    require!(
+        quorum_tiers.len() <= MAX_QUORUM_TIERS,
+        ErrorCode::InvalidQuorum
+    );
+    require!(
+        quorum_tiers
+            .iter()
+            .all(|t| t.min_len <= t.max_len && valid_threshold(t.threshold)),
+        ErrorCode::InvalidQuorum
+    );
+
+    let review_config = &mut ctx.accounts.review_config;
+    review_config.default_quorum = default_quorum;
+    review_config.quorum_tiers = quorum_tiers;
+    review_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(crate::events::ReviewQuorumUpdated {
+        admin: ctx.accounts.admin.key(),
+        default_quorum,
+        tiers_len: review_config.quorum_tiers.len() as u8,
+        updated_at: review_config.updated_at,
+    });
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ProtocolConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRoyalty<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn set_royalty_handler(ctx: Context<SetRoyalty>, royalty_bps: u16) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    require!(royalty_bps <= MAX_ROYALTY_BPS, ErrorCode::InvalidRoyalty);
+
+    let config = &mut ctx.accounts.protocol_config;
+    let old_bps = config.royalty_bps;
+    config.royalty_bps = royalty_bps;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(crate::events::RoyaltyUpdated {
+        admin: ctx.accounts.admin.key(),
+        old_bps,
+        new_bps: royalty_bps,
+        updated_at: config.updated_at,
+    });
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ProtocolConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetSweepPolicy<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn set_sweep_policy_handler(
+    ctx: Context<SetSweepPolicy>,
+    sweep_reward: u64,
+    rejected_grace_period: i64,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    require!(rejected_grace_period >= 0, ErrorCode::InvalidProtocolConfig);
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.sweep_reward = sweep_reward;
+    config.rejected_grace_period = rejected_grace_period;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(crate::events::SweepPolicyUpdated {
+        admin: ctx.accounts.admin.key(),
+        sweep_reward,
+        rejected_grace_period,
+        updated_at: config.updated_at,
+    });
+    Ok(())
+}
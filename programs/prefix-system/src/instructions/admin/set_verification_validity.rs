@@ -0,0 +1,42 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ReviewConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetVerificationValidity<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+}
+
+pub fn set_verification_validity_handler(
+    ctx: Context<SetVerificationValidity>,
+    verification_validity: i64,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    // 0 keeps approvals trusted indefinitely
+    require!(
+        verification_validity >= 0,
+        ErrorCode::InvalidVerificationValidity
+    );
+
+    let review_config = &mut ctx.accounts.review_config;
+    let old_validity = review_config.verification_validity;
+    review_config.verification_validity = verification_validity;
+    review_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(crate::events::VerificationValidityUpdated {
+        admin: ctx.accounts.admin.key(),
+        old_validity,
+        new_validity: verification_validity,
+        updated_at: review_config.updated_at,
+    });
+    Ok(())
+}
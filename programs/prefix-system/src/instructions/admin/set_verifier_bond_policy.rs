@@ -0,0 +1,39 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ReviewConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetVerifierBondPolicy<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+}
+
+pub fn set_verifier_bond_policy_handler(
+    ctx: Context<SetVerifierBondPolicy>,
+    min_verifier_bond: u64,
+    unbonding_period: i64,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    require!(unbonding_period >= 0, ErrorCode::InvalidBondPolicy);
+
+    let review_config = &mut ctx.accounts.review_config;
+    review_config.min_verifier_bond = min_verifier_bond;
+    review_config.unbonding_period = unbonding_period;
+    review_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(crate::events::VerifierBondPolicyUpdated {
+        admin: ctx.accounts.admin.key(),
+        min_verifier_bond,
+        unbonding_period,
+        updated_at: review_config.updated_at,
+    });
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, VerifierRecord, VerifierScope};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(verifier: Pubkey)]
+pub struct SetVerifierScope<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [VERIFIER_SEED, verifier.as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+}
+
+pub fn set_verifier_scope_handler(
+    ctx: Context<SetVerifierScope>,
+    verifier: Pubkey,
+    scope: VerifierScope,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    require!(
+        scope.category_mask & !INTENT_ALL == 0,
+        ErrorCode::InvalidVerifierScope
+    );
+    require!(
+        scope.max_len == 0 || scope.min_len <= scope.max_len,
+        ErrorCode::InvalidVerifierScope
+    );
+    require!(
+        scope.patterns.len() <= MAX_SCOPE_PATTERNS,
+        ErrorCode::InvalidVerifierScope
+    );
+    require!(
+        scope
+            .patterns
+            .iter()
+            .all(|p| !p.is_empty() && p.len() <= MAX_PREFIX_LEN),
+        ErrorCode::InvalidVerifierScope
+    );
+
+    let record = &mut ctx.accounts.verifier_record;
+    record.scope = scope;
+    let now = Clock::get()?.unix_timestamp;
+
+    emit!(crate::events::VerifierScopeUpdated {
+        admin: ctx.accounts.admin.key(),
+        verifier,
+        category_mask: record.scope.category_mask,
+        min_len: record.scope.min_len,
+        max_len: record.scope.max_len,
+        patterns: record.scope.patterns.clone(),
+        updated_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, VerifierRecord};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(verifier: Pubkey)]
+pub struct SlashVerifier<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [VERIFIER_SEED, verifier.as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    /// CHECK: Verifier vault PDA
+    #[account(mut, seeds = [VERIFIER_VAULT_SEED, verifier.as_ref()], bump)]
+    pub vault: UncheckedAccount<'info>,
+    /// CHECK: Treasury PDA
+    #[account(mut, seeds = [TREASURY_SEED, fee_registry.key().as_ref()], bump)]
+    pub treasury: UncheckedAccount<'info>,
+}
+
+pub fn slash_verifier_handler(
+    ctx: Context<SlashVerifier>,
+    verifier: Pubkey,
+    amount: u64,
+    reason: String,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    require!(
+        reason.len() <= MAX_SLASH_REASON_LEN,
+        ErrorCode::SlashReasonTooLong
+    );
+    let record = &mut ctx.accounts.verifier_record;
+    require!(
+        amount > 0 && amount <= record.bond,
+        ErrorCode::InvalidSlashAmount
+    );
+    require!(
+        ctx.accounts.vault.owner == ctx.program_id,
+        ErrorCode::InvalidTreasuryAccount
+    );
+
+    // Slashed lamports move from the verifier vault to the treasury
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    **vault_info.lamports.borrow_mut() -= amount;
+    **treasury_info.lamports.borrow_mut() += amount;
+    record.bond -= amount;
+
+    emit!(crate::events::VerifierSlashed {
+        admin: ctx.accounts.admin.key(),
+        verifier,
+        amount,
+        remaining_bond: record.bond,
+        reason,
+        slashed_at: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
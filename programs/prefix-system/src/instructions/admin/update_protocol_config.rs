@@ -0,0 +1,61 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{FeeRegistry, ProtocolConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_protocol_config_handler(
+    ctx: Context<UpdateProtocolConfig>,
+    min_prefix_len: u8,
+    max_prefix_len: u8,
+    allow_hyphen: bool,
+    allow_underscore: bool,
+    max_expiry_duration: i64,
+    min_review_window: i64,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    // Existing PrefixAccounts are sized for MAX_PREFIX_LEN, so it stays the hard cap
+    require!(
+        min_prefix_len > 0
+            && min_prefix_len <= max_prefix_len
+            && max_prefix_len as usize <= MAX_PREFIX_LEN,
+        ErrorCode::InvalidProtocolConfig
+    );
+    require!(
+        min_review_window > 0 && min_review_window <= max_expiry_duration,
+        ErrorCode::InvalidProtocolConfig
+    );
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.min_prefix_len = min_prefix_len;
+    config.max_prefix_len = max_prefix_len;
+    config.allow_hyphen = allow_hyphen;
+    config.allow_underscore = allow_underscore;
+    config.max_expiry_duration = max_expiry_duration;
+    config.min_review_window = min_review_window;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(crate::events::ProtocolConfigUpdated {
+        admin: ctx.accounts.admin.key(),
+        min_prefix_len,
+        max_prefix_len,
+        allow_hyphen,
+        allow_underscore,
+        max_expiry_duration,
+        min_review_window,
+        updated_at: config.updated_at,
+    });
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use crate::constants::*;
 use crate::events::FeeUpdated;
-use crate::state::{FeeRegistry, VerifiersList};
+use crate::state::{FeeRegistry, ProtocolConfig, ReviewConfig};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -20,15 +20,25 @@ pub struct Initialize<'info> {
     )]
     pub fee_registry: Account<'info, FeeRegistry>,
 
-    /// Verifiers list PDA
+    /// Review policy PDA (verifier quorum)
     #[account(
         init,
         payer = payer,
-        space = VerifiersList::space(MAX_VERIFIERS),
-        seeds = [VERIFIERS_SEED],
+        space = ReviewConfig::space(MAX_QUORUM_TIERS),
+        seeds = [REVIEW_CONFIG_SEED],
         bump,
     )]
-    pub verifiers: Account<'info, VerifiersList>,
+    pub review_config: Account<'info, ReviewConfig>,
+
+    /// Prefix rules PDA
+    #[account(
+        init,
+        payer = payer,
+        space = ProtocolConfig::space(),
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     ///CHECK: Treasury PDA owned by the program (created rent-exempt)
     #[account(
@@ -52,7 +62,8 @@ pub fn initialize_handler(
 
     // bumps for created PDAs (available because we used `bump` in the account constraints)
     let bump_fee = ctx.bumps.fee_registry;
-    let bump_ver = ctx.bumps.verifiers;
+    let bump_review = ctx.bumps.review_config;
+    let bump_protocol = ctx.bumps.protocol_config;
     let _bump_treasury = ctx.bumps.treasury; // stored if needed later
 
     let fee_registry = &mut ctx.accounts.fee_registry;
@@ -63,12 +74,9 @@ pub fn initialize_handler(
     fee_registry.created_at = now;
     fee_registry.updated_at = now;
 
-    let verifiers = &mut ctx.accounts.verifiers;
-    verifiers.admin = admin_pubkey;
-    verifiers.verifiers = Vec::new();
-    verifiers.bump = bump_ver;
-    verifiers.created_at = now;
-    verifiers.updated_at = now;
+    ctx.accounts.review_config.set_defaults(bump_review, now);
+
+    ctx.accounts.protocol_config.set_defaults(bump_protocol, now);
 
     emit!(FeeUpdated {
         admin: admin_pubkey,
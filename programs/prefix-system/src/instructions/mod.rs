@@ -1,6 +1,7 @@
 pub mod admin;
 pub mod initialize;
 pub mod prefix;
+pub mod verifier;
 
 // Re-export all instruction contexts and handlers
 pub use initialize::*;
@@ -15,6 +16,31 @@ pub use prefix::update_prefix_authority::*;
 pub use prefix::deactivate_prefix::*;
 pub use prefix::reactivate_prefix::*;
 pub use prefix::recover_prefix_owner_with_fee::*;
+pub use prefix::claim_review::*;
+pub use prefix::release_review::*;
+pub use prefix::resubmit_prefix::*;
+pub use prefix::appeal_rejection::*;
+pub use prefix::resolve_appeal::*;
+pub use prefix::request_prefix_info::*;
+pub use prefix::reverify_prefix::*;
+pub use prefix::create_sub_prefix::*;
+pub use prefix::sweep_expired_prefix::*;
+pub use prefix::extend_review_window::*;
+pub use prefix::suspend_prefix::*;
+pub use prefix::extend_suspension::*;
+pub use prefix::end_suspension::*;
+pub use prefix::pause_prefix::*;
+pub use prefix::resume_prefix::*;
+pub use prefix::emergency_freeze::*;
+pub use prefix::unfreeze_prefix::*;
+pub use prefix::list_prefix::*;
+pub use prefix::delist_prefix::*;
+pub use prefix::buy_prefix::*;
+pub use prefix::place_bid::*;
+pub use prefix::settle_auction::*;
+pub use prefix::commit_prefix::*;
+pub use prefix::reveal_prefix::*;
+pub use prefix::refund_commitment::*;
 
 // Re-export admin instruction contexts and handlers
 pub use admin::update_fee::*;
@@ -22,3 +48,29 @@ pub use admin::add_verifier::*;
 pub use admin::remove_verifier::*;
 pub use admin::withdraw_treasury::*;
 pub use admin::set_pause::*;
+pub use admin::set_review_quorum::*;
+pub use admin::init_review_config::*;
+pub use admin::init_protocol_config::*;
+pub use admin::migrate_prefix_account::*;
+pub use admin::migrate_verifier::*;
+pub use admin::set_claim_window::*;
+pub use admin::set_appeal_bond::*;
+pub use admin::slash_verifier::*;
+pub use admin::set_verifier_bond_policy::*;
+pub use admin::set_expiry_extension_policy::*;
+pub use admin::set_verification_validity::*;
+pub use admin::set_verifier_scope::*;
+pub use admin::reserve_prefix::*;
+pub use admin::release_reserved_prefix::*;
+pub use admin::update_protocol_config::*;
+pub use admin::set_sweep_policy::*;
+pub use admin::set_royalty::*;
+pub use admin::open_auction::*;
+pub use admin::cancel_auction::*;
+pub use admin::set_commit_policy::*;
+pub use admin::revoke_prefix::*;
+
+// Re-export verifier instruction contexts and handlers
+pub use verifier::deposit_verifier_bond::*;
+pub use verifier::resign_verifier::*;
+pub use verifier::withdraw_verifier_bond::*;
@@ -0,0 +1,93 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::AppealFiled;
+use crate::state::{
+    prefix_account::PrefixStatus, Appeal, AuditAction, AuditLog, FeeRegistry, PrefixAccount,
+    ReviewConfig,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct AppealRejection<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+    /// Appeal PDA; escrows the bond until resolution
+    #[account(
+        init,
+        payer = owner,
+        space = Appeal::space(),
+        seeds = [APPEAL_SEED, prefix.as_bytes()],
+        bump,
+    )]
+    pub appeal: Account<'info, Appeal>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn appeal_rejection_handler(
+    ctx: Context<AppealRejection>,
+    prefix: String,
+    evidence_hash: [u8; 32],
+) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+    require_keys_eq!(
+        ctx.accounts.owner.key(),
+        ctx.accounts.prefix_account.owner,
+        ErrorCode::UnauthorizedOwnerAction
+    );
+    require!(
+        ctx.accounts.prefix_account.status == PrefixStatus::Rejected,
+        ErrorCode::InvalidPrefixStatus
+    );
+
+    // Escrow the bond in the appeal PDA
+    let bond = ctx.accounts.review_config.appeal_bond;
+    if bond > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.owner.to_account_info(),
+            to: ctx.accounts.appeal.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, bond)?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let appeal = &mut ctx.accounts.appeal;
+    appeal.owner = ctx.accounts.owner.key();
+    appeal.bond = bond;
+    appeal.evidence_hash = evidence_hash;
+    appeal.created_at = now;
+    appeal.bump = ctx.bumps.appeal;
+
+    let acct = &mut ctx.accounts.prefix_account;
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
+    acct.status = PrefixStatus::UnderAppeal;
+    acct.updated_at = now;
+
+    ctx.accounts.audit_log.record(
+        appeal.owner,
+        AuditAction::Appealed,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
+    emit!(AppealFiled {
+        prefix,
+        owner: appeal.owner,
+        bond,
+        evidence_hash,
+        filed_at: now,
+    });
+    Ok(())
+}
@@ -1,8 +1,14 @@
 use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::events::{PrefixActivated, PrefixApproved};
-use crate::state::{prefix_account::PrefixStatus, FeeRegistry, PrefixAccount, VerifiersList};
+use crate::events::{
+    ClaimReleaseReason, PrefixActivated, PrefixApproved, ReviewReleased, ReviewVoteCast,
+};
+use crate::state::{
+    prefix_account::PrefixStatus, AuditAction, AuditLog, FeeRegistry, PrefixAccount, ReviewConfig,
+    ReviewState, VerifierRecord,
+};
 // Treasury is owned by System Program, no need for ownership checks
+use crate::utils::{prune_ineligible_votes, skeleton_prefix};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -11,12 +17,18 @@ pub struct ApprovePrefix<'info> {
     pub verifier: Signer<'info>,
     #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
     pub fee_registry: Account<'info, FeeRegistry>,
-    #[account(seeds = [VERIFIERS_SEED], bump = verifiers.bump)]
-    pub verifiers: Account<'info, VerifiersList>,
+    #[account(mut, seeds = [VERIFIER_SEED, verifier.key().as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    #[account(seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
     /// CHECK: ownership asserted at runtime
     pub treasury: UncheckedAccount<'info>,
     #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
     pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut, seeds = [REVIEW_SEED, prefix.as_bytes()], bump = review_state.bump)]
+    pub review_state: Account<'info, ReviewState>,
 }
 
 pub fn approve_prefix_handler(
@@ -31,13 +43,24 @@ pub fn approve_prefix_handler(
     );
 
     // Auth
+    let verifier = ctx.accounts.verifier.key();
     require!(
-        ctx.accounts
-            .verifiers
-            .verifiers
-            .contains(&ctx.accounts.verifier.key()),
+        ctx.accounts.verifier_record.active,
         ErrorCode::UnauthorizedVerifier
     );
+    require!(
+        ctx.accounts
+            .verifier_record
+            .can_review(ctx.accounts.review_config.min_verifier_bond),
+        ErrorCode::InsufficientVerifierBond
+    );
+    require!(
+        ctx.accounts.verifier_record.scope.covers(
+            &ctx.accounts.prefix_account.prefix,
+            ctx.accounts.prefix_account.intent_categories,
+        ),
+        ErrorCode::OutOfVerifierScope
+    );
 
     // State checks
     require!(
@@ -45,23 +68,96 @@ pub fn approve_prefix_handler(
         ErrorCode::InvalidPrefixStatus
     );
 
+    let now = Clock::get()?.unix_timestamp;
     require!(
-        Clock::get()?.unix_timestamp <= ctx.accounts.prefix_account.expiry_at,
+        now <= ctx.accounts.prefix_account.expiry_at,
         ErrorCode::PrefixExpired
     );
 
     // Treasury is owned by System Program, no need to check ownership
 
-    // Update state
+    // A live review claim reserves the vote for its holder; casting it releases the claim
+    let acct = &mut ctx.accounts.prefix_account;
+    if let Some(holder) = acct.live_claim(now) {
+        require_keys_eq!(holder, verifier, ErrorCode::ReviewAlreadyClaimed);
+        acct.clear_claim();
+        ctx.accounts.verifier_record.clear_claim();
+        emit!(ReviewReleased {
+            prefix: prefix.clone(),
+            verifier,
+            reason: ClaimReleaseReason::Voted,
+            released_at: now,
+        });
+    }
+
+    // Record the vote; one vote per verifier per review round
+    let review = &mut ctx.accounts.review_state;
+    review.sync_round(ctx.accounts.prefix_account.review_round);
+    require!(!review.has_voted(&verifier), ErrorCode::AlreadyVoted);
+    // Votes from verifiers that have since lost review rights are dropped before tallying
+    let min_bond = ctx.accounts.review_config.min_verifier_bond;
+    prune_ineligible_votes(
+        &mut review.approvals,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        min_bond,
+    )?;
+    prune_ineligible_votes(
+        &mut review.rejections,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        min_bond,
+    )?;
+    review.approvals.push(verifier);
+    review.updated_at = now;
+    ctx.accounts
+        .verifier_record
+        .record_vote(true, ctx.accounts.prefix_account.review_started_at, now);
+
+    let threshold = ctx
+        .accounts
+        .review_config
+        .quorum_for(ctx.accounts.prefix_account.prefix.len());
+    let approvals = review.approvals.len() as u8;
+    let rejections = review.rejections.len() as u8;
+    emit!(ReviewVoteCast {
+        prefix: prefix.clone(),
+        verifier,
+        approve: true,
+        approvals,
+        rejections,
+        threshold,
+        voted_at: now,
+    });
+
+    if approvals < threshold {
+        return Ok(());
+    }
+
+    // Quorum reached: update state
     let acct = &mut ctx.accounts.prefix_account;
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
     acct.status = PrefixStatus::Active;
     acct.ref_hash = ref_hash;
-    acct.updated_at = Clock::get()?.unix_timestamp;
+    acct.decided_by = Some(verifier);
+    acct.verified_until = ctx.accounts.review_config.verified_until(now);
+    acct.updated_at = now;
+
+    ctx.accounts.audit_log.record(
+        verifier,
+        AuditAction::Approved,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
 
     emit!(PrefixApproved {
         prefix: prefix.clone(),
-        verifier: ctx.accounts.verifier.key(),
+        skeleton: skeleton_prefix(&acct.prefix),
+        verifier,
         ref_hash,
+        verified_until: acct.verified_until,
         approved_at: acct.updated_at,
     });
 
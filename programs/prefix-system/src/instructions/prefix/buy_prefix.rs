@@ -0,0 +1,114 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixSold;
+use crate::state::{
+    prefix_account::PrefixStatus, AuditAction, AuditLog, FeeRegistry, Listing, PrefixAccount,
+    ProtocolConfig,
+};
+use crate::utils::{assert_ancestors_trusted, resolve_review_window};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct BuyPrefix<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    /// CHECK: Seller recorded on the listing; receives the price and listing rent
+    #[account(mut, address = listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    /// CHECK: Treasury PDA
+    #[account(mut, seeds = [TREASURY_SEED, fee_registry.key().as_ref()], bump)]
+    pub treasury: UncheckedAccount<'info>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut, close = seller, seeds = [LISTING_SEED, prefix.as_bytes()], bump = listing.bump)]
+    pub listing: Account<'info, Listing>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn buy_prefix_handler(ctx: Context<BuyPrefix>, prefix: String, max_price: u64) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+    require!(
+        ctx.accounts.treasury.owner == ctx.program_id,
+        ErrorCode::InvalidTreasuryAccount
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let seller = ctx.accounts.listing.seller;
+    let buyer = ctx.accounts.buyer.key();
+    let price = ctx.accounts.listing.price;
+    // Guards against a delist/relist at a higher price landing before this transaction
+    require!(price <= max_price, ErrorCode::InvalidListingPrice);
+    require_keys_neq!(buyer, seller, ErrorCode::BuyerIsSeller);
+
+    let acct = &ctx.accounts.prefix_account;
+    // Ownership may have moved (e.g. admin recovery) since the listing was made
+    require_keys_eq!(acct.owner, seller, ErrorCode::StaleListing);
+    // Only a currently verified prefix (and, for a sub-prefix, its ancestors, passed as
+    // remaining accounts) can change hands
+    require!(acct.is_trusted(now), ErrorCode::PrefixNotTrusted);
+    require!(acct.sub_prefix_count == 0, ErrorCode::PrefixHasSubPrefixes);
+    assert_ancestors_trusted(acct, ctx.remaining_accounts, ctx.program_id, now)?;
+
+    let royalty = ctx.accounts.protocol_config.royalty_for(price);
+    let proceeds = price - royalty;
+    if proceeds > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.buyer.to_account_info(),
+            to: ctx.accounts.seller.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, proceeds)?;
+    }
+    if royalty > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.buyer.to_account_info(),
+            to: ctx.accounts.treasury.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, royalty)?;
+    }
+
+    // The seller's signing infrastructure must not keep signing for the new owner
+    let acct = &mut ctx.accounts.prefix_account;
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
+    acct.owner = buyer;
+    acct.authority_keys = Vec::new();
+    acct.listed = false;
+    // The approval vouched for the seller; the buyer's prefix goes back through quorum review
+    acct.status = PrefixStatus::Pending;
+    acct.verified_until = 0;
+    acct.decided_by = None;
+    acct.ref_hash = [0u8; 32];
+    acct.review_round = acct.review_round.wrapping_add(1);
+    acct.review_started_at = now;
+    acct.expiry_at = now + resolve_review_window(None, &ctx.accounts.protocol_config)?;
+    acct.expiry_extension_used = 0;
+    acct.updated_at = now;
+
+    ctx.accounts.audit_log.record(
+        buyer,
+        AuditAction::Sold,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
+    emit!(PrefixSold {
+        prefix,
+        seller,
+        buyer,
+        price,
+        royalty,
+        sold_at: now,
+    });
+    Ok(())
+}
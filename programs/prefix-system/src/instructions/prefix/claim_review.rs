@@ -0,0 +1,100 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::{ClaimReleaseReason, ReviewClaimed, ReviewReleased};
+use crate::state::{
+    prefix_account::PrefixStatus, FeeRegistry, PrefixAccount, ReviewConfig, ReviewState,
+    VerifierRecord,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct ClaimReview<'info> {
+    pub verifier: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+    #[account(mut, seeds = [VERIFIER_SEED, verifier.key().as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(seeds = [REVIEW_SEED, prefix.as_bytes()], bump = review_state.bump)]
+    pub review_state: Account<'info, ReviewState>,
+}
+
+pub fn claim_review_handler(ctx: Context<ClaimReview>, prefix: String) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+    let verifier = ctx.accounts.verifier.key();
+    require!(
+        ctx.accounts.verifier_record.active,
+        ErrorCode::UnauthorizedVerifier
+    );
+    require!(
+        ctx.accounts
+            .verifier_record
+            .can_review(ctx.accounts.review_config.min_verifier_bond),
+        ErrorCode::InsufficientVerifierBond
+    );
+    require!(
+        ctx.accounts.verifier_record.scope.covers(
+            &ctx.accounts.prefix_account.prefix,
+            ctx.accounts.prefix_account.intent_categories,
+        ),
+        ErrorCode::OutOfVerifierScope
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let prefix_key = ctx.accounts.prefix_account.key();
+    let acct = &mut ctx.accounts.prefix_account;
+    require!(
+        acct.status == PrefixStatus::Pending,
+        ErrorCode::InvalidPrefixStatus
+    );
+    require!(now <= acct.expiry_at, ErrorCode::PrefixExpired);
+
+    // A verifier that already voted this round has nothing left to review
+    let review = &ctx.accounts.review_state;
+    require!(
+        review.round != acct.review_round || !review.has_voted(&verifier),
+        ErrorCode::AlreadyVoted
+    );
+
+    match acct.live_claim(now) {
+        Some(holder) => require_keys_eq!(holder, verifier, ErrorCode::ReviewAlreadyClaimed),
+        None => {
+            if let Some(previous) = acct.claimed_by {
+                emit!(ReviewReleased {
+                    prefix: prefix.clone(),
+                    verifier: previous,
+                    reason: ClaimReleaseReason::Expired,
+                    released_at: now,
+                });
+            }
+        }
+    }
+
+    // One live claim per verifier so removal can always release it
+    let record = &mut ctx.accounts.verifier_record;
+    if let Some(held) = record.live_claim(now) {
+        require_keys_eq!(held, prefix_key, ErrorCode::VerifierClaimActive);
+    }
+
+    // Never outlive the review window, so a live claim always points at an open prefix
+    let expires_at = now
+        .saturating_add(ctx.accounts.review_config.claim_window)
+        .min(acct.expiry_at);
+    acct.claimed_by = Some(verifier);
+    acct.claim_expires_at = expires_at;
+    acct.updated_at = now;
+    record.claimed_prefix = Some(prefix_key);
+    record.claim_expires_at = expires_at;
+
+    emit!(ReviewClaimed {
+        prefix,
+        verifier,
+        expires_at,
+        claimed_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixCommitted;
+use crate::state::{FeeRegistry, PrefixCommitment, ProtocolConfig};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct CommitPrefix<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    /// Commitment PDA; escrows the deposit until reveal or refund
+    #[account(
+        init,
+        payer = owner,
+        space = PrefixCommitment::space(),
+        seeds = [COMMITMENT_SEED, owner.key().as_ref(), commitment.as_ref()],
+        bump,
+    )]
+    pub prefix_commitment: Account<'info, PrefixCommitment>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn commit_prefix_handler(ctx: Context<CommitPrefix>, commitment: [u8; 32]) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+
+    let config = &ctx.accounts.protocol_config;
+    let deposit = config.commit_deposit;
+    if deposit > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.owner.to_account_info(),
+            to: ctx.accounts.prefix_commitment.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, deposit)?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let record = &mut ctx.accounts.prefix_commitment;
+    record.owner = ctx.accounts.owner.key();
+    record.commitment = commitment;
+    record.deposit = deposit;
+    // Timing is fixed at commit so later policy changes can't strand an open commitment
+    record.reveal_after = now + config.min_reveal_delay;
+    record.expires_at = now + config.commitment_ttl;
+    record.created_at = now;
+    record.bump = ctx.bumps.prefix_commitment;
+
+    emit!(PrefixCommitted {
+        owner: record.owner,
+        commitment,
+        deposit,
+        reveal_after: record.reveal_after,
+        expires_at: record.expires_at,
+    });
+    Ok(())
+}
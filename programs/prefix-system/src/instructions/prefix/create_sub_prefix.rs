@@ -0,0 +1,211 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::SubPrefixCreated;
+use crate::state::{
+    prefix_account::PrefixStatus, AuditAction, AuditLog, FeeRegistry, PrefixAccount,
+    ProtocolConfig, ReviewState, SkeletonGuard,
+};
+use crate::utils::{
+    assert_ancestors_trusted, assert_not_tombstoned, assert_prefix_available, skeleton_prefix,
+    sub_prefix_name, validate_intent_categories, validate_metadata,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(parent_prefix: String, label: String)]
+pub struct CreateSubPrefix<'info> {
+    /// Owner of the parent prefix; pays for the child accounts
+    #[account(mut)]
+    pub parent_owner: Signer<'info>,
+
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+
+    #[account(seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(mut, seeds = [PREFIX_SEED, parent_prefix.as_bytes()], bump = parent_account.bump)]
+    pub parent_account: Box<Account<'info, PrefixAccount>>,
+
+    #[account(
+        init,
+        payer = parent_owner,
+        space = PrefixAccount::space(MAX_PREFIX_LEN, MAX_URI_LEN, MAX_AUTH_KEYS),
+        seeds = [PREFIX_SEED, (sub_prefix_name(&parent_prefix, &label).as_bytes())],
+        bump,
+    )]
+    pub prefix_account: Box<Account<'info, PrefixAccount>>,
+
+    #[account(
+        init,
+        payer = parent_owner,
+        space = ReviewState::space(MAX_QUORUM),
+        seeds = [REVIEW_SEED, (sub_prefix_name(&parent_prefix, &label).as_bytes())],
+        bump,
+    )]
+    pub review_state: Box<Account<'info, ReviewState>>,
+
+    #[account(
+        init,
+        payer = parent_owner,
+        space = SkeletonGuard::space(MAX_PREFIX_LEN),
+        seeds = [SKELETON_SEED, (skeleton_prefix(&sub_prefix_name(&parent_prefix, &label)).as_bytes())],
+        bump,
+    )]
+    pub skeleton_guard: Box<Account<'info, SkeletonGuard>>,
+
+    /// Kept from an earlier registration of the name if it was revoked; sized like every
+    /// other log since it outlives the prefix
+    #[account(
+        init_if_needed,
+        payer = parent_owner,
+        space = AuditLog::space(MAX_PREFIX_LEN, MAX_AUDIT_ENTRIES),
+        seeds = [AUDIT_LOG_SEED, (sub_prefix_name(&parent_prefix, &label).as_bytes())],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+
+    /// CHECK: reserved-names registry entry for the child name's skeleton; may be uninitialized
+    #[account(
+        seeds = [RESERVED_SEED, (skeleton_prefix(&sub_prefix_name(&parent_prefix, &label)).as_bytes())],
+        bump,
+    )]
+    pub reserved_prefix: UncheckedAccount<'info>,
+
+    /// CHECK: revocation tombstone for the child name's skeleton; may be uninitialized
+    #[account(
+        seeds = [TOMBSTONE_SEED, (skeleton_prefix(&sub_prefix_name(&parent_prefix, &label)).as_bytes())],
+        bump,
+    )]
+    pub tombstone: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_sub_prefix_handler(
+    ctx: Context<CreateSubPrefix>,
+    parent_prefix: String,
+    label: String,
+    owner: Pubkey,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
+    authority_keys: Vec<Pubkey>,
+    intent_categories: u32,
+) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+
+    let now = Clock::get()?.unix_timestamp;
+    assert_not_tombstoned(&ctx.accounts.tombstone.to_account_info(), ctx.program_id, now)?;
+    // Delegating a namespace does not bypass the reserved and blocked names registry
+    assert_prefix_available(
+        &ctx.accounts.reserved_prefix.to_account_info(),
+        ctx.program_id,
+        &owner,
+    )?;
+    let parent = &mut ctx.accounts.parent_account;
+    require_keys_eq!(
+        ctx.accounts.parent_owner.key(),
+        parent.owner,
+        ErrorCode::UnauthorizedOwnerAction
+    );
+    require!(parent.is_trusted(now), ErrorCode::ParentPrefixNotTrusted);
+    require!(!parent.listed, ErrorCode::PrefixListed);
+    // The grandparent chain is passed as remaining accounts
+    assert_ancestors_trusted(parent, ctx.remaining_accounts, ctx.program_id, now)?;
+    require!(
+        parent.depth < MAX_SUB_PREFIX_DEPTH,
+        ErrorCode::SubPrefixDepthExceeded
+    );
+    require!(
+        parent.sub_prefix_count < MAX_SUB_PREFIXES,
+        ErrorCode::SubPrefixLimitReached
+    );
+
+    let name = sub_prefix_name(&parent_prefix, &label);
+    require!(
+        !label.is_empty()
+            && label.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            && name.len() <= ctx.accounts.protocol_config.max_prefix_len as usize,
+        ErrorCode::InvalidSubPrefixLabel
+    );
+    validate_metadata(&metadata_uri, &metadata_hash)?;
+    validate_intent_categories(intent_categories)?;
+    require!(authority_keys.len() <= MAX_AUTH_KEYS, ErrorCode::AuthorityKeysTooMany);
+
+    parent.sub_prefix_count += 1;
+    parent.updated_at = now;
+    let parent_key = parent.key();
+    let depth = parent.depth + 1;
+    let verified_until = parent.verified_until;
+
+    // Children skip verifier review; their trust is inherited through the parent chain
+    let data = &mut ctx.accounts.prefix_account;
+    data.owner = owner;
+    data.prefix = name.clone();
+    data.metadata_uri = metadata_uri;
+    data.metadata_hash = metadata_hash;
+    data.ref_hash = [0u8; 32];
+    data.status = PrefixStatus::Active;
+    data.authority_keys = authority_keys;
+    data.fee_paid = 0;
+    data.expiry_at = now;
+    data.created_at = now;
+    data.updated_at = now;
+    data.bump = ctx.bumps.prefix_account;
+    data.review_round = 0;
+    data.review_started_at = now;
+    data.claimed_by = None;
+    data.claim_expires_at = 0;
+    data.rejection_reason = None;
+    data.rejection_evidence = None;
+    data.resubmission_count = 0;
+    data.appeal_evidence = None;
+    data.decided_by = None;
+    data.info_request_hash = [0u8; 32];
+    data.expiry_extension_used = 0;
+    // The child's verification starts out as the parent's; verifiers renew it with reverify_prefix
+    data.verified_until = verified_until;
+    data.intent_categories = intent_categories;
+    data.parent = Some(parent_key);
+    data.depth = depth;
+    data.sub_prefix_count = 0;
+    data.rejected_at = 0;
+    data.clear_suspension();
+    data.frozen_by = None;
+    data.deactivated_from = None;
+    data.listed = false;
+
+    let review = &mut ctx.accounts.review_state;
+    review.round = 0;
+    review.approvals = Vec::new();
+    review.rejections = Vec::new();
+    review.bump = ctx.bumps.review_state;
+    review.created_at = now;
+    review.updated_at = now;
+
+    let guard = &mut ctx.accounts.skeleton_guard;
+    guard.prefix = name.clone();
+    guard.bump = ctx.bumps.skeleton_guard;
+
+    let log = &mut ctx.accounts.audit_log;
+    log.open(&name, ctx.bumps.audit_log);
+    log.record(
+        ctx.accounts.parent_owner.key(),
+        AuditAction::SubPrefixCreated,
+        None,
+        None,
+        &ctx.accounts.prefix_account,
+    )?;
+
+    emit!(SubPrefixCreated {
+        skeleton: skeleton_prefix(&name),
+        prefix: name,
+        parent_prefix,
+        parent: parent_key,
+        owner,
+        depth,
+        created_at: now,
+    });
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::events::PrefixDeactivated;
-use crate::state::{prefix_account::PrefixStatus, FeeRegistry, PrefixAccount};
+use crate::state::{prefix_account::PrefixStatus, AuditAction, AuditLog, FeeRegistry, PrefixAccount};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -12,6 +12,8 @@ pub struct DeactivatePrefix<'info> {
     pub fee_registry: Account<'info, FeeRegistry>,
     #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
     pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
 }
 
 pub fn deactivate_prefix_handler(ctx: Context<DeactivatePrefix>, prefix: String) -> Result<()> {
@@ -21,12 +23,28 @@ pub fn deactivate_prefix_handler(ctx: Context<DeactivatePrefix>, prefix: String)
         ctx.accounts.fee_registry.admin,
         ErrorCode::UnauthorizedAdmin
     );
+    // Owner-paused and frozen prefixes can still be deactivated; the owner cannot leave Inactive,
+    // and reactivation returns the prefix to the status it had here
     require!(
-        ctx.accounts.prefix_account.status == PrefixStatus::Active,
+        matches!(
+            ctx.accounts.prefix_account.status,
+            PrefixStatus::Active | PrefixStatus::OwnerPaused | PrefixStatus::Frozen
+        ),
         ErrorCode::InvalidPrefixStatus
     );
+    let old_status = ctx.accounts.prefix_account.status.clone();
+    let old_owner = ctx.accounts.prefix_account.owner;
+    ctx.accounts.prefix_account.deactivated_from = Some(old_status.clone());
     ctx.accounts.prefix_account.status = PrefixStatus::Inactive;
     ctx.accounts.prefix_account.updated_at = Clock::get()?.unix_timestamp;
+    ctx.accounts.audit_log.record(
+        admin,
+        AuditAction::Deactivated,
+        Some(old_status),
+        Some(old_owner),
+        &ctx.accounts.prefix_account,
+    )?;
+
     emit!(PrefixDeactivated {
         prefix,
         admin,
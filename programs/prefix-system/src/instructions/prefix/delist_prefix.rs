@@ -0,0 +1,37 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixDelisted;
+use crate::state::{Listing, PrefixAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct DelistPrefix<'info> {
+    pub signer: Signer<'info>,
+    /// CHECK: Seller recorded on the listing; receives the listing rent
+    #[account(mut, address = listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, close = seller, seeds = [LISTING_SEED, prefix.as_bytes()], bump = listing.bump)]
+    pub listing: Account<'info, Listing>,
+}
+
+pub fn delist_prefix_handler(ctx: Context<DelistPrefix>, prefix: String) -> Result<()> {
+    // The current owner may also clear a listing left behind by an earlier owner
+    let signer = ctx.accounts.signer.key();
+    require!(
+        signer == ctx.accounts.listing.seller || signer == ctx.accounts.prefix_account.owner,
+        ErrorCode::UnauthorizedOwnerAction
+    );
+
+    ctx.accounts.prefix_account.listed = false;
+
+    emit!(PrefixDelisted {
+        prefix,
+        seller: ctx.accounts.listing.seller,
+        delisted_by: signer,
+        delisted_at: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixFrozen;
+use crate::state::{prefix_account::PrefixStatus, AuditAction, AuditLog, PrefixAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct EmergencyFreeze<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+}
+
+pub fn emergency_freeze_handler(ctx: Context<EmergencyFreeze>, prefix: String) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let acct = &mut ctx.accounts.prefix_account;
+    // Any current signing key can pull the brake; the owner wallet may be cold
+    require!(
+        acct.authority_keys.contains(&authority),
+        ErrorCode::UnauthorizedAuthorityKey
+    );
+    require!(
+        matches!(acct.status, PrefixStatus::Active | PrefixStatus::OwnerPaused),
+        ErrorCode::InvalidPrefixStatus
+    );
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
+    acct.status = PrefixStatus::Frozen;
+    acct.frozen_by = Some(authority);
+    acct.updated_at = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.audit_log.record(
+        authority,
+        AuditAction::Frozen,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
+    emit!(PrefixFrozen {
+        prefix,
+        authority,
+        at: acct.updated_at,
+    });
+    Ok(())
+}
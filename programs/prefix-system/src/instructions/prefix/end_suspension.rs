@@ -0,0 +1,49 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixSuspensionEnded;
+use crate::state::{AuditAction, AuditLog, FeeRegistry, PrefixAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct EndSuspension<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+}
+
+pub fn end_suspension_handler(ctx: Context<EndSuspension>, prefix: String) -> Result<()> {
+    let admin = ctx.accounts.admin.key();
+    require_keys_eq!(
+        admin,
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let acct = &mut ctx.accounts.prefix_account;
+    require!(acct.is_suspended(now), ErrorCode::PrefixNotSuspended);
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
+    acct.clear_suspension();
+    acct.updated_at = now;
+
+    ctx.accounts.audit_log.record(
+        admin,
+        AuditAction::SuspensionEnded,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
+    emit!(PrefixSuspensionEnded {
+        prefix,
+        admin,
+        at: now,
+    });
+    Ok(())
+}
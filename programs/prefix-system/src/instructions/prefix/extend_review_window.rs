@@ -0,0 +1,78 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::ReviewWindowExtended;
+use crate::state::{
+    prefix_account::PrefixStatus, FeeRegistry, PrefixAccount, ReviewConfig, VerifierRecord,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct ExtendReviewWindow<'info> {
+    pub verifier: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+    #[account(seeds = [VERIFIER_SEED, verifier.key().as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+}
+
+pub fn extend_review_window_handler(
+    ctx: Context<ExtendReviewWindow>,
+    prefix: String,
+    extension: i64,
+) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+    let verifier = ctx.accounts.verifier.key();
+    let config = &ctx.accounts.review_config;
+    require!(
+        ctx.accounts.verifier_record.active,
+        ErrorCode::UnauthorizedVerifier
+    );
+    require!(
+        ctx.accounts
+            .verifier_record
+            .can_review(config.min_verifier_bond),
+        ErrorCode::InsufficientVerifierBond
+    );
+    require!(
+        ctx.accounts.verifier_record.scope.covers(
+            &ctx.accounts.prefix_account.prefix,
+            ctx.accounts.prefix_account.intent_categories,
+        ),
+        ErrorCode::OutOfVerifierScope
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let acct = &mut ctx.accounts.prefix_account;
+    require!(
+        matches!(acct.status, PrefixStatus::Pending | PrefixStatus::NeedsInfo),
+        ErrorCode::InvalidPrefixStatus
+    );
+    require!(now <= acct.expiry_at, ErrorCode::PrefixExpired);
+    if let Some(holder) = acct.live_claim(now) {
+        require_keys_eq!(holder, verifier, ErrorCode::ReviewAlreadyClaimed);
+    }
+
+    // Shares the lifetime extension budget with info requests
+    require!(extension > 0, ErrorCode::ReviewWindowTooShort);
+    require!(
+        extension <= config.remaining_extension(acct.expiry_extension_used),
+        ErrorCode::ReviewWindowTooLong
+    );
+    acct.expiry_at = acct.expiry_at.saturating_add(extension);
+    acct.expiry_extension_used = acct.expiry_extension_used.saturating_add(extension);
+    acct.updated_at = now;
+
+    emit!(ReviewWindowExtended {
+        prefix,
+        verifier,
+        extension,
+        expiry_at: acct.expiry_at,
+        extended_at: now,
+    });
+    Ok(())
+}
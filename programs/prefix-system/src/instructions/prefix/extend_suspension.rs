@@ -0,0 +1,62 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixSuspensionExtended;
+use crate::state::{AuditAction, AuditLog, FeeRegistry, PrefixAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct ExtendSuspension<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+}
+
+pub fn extend_suspension_handler(
+    ctx: Context<ExtendSuspension>,
+    prefix: String,
+    additional: i64,
+) -> Result<()> {
+    let admin = ctx.accounts.admin.key();
+    require_keys_eq!(
+        admin,
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let acct = &mut ctx.accounts.prefix_account;
+    require!(acct.is_suspended(now), ErrorCode::PrefixNotSuspended);
+
+    // The remaining suspension is capped the same way a fresh one is
+    let suspended_until = acct.suspended_until.saturating_add(additional);
+    require!(
+        additional > 0 && suspended_until - now <= MAX_SUSPENSION_DURATION,
+        ErrorCode::InvalidSuspensionDuration
+    );
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
+    acct.suspended_until = suspended_until;
+    acct.suspended_by = Some(admin);
+    acct.updated_at = now;
+
+    ctx.accounts.audit_log.record(
+        admin,
+        AuditAction::SuspensionExtended,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
+    emit!(PrefixSuspensionExtended {
+        prefix,
+        admin,
+        suspended_until,
+        at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixListed;
+use crate::state::{prefix_account::PrefixStatus, FeeRegistry, Listing, PrefixAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct ListPrefix<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(
+        init,
+        payer = owner,
+        space = Listing::space(),
+        seeds = [LISTING_SEED, prefix.as_bytes()],
+        bump,
+    )]
+    pub listing: Account<'info, Listing>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn list_prefix_handler(ctx: Context<ListPrefix>, prefix: String, price: u64) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+    let acct = &mut ctx.accounts.prefix_account;
+    require_keys_eq!(
+        ctx.accounts.owner.key(),
+        acct.owner,
+        ErrorCode::UnauthorizedOwnerAction
+    );
+    require!(
+        acct.status == PrefixStatus::Active,
+        ErrorCode::InvalidPrefixStatus
+    );
+    // Sub-prefixes would stay with the seller's namespace; only a prefix without children is sold
+    require!(acct.sub_prefix_count == 0, ErrorCode::PrefixHasSubPrefixes);
+    require!(price > 0, ErrorCode::InvalidListingPrice);
+
+    let now = Clock::get()?.unix_timestamp;
+    // Metadata, authority keys and sub-prefixes are locked until the listing ends
+    acct.listed = true;
+    let listing = &mut ctx.accounts.listing;
+    listing.prefix = prefix.clone();
+    listing.seller = acct.owner;
+    listing.price = price;
+    listing.created_at = now;
+    listing.bump = ctx.bumps.listing;
+
+    emit!(PrefixListed {
+        prefix,
+        seller: listing.seller,
+        price,
+        listed_at: now,
+    });
+    Ok(())
+}
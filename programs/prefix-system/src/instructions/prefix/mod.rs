@@ -6,4 +6,29 @@ pub mod update_prefix_metadata;
 pub mod update_prefix_authority;
 pub mod deactivate_prefix;
 pub mod reactivate_prefix;
-pub mod recover_prefix_owner_with_fee;
\ No newline at end of file
+pub mod recover_prefix_owner_with_fee;
+pub mod claim_review;
+pub mod release_review;
+pub mod resubmit_prefix;
+pub mod appeal_rejection;
+pub mod resolve_appeal;
+pub mod request_prefix_info;
+pub mod reverify_prefix;
+pub mod create_sub_prefix;
+pub mod sweep_expired_prefix;
+pub mod extend_review_window;
+pub mod suspend_prefix;
+pub mod extend_suspension;
+pub mod end_suspension;
+pub mod pause_prefix;
+pub mod resume_prefix;
+pub mod emergency_freeze;
+pub mod unfreeze_prefix;
+pub mod list_prefix;
+pub mod delist_prefix;
+pub mod buy_prefix;
+pub mod place_bid;
+pub mod settle_auction;
+pub mod commit_prefix;
+pub mod reveal_prefix;
+pub mod refund_commitment;
\ No newline at end of file
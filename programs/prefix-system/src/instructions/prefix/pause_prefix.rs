@@ -0,0 +1,45 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixPausedByOwner;
+use crate::state::{prefix_account::PrefixStatus, AuditAction, AuditLog, PrefixAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct PausePrefix<'info> {
+    pub owner: Signer<'info>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+}
+
+pub fn pause_prefix_handler(ctx: Context<PausePrefix>, prefix: String) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let acct = &mut ctx.accounts.prefix_account;
+    require_keys_eq!(owner, acct.owner, ErrorCode::UnauthorizedOwnerAction);
+    require!(
+        acct.status == PrefixStatus::Active,
+        ErrorCode::InvalidPrefixStatus
+    );
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
+    // Not gated on the fee pause: this is the owner's emergency brake
+    acct.status = PrefixStatus::OwnerPaused;
+    acct.updated_at = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.audit_log.record(
+        owner,
+        AuditAction::OwnerPaused,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
+    emit!(PrefixPausedByOwner {
+        prefix,
+        owner,
+        at: acct.updated_at,
+    });
+    Ok(())
+}
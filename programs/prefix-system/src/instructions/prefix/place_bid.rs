@@ -0,0 +1,76 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::AuctionBidPlaced;
+use crate::state::{Auction, FeeRegistry};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct PlaceBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    /// Auction PDA; escrows the highest bid
+    #[account(mut, seeds = [AUCTION_SEED, prefix.as_bytes()], bump = auction.bump)]
+    pub auction: Account<'info, Auction>,
+    /// CHECK: Current highest bidder, refunded when outbid; required once the auction has a bid
+    #[account(mut)]
+    pub previous_bidder: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn place_bid_handler(ctx: Context<PlaceBid>, prefix: String, amount: u64) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+
+    let now = Clock::get()?.unix_timestamp;
+    let auction = &ctx.accounts.auction;
+    require!(now < auction.end_at, ErrorCode::AuctionEnded);
+    require!(amount >= auction.min_next_bid(), ErrorCode::BidTooLow);
+
+    // Escrow the new bid before releasing the old one
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.bidder.to_account_info(),
+        to: ctx.accounts.auction.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    let outbid = ctx.accounts.auction.highest_bidder;
+    if let Some(previous) = outbid {
+        let previous_info = ctx
+            .accounts
+            .previous_bidder
+            .as_ref()
+            .ok_or(ErrorCode::InvalidPreviousBidder)?
+            .to_account_info();
+        require_keys_eq!(
+            previous_info.key(),
+            previous,
+            ErrorCode::InvalidPreviousBidder
+        );
+        let refund = ctx.accounts.auction.highest_bid;
+        let auction_info = ctx.accounts.auction.to_account_info();
+        **auction_info.lamports.borrow_mut() -= refund;
+        **previous_info.lamports.borrow_mut() += refund;
+    }
+
+    let auction = &mut ctx.accounts.auction;
+    auction.highest_bidder = Some(ctx.accounts.bidder.key());
+    auction.highest_bid = amount;
+    // Anti-sniping: a late bid leaves everyone else a full window to respond
+    if auction.end_at - now < AUCTION_SNIPE_WINDOW {
+        auction.end_at = now + AUCTION_SNIPE_WINDOW;
+    }
+
+    emit!(AuctionBidPlaced {
+        prefix,
+        bidder: ctx.accounts.bidder.key(),
+        amount,
+        outbid,
+        end_at: auction.end_at,
+        placed_at: now,
+    });
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::events::PrefixReactivated;
-use crate::state::{prefix_account::PrefixStatus, FeeRegistry, PrefixAccount};
+use crate::state::{prefix_account::PrefixStatus, AuditAction, AuditLog, FeeRegistry, PrefixAccount};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -12,6 +12,8 @@ pub struct ReactivatePrefix<'info> {
     pub fee_registry: Account<'info, FeeRegistry>,
     #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
     pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
 }
 
 pub fn reactivate_prefix_handler(ctx: Context<ReactivatePrefix>, prefix: String) -> Result<()> {
@@ -25,8 +27,25 @@ pub fn reactivate_prefix_handler(ctx: Context<ReactivatePrefix>, prefix: String)
         ctx.accounts.prefix_account.status == PrefixStatus::Inactive,
         ErrorCode::InvalidPrefixStatus
     );
-    ctx.accounts.prefix_account.status = PrefixStatus::Active;
+    let old_status = ctx.accounts.prefix_account.status.clone();
+    let old_owner = ctx.accounts.prefix_account.owner;
+    // A paused prefix stays paused and a frozen one still needs the owner's unfreeze;
+    // migrated legacy accounts have none recorded and come back Active
+    ctx.accounts.prefix_account.status = ctx
+        .accounts
+        .prefix_account
+        .deactivated_from
+        .take()
+        .unwrap_or(PrefixStatus::Active);
     ctx.accounts.prefix_account.updated_at = Clock::get()?.unix_timestamp;
+    ctx.accounts.audit_log.record(
+        admin,
+        AuditAction::Reactivated,
+        Some(old_status),
+        Some(old_owner),
+        &ctx.accounts.prefix_account,
+    )?;
+
     emit!(PrefixReactivated {
         prefix,
         admin,
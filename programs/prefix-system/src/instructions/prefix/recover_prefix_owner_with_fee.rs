@@ -1,7 +1,7 @@
 use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::events::PrefixOwnerRecovered;
-use crate::state::{FeeRegistry, PrefixAccount};
+use crate::state::{AuditAction, AuditLog, FeeRegistry, PrefixAccount};
 // Treasury is owned by System Program, no need for ownership checks
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
@@ -25,6 +25,8 @@ pub struct RecoverPrefixOwnerWithFee<'info> {
 
     #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
     pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
 
     pub system_program: Program<'info, System>,
 }
@@ -74,10 +76,19 @@ pub fn recover_prefix_owner_with_fee_handler(
 
     // 7. Update owner in prefix_account
     let acct = &mut ctx.accounts.prefix_account;
+    let old_status = acct.status.clone();
     let old_owner = acct.owner;
     acct.owner = new_owner;
     acct.updated_at = Clock::get()?.unix_timestamp;
 
+    ctx.accounts.audit_log.record(
+        ctx.accounts.admin.key(),
+        AuditAction::OwnerRecovered,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
     // 8. Emit event
     emit!(PrefixOwnerRecovered {
         prefix,
@@ -0,0 +1,32 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::CommitmentRefunded;
+use crate::state::PrefixCommitment;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RefundCommitment<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        close = owner,
+        seeds = [COMMITMENT_SEED, owner.key().as_ref(), prefix_commitment.commitment.as_ref()],
+        bump = prefix_commitment.bump,
+    )]
+    pub prefix_commitment: Account<'info, PrefixCommitment>,
+}
+
+pub fn refund_commitment_handler(ctx: Context<RefundCommitment>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let record = &ctx.accounts.prefix_commitment;
+    require!(now > record.expires_at, ErrorCode::CommitmentNotExpired);
+
+    emit!(CommitmentRefunded {
+        owner: record.owner,
+        commitment: record.commitment,
+        deposit: record.deposit,
+        refunded_at: now,
+    });
+    Ok(())
+}
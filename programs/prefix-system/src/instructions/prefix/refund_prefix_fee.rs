@@ -1,7 +1,10 @@
 use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::events::PrefixRefunded;
-use crate::state::{prefix_account::PrefixStatus, FeeRegistry, PrefixAccount};
+use crate::state::{
+    prefix_account::PrefixStatus, AuditAction, AuditLog, FeeRegistry, PrefixAccount, ReviewState, SkeletonGuard,
+};
+use crate::utils::skeleton_prefix;
 // Treasury is a PDA owned by this program
 use anchor_lang::prelude::*;
 
@@ -17,6 +20,18 @@ pub struct RefundPrefixFee<'info> {
     pub treasury: UncheckedAccount<'info>,
     #[account(mut, close = owner, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
     pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, close = owner, seeds = [REVIEW_SEED, prefix.as_bytes()], bump = review_state.bump)]
+    pub review_state: Account<'info, ReviewState>,
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SKELETON_SEED, (skeleton_prefix(&prefix).as_bytes())],
+        bump = skeleton_guard.bump,
+    )]
+    pub skeleton_guard: Account<'info, SkeletonGuard>,
+    /// Kept open so the refund stays on record
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
     pub system_program: Program<'info, System>,
 }
 
@@ -30,8 +45,8 @@ pub fn refund_prefix_fee_handler(ctx: Context<RefundPrefixFee>, _prefix: String)
     let acct = &mut ctx.accounts.prefix_account;
     // Allow refund for rejected prefixes or expired pending prefixes
     let is_rejected = acct.status == PrefixStatus::Rejected;
-    let is_expired =
-        acct.status == PrefixStatus::Pending && Clock::get()?.unix_timestamp > acct.expiry_at;
+    let is_expired = matches!(acct.status, PrefixStatus::Pending | PrefixStatus::NeedsInfo)
+        && Clock::get()?.unix_timestamp > acct.expiry_at;
 
     require!(is_rejected || is_expired, ErrorCode::RefundNotAllowed);
     require_keys_eq!(
@@ -59,6 +74,17 @@ pub fn refund_prefix_fee_handler(ctx: Context<RefundPrefixFee>, _prefix: String)
     **treasury_info.lamports.borrow_mut() -= amount;
     **owner_info.lamports.borrow_mut() += amount;
 
+    // The prefix account closes; the kept log records it as inactive
+    let old_status = acct.status.clone();
+    acct.status = PrefixStatus::Inactive;
+    ctx.accounts.audit_log.record(
+        acct.owner,
+        AuditAction::Refunded,
+        Some(old_status),
+        Some(acct.owner),
+        acct,
+    )?;
+
     // Emit event with all data before closing account
     emit!(PrefixRefunded {
         prefix: acct.prefix.clone(),
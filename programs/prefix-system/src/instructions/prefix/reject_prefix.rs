@@ -1,7 +1,11 @@
 use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::events::PrefixRejected;
-use crate::state::{prefix_account::PrefixStatus, FeeRegistry, PrefixAccount, VerifiersList};
+use crate::events::{ClaimReleaseReason, PrefixRejected, ReviewReleased, ReviewVoteCast};
+use crate::state::{
+    prefix_account::{PrefixStatus, RejectionReason},
+    AuditAction, AuditLog, FeeRegistry, PrefixAccount, ReviewConfig, ReviewState, VerifierRecord,
+};
+use crate::utils::prune_ineligible_votes;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -10,33 +14,137 @@ pub struct RejectPrefix<'info> {
     pub verifier: Signer<'info>,
     #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
     pub fee_registry: Account<'info, FeeRegistry>,
-    #[account(seeds = [VERIFIERS_SEED], bump = verifiers.bump)]
-    pub verifiers: Account<'info, VerifiersList>,
+    #[account(mut, seeds = [VERIFIER_SEED, verifier.key().as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    #[account(seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
     #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
     pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut, seeds = [REVIEW_SEED, prefix.as_bytes()], bump = review_state.bump)]
+    pub review_state: Account<'info, ReviewState>,
 }
 
-pub fn reject_prefix_handler(ctx: Context<RejectPrefix>, prefix: String, reason: String) -> Result<()> {
+pub fn reject_prefix_handler(
+    ctx: Context<RejectPrefix>,
+    prefix: String,
+    reason_code: RejectionReason,
+    evidence_hash: Option<[u8; 32]>,
+    reason: String,
+) -> Result<()> {
     require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
     require!(
-        ctx.accounts
-            .verifiers
-            .verifiers
-            .contains(&ctx.accounts.verifier.key()),
+        reason.len() <= MAX_REJECTION_NOTE_LEN,
+        ErrorCode::RejectionNoteTooLong
+    );
+    let verifier = ctx.accounts.verifier.key();
+    require!(
+        ctx.accounts.verifier_record.active,
         ErrorCode::UnauthorizedVerifier
     );
+    require!(
+        ctx.accounts
+            .verifier_record
+            .can_review(ctx.accounts.review_config.min_verifier_bond),
+        ErrorCode::InsufficientVerifierBond
+    );
+    require!(
+        ctx.accounts.verifier_record.scope.covers(
+            &ctx.accounts.prefix_account.prefix,
+            ctx.accounts.prefix_account.intent_categories,
+        ),
+        ErrorCode::OutOfVerifierScope
+    );
     require!(
         ctx.accounts.prefix_account.status == PrefixStatus::Pending,
         ErrorCode::InvalidPrefixStatus
     );
 
+    let now = Clock::get()?.unix_timestamp;
+
+    // A live review claim reserves the vote for its holder; casting it releases the claim
+    let acct = &mut ctx.accounts.prefix_account;
+    if let Some(holder) = acct.live_claim(now) {
+        require_keys_eq!(holder, verifier, ErrorCode::ReviewAlreadyClaimed);
+        acct.clear_claim();
+        ctx.accounts.verifier_record.clear_claim();
+        emit!(ReviewReleased {
+            prefix: prefix.clone(),
+            verifier,
+            reason: ClaimReleaseReason::Voted,
+            released_at: now,
+        });
+    }
+
+    // Record the vote; one vote per verifier per review round
+    let review = &mut ctx.accounts.review_state;
+    review.sync_round(ctx.accounts.prefix_account.review_round);
+    require!(!review.has_voted(&verifier), ErrorCode::AlreadyVoted);
+    // Votes from verifiers that have since lost review rights are dropped before tallying
+    let min_bond = ctx.accounts.review_config.min_verifier_bond;
+    prune_ineligible_votes(
+        &mut review.approvals,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        min_bond,
+    )?;
+    prune_ineligible_votes(
+        &mut review.rejections,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        min_bond,
+    )?;
+    review.rejections.push(verifier);
+    review.updated_at = now;
+    ctx.accounts
+        .verifier_record
+        .record_vote(false, ctx.accounts.prefix_account.review_started_at, now);
+
+    let threshold = ctx
+        .accounts
+        .review_config
+        .quorum_for(ctx.accounts.prefix_account.prefix.len());
+    let approvals = review.approvals.len() as u8;
+    let rejections = review.rejections.len() as u8;
+    emit!(ReviewVoteCast {
+        prefix: prefix.clone(),
+        verifier,
+        approve: false,
+        approvals,
+        rejections,
+        threshold,
+        voted_at: now,
+    });
+
+    if rejections < threshold {
+        return Ok(());
+    }
+
     let acct = &mut ctx.accounts.prefix_account;
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
     acct.status = PrefixStatus::Rejected;
-    acct.updated_at = Clock::get()?.unix_timestamp;
+    acct.ref_hash = evidence_hash.unwrap_or_default();
+    acct.rejection_reason = Some(reason_code);
+    acct.rejection_evidence = evidence_hash;
+    acct.decided_by = Some(verifier);
+    acct.rejected_at = now;
+    acct.updated_at = now;
+
+    ctx.accounts.audit_log.record(
+        verifier,
+        AuditAction::Rejected,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
 
     emit!(PrefixRejected {
         prefix,
-        verifier: ctx.accounts.verifier.key(),
+        verifier,
+        reason_code,
+        evidence_hash,
         reason,
         rejected_at: acct.updated_at,
     });
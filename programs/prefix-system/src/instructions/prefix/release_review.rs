@@ -0,0 +1,37 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::{ClaimReleaseReason, ReviewReleased};
+use crate::state::{PrefixAccount, VerifierRecord};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct ReleaseReview<'info> {
+    pub verifier: Signer<'info>,
+    #[account(mut, seeds = [VERIFIER_SEED, verifier.key().as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+}
+
+pub fn release_review_handler(ctx: Context<ReleaseReview>, prefix: String) -> Result<()> {
+    let verifier = ctx.accounts.verifier.key();
+    let now = Clock::get()?.unix_timestamp;
+    let acct = &mut ctx.accounts.prefix_account;
+    require!(
+        acct.live_claim(now) == Some(verifier),
+        ErrorCode::ReviewNotClaimed
+    );
+
+    acct.clear_claim();
+    acct.updated_at = now;
+    ctx.accounts.verifier_record.clear_claim();
+
+    emit!(ReviewReleased {
+        prefix,
+        verifier,
+        reason: ClaimReleaseReason::Voluntary,
+        released_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixInfoRequested;
+use crate::state::{
+    prefix_account::PrefixStatus, AuditAction, AuditLog, FeeRegistry, PrefixAccount, ReviewConfig,
+    VerifierRecord,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct RequestPrefixInfo<'info> {
+    pub verifier: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+    #[account(seeds = [VERIFIER_SEED, verifier.key().as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+}
+
+pub fn request_prefix_info_handler(
+    ctx: Context<RequestPrefixInfo>,
+    prefix: String,
+    request_hash: [u8; 32],
+) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+    let verifier = ctx.accounts.verifier.key();
+    let config = &ctx.accounts.review_config;
+    require!(
+        ctx.accounts.verifier_record.active,
+        ErrorCode::UnauthorizedVerifier
+    );
+    require!(
+        ctx.accounts
+            .verifier_record
+            .can_review(config.min_verifier_bond),
+        ErrorCode::InsufficientVerifierBond
+    );
+    require!(
+        ctx.accounts.verifier_record.scope.covers(
+            &ctx.accounts.prefix_account.prefix,
+            ctx.accounts.prefix_account.intent_categories,
+        ),
+        ErrorCode::OutOfVerifierScope
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let acct = &mut ctx.accounts.prefix_account;
+    require!(
+        acct.status == PrefixStatus::Pending,
+        ErrorCode::InvalidPrefixStatus
+    );
+    require!(now <= acct.expiry_at, ErrorCode::PrefixExpired);
+    if let Some(holder) = acct.live_claim(now) {
+        require_keys_eq!(holder, verifier, ErrorCode::ReviewAlreadyClaimed);
+    }
+
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
+    // Give the owner time to respond, within the prefix's lifetime extension budget; once it
+    // is spent, further requests would only restart the votes without extending the review
+    let remaining = config.remaining_extension(acct.expiry_extension_used);
+    require!(remaining > 0, ErrorCode::ExtensionBudgetExhausted);
+    let extension = config.needs_info_extension.min(remaining);
+    acct.expiry_at = acct.expiry_at.saturating_add(extension);
+    acct.expiry_extension_used = acct.expiry_extension_used.saturating_add(extension);
+    acct.info_request_hash = request_hash;
+    acct.status = PrefixStatus::NeedsInfo;
+    acct.updated_at = now;
+
+    ctx.accounts.audit_log.record(
+        verifier,
+        AuditAction::InfoRequested,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
+    emit!(PrefixInfoRequested {
+        prefix,
+        verifier,
+        request_hash,
+        expiry_at: acct.expiry_at,
+        requested_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::{AppealResolved, PrefixActivated};
+use crate::state::{
+    prefix_account::PrefixStatus, Appeal, AuditAction, AuditLog, FeeRegistry, PrefixAccount,
+    ReviewConfig, ReviewState, VerifierRecord,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct ResolveAppeal<'info> {
+    /// Admin, or an active verifier that did not vote to reject
+    pub resolver: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+    /// Required when the resolver is not the admin
+    #[account(seeds = [VERIFIER_SEED, resolver.key().as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Option<Account<'info, VerifierRecord>>,
+    /// CHECK: Treasury PDA
+    #[account(mut, seeds = [TREASURY_SEED, fee_registry.key().as_ref()], bump)]
+    pub treasury: UncheckedAccount<'info>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(seeds = [REVIEW_SEED, prefix.as_bytes()], bump = review_state.bump)]
+    pub review_state: Account<'info, ReviewState>,
+    #[account(mut, close = owner, seeds = [APPEAL_SEED, prefix.as_bytes()], bump = appeal.bump)]
+    pub appeal: Account<'info, Appeal>,
+    /// CHECK: appellant receiving the appeal account rent (and the bond if upheld)
+    #[account(mut, address = appeal.owner)]
+    pub owner: UncheckedAccount<'info>,
+}
+
+pub fn resolve_appeal_handler(
+    ctx: Context<ResolveAppeal>,
+    prefix: String,
+    uphold: bool,
+) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+    require!(
+        ctx.accounts.prefix_account.status == PrefixStatus::UnderAppeal,
+        ErrorCode::InvalidPrefixStatus
+    );
+
+    let resolver = ctx.accounts.resolver.key();
+    if resolver != ctx.accounts.fee_registry.admin {
+        let record = ctx
+            .accounts
+            .verifier_record
+            .as_ref()
+            .ok_or(error!(ErrorCode::UnauthorizedVerifier))?;
+        require!(record.active, ErrorCode::UnauthorizedVerifier);
+        require!(
+            record.can_review(ctx.accounts.review_config.min_verifier_bond),
+            ErrorCode::InsufficientVerifierBond
+        );
+        require!(
+            record.scope.covers(
+                &ctx.accounts.prefix_account.prefix,
+                ctx.accounts.prefix_account.intent_categories,
+            ),
+            ErrorCode::OutOfVerifierScope
+        );
+        // The rejection being appealed was decided in the current review round
+        let review = &ctx.accounts.review_state;
+        require!(
+            review.round != ctx.accounts.prefix_account.review_round
+                || !review.rejections.contains(&resolver),
+            ErrorCode::ConflictedAppealResolver
+        );
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let bond = ctx.accounts.appeal.bond;
+    let acct = &mut ctx.accounts.prefix_account;
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
+    if uphold {
+        // The bond returns to the owner together with the appeal rent on close
+        acct.status = PrefixStatus::Active;
+        // The rejection reference no longer applies; the appeal evidence is kept on its own
+        acct.ref_hash = [0u8; 32];
+        acct.rejection_reason = None;
+        acct.rejection_evidence = None;
+        acct.verified_until = ctx.accounts.review_config.verified_until(now);
+    } else {
+        // Forfeit the bond to the treasury; only the rent goes back on close
+        require!(
+            ctx.accounts.treasury.owner == ctx.program_id,
+            ErrorCode::InvalidTreasuryAccount
+        );
+        let appeal_info = ctx.accounts.appeal.to_account_info();
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        **appeal_info.lamports.borrow_mut() -= bond;
+        **treasury_info.lamports.borrow_mut() += bond;
+        acct.status = PrefixStatus::Rejected;
+        // The owner's grace period restarts from the final decision
+        acct.rejected_at = now;
+    }
+    acct.appeal_evidence = Some(ctx.accounts.appeal.evidence_hash);
+    acct.decided_by = Some(resolver);
+    acct.updated_at = now;
+
+    let action = if uphold {
+        AuditAction::AppealUpheld
+    } else {
+        AuditAction::AppealDenied
+    };
+    ctx.accounts.audit_log.record(
+        resolver,
+        action,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
+    emit!(AppealResolved {
+        prefix: prefix.clone(),
+        resolver,
+        upheld: uphold,
+        bond,
+        resolved_at: now,
+    });
+
+    if uphold {
+        emit!(PrefixActivated {
+            prefix,
+            owner: acct.owner,
+            authority_keys_len: acct.authority_keys.len() as u8,
+            activated_at: now,
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixResubmitted;
+use crate::state::{
+    prefix_account::PrefixStatus, AuditAction, AuditLog, FeeRegistry, PrefixAccount, ProtocolConfig,
+};
+use crate::utils::{
+    resolve_review_window, validate_intent_categories, validate_metadata, verify_ed25519_signature,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct ResubmitPrefix<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+
+    #[account(seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: PDA escrow; ownership asserted at runtime
+    #[account(mut, seeds = [TREASURY_SEED, fee_registry.key().as_ref()], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+
+    /// CHECK: Instructions sysvar for Ed25519 signature verification
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn resubmit_prefix_handler(
+    ctx: Context<ResubmitPrefix>,
+    _prefix: String,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
+    intent_categories: u32,
+    review_window: Option<i64>,
+) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+
+    let acct = &ctx.accounts.prefix_account;
+    require_keys_eq!(
+        ctx.accounts.owner.key(),
+        acct.owner,
+        ErrorCode::UnauthorizedOwnerAction
+    );
+    require!(
+        acct.status == PrefixStatus::Rejected,
+        ErrorCode::InvalidPrefixStatus
+    );
+    validate_metadata(&metadata_uri, &metadata_hash)?;
+    validate_intent_categories(intent_categories)?;
+    let review_window = resolve_review_window(review_window, &ctx.accounts.protocol_config)?;
+    require!(
+        ctx.accounts.treasury.owner == ctx.program_id,
+        ErrorCode::InvalidTreasuryAccount
+    );
+
+    // Fresh owner signature over the corrected metadata
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &ctx.accounts.owner.key(),
+        &metadata_hash,
+    )?;
+
+    // The unrefunded fee is reused; the owner only tops it up if the fee has risen since
+    let fee = ctx.accounts.fee_registry.current_fee;
+    let top_up = fee.saturating_sub(acct.fee_paid);
+    if top_up > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.owner.to_account_info(),
+            to: ctx.accounts.treasury.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, top_up)?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let acct = &mut ctx.accounts.prefix_account;
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
+    acct.metadata_uri = metadata_uri;
+    acct.metadata_hash = metadata_hash;
+    acct.intent_categories = intent_categories;
+    acct.ref_hash = [0u8; 32];
+    acct.status = PrefixStatus::Pending;
+    acct.fee_paid += top_up;
+    acct.expiry_at = now + review_window;
+    // Each review round gets the full extension budget for info requests and extensions
+    acct.expiry_extension_used = 0;
+    acct.updated_at = now;
+    acct.review_round = acct.review_round.wrapping_add(1);
+    acct.review_started_at = now;
+    acct.rejection_reason = None;
+    acct.rejection_evidence = None;
+    acct.appeal_evidence = None;
+    acct.decided_by = None;
+    acct.resubmission_count = acct.resubmission_count.saturating_add(1);
+
+    ctx.accounts.audit_log.record(
+        acct.owner,
+        AuditAction::Resubmitted,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
+    emit!(PrefixResubmitted {
+        prefix: acct.prefix.clone(),
+        owner: acct.owner,
+        metadata_hash: acct.metadata_hash,
+        metadata_uri: acct.metadata_uri.clone(),
+        fee_top_up: top_up,
+        resubmission_count: acct.resubmission_count,
+        expiry_at: acct.expiry_at,
+        resubmitted_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixResumedByOwner;
+use crate::state::{prefix_account::PrefixStatus, AuditAction, AuditLog, PrefixAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct ResumePrefix<'info> {
+    pub owner: Signer<'info>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+}
+
+pub fn resume_prefix_handler(ctx: Context<ResumePrefix>, prefix: String) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let acct = &mut ctx.accounts.prefix_account;
+    require_keys_eq!(owner, acct.owner, ErrorCode::UnauthorizedOwnerAction);
+    require!(
+        acct.status == PrefixStatus::OwnerPaused,
+        ErrorCode::InvalidPrefixStatus
+    );
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
+    acct.status = PrefixStatus::Active;
+    acct.updated_at = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.audit_log.record(
+        owner,
+        AuditAction::OwnerResumed,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
+    emit!(PrefixResumedByOwner {
+        prefix,
+        owner,
+        at: acct.updated_at,
+    });
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use super::submit_prefix_with_fee::*;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixRevealed;
+use crate::state::PrefixCommitment;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct RevealPrefix<'info> {
+    /// Same accounts as a direct submission
+    pub submit: SubmitPrefixWithFee<'info>,
+    #[account(
+        mut,
+        seeds = [COMMITMENT_SEED, submit.owner.key().as_ref(), prefix_commitment.commitment.as_ref()],
+        bump = prefix_commitment.bump,
+    )]
+    pub prefix_commitment: Account<'info, PrefixCommitment>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn reveal_prefix_handler(
+    ctx: Context<RevealPrefix>,
+    prefix: String,
+    salt: [u8; 32],
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
+    authority_keys: Vec<Pubkey>,
+    intent_categories: u32,
+    review_window: Option<i64>,
+) -> Result<()> {
+    let owner = ctx.accounts.submit.owner.key();
+    let record = &ctx.accounts.prefix_commitment;
+    let commitment = record.commitment;
+    // Binding the owner means a copied reveal can't be replayed from another wallet
+    let expected = hashv(&[prefix.as_bytes(), owner.as_ref(), &salt]).to_bytes();
+    require!(expected == commitment, ErrorCode::CommitmentMismatch);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= record.reveal_after, ErrorCode::RevealTooEarly);
+    require!(now <= record.expires_at, ErrorCode::CommitmentExpired);
+
+    register_prefix(
+        &mut ctx.accounts.submit,
+        &ctx.bumps.submit,
+        ctx.program_id,
+        prefix.clone(),
+        metadata_uri,
+        metadata_hash,
+        authority_keys,
+        intent_categories,
+        review_window,
+    )?;
+
+    // Deposit and rent go back to the owner
+    ctx.accounts
+        .prefix_commitment
+        .close(ctx.accounts.submit.owner.to_account_info())?;
+
+    emit!(PrefixRevealed {
+        prefix,
+        owner,
+        commitment,
+        revealed_at: now,
+    });
+    Ok(())
+}
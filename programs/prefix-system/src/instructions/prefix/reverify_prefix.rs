@@ -0,0 +1,64 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixReverified;
+use crate::state::{
+    prefix_account::PrefixStatus, FeeRegistry, PrefixAccount, ReviewConfig, VerifierRecord,
+};
+use anchor_lang::prelude::*;
+
+/// Renews `verified_until` on an active prefix without a metadata resubmission
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct ReverifyPrefix<'info> {
+    pub verifier: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+    #[account(mut, seeds = [VERIFIER_SEED, verifier.key().as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+}
+
+pub fn reverify_prefix_handler(ctx: Context<ReverifyPrefix>, prefix: String) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+    let verifier = ctx.accounts.verifier.key();
+    let config = &ctx.accounts.review_config;
+    require!(
+        ctx.accounts.verifier_record.active,
+        ErrorCode::UnauthorizedVerifier
+    );
+    require!(
+        ctx.accounts
+            .verifier_record
+            .can_review(config.min_verifier_bond),
+        ErrorCode::InsufficientVerifierBond
+    );
+    require!(
+        ctx.accounts.verifier_record.scope.covers(
+            &ctx.accounts.prefix_account.prefix,
+            ctx.accounts.prefix_account.intent_categories,
+        ),
+        ErrorCode::OutOfVerifierScope
+    );
+
+    let acct = &mut ctx.accounts.prefix_account;
+    require!(
+        acct.status == PrefixStatus::Active,
+        ErrorCode::InvalidPrefixStatus
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    acct.verified_until = config.verified_until(now);
+    acct.updated_at = now;
+    ctx.accounts.verifier_record.last_action_at = now;
+
+    emit!(PrefixReverified {
+        prefix,
+        verifier,
+        verified_until: acct.verified_until,
+        reverified_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::{AuctionSettled, PrefixActivated};
+use crate::state::{
+    prefix_account::PrefixStatus, AuditAction, AuditLog, Auction, FeeRegistry, PrefixAccount,
+    ProtocolConfig, ReviewConfig, ReviewState, SkeletonGuard,
+};
+use crate::utils::{
+    assert_not_tombstoned, skeleton_prefix, validate_intent_categories, validate_metadata,
+    verify_ed25519_signature,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct SettleAuction<'info> {
+    /// Winning bidder; pays rent for the new prefix accounts
+    #[account(mut)]
+    pub winner: Signer<'info>,
+    /// CHECK: Protocol admin, refunded the auction rent it paid at opening
+    #[account(mut, address = fee_registry.admin)]
+    pub admin: UncheckedAccount<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Box<Account<'info, FeeRegistry>>,
+    #[account(seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    #[account(seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Box<Account<'info, ReviewConfig>>,
+    /// CHECK: Treasury PDA, receives the winning bid
+    #[account(mut, seeds = [TREASURY_SEED, fee_registry.key().as_ref()], bump)]
+    pub treasury: UncheckedAccount<'info>,
+    #[account(mut, close = admin, seeds = [AUCTION_SEED, prefix.as_bytes()], bump = auction.bump)]
+    pub auction: Box<Account<'info, Auction>>,
+    #[account(
+        init,
+        payer = winner,
+        space = PrefixAccount::space(MAX_PREFIX_LEN, MAX_URI_LEN, MAX_AUTH_KEYS),
+        seeds = [PREFIX_SEED, prefix.as_bytes()],
+        bump,
+    )]
+    pub prefix_account: Box<Account<'info, PrefixAccount>>,
+    #[account(
+        init,
+        payer = winner,
+        space = ReviewState::space(MAX_QUORUM),
+        seeds = [REVIEW_SEED, prefix.as_bytes()],
+        bump,
+    )]
+    pub review_state: Box<Account<'info, ReviewState>>,
+    /// Taken when the auction opened; now belongs to the winner's prefix
+    #[account(
+        seeds = [SKELETON_SEED, (skeleton_prefix(&prefix).as_bytes())],
+        bump = skeleton_guard.bump,
+    )]
+    pub skeleton_guard: Box<Account<'info, SkeletonGuard>>,
+    /// Kept from an earlier registration of the name if it was revoked
+    #[account(
+        init_if_needed,
+        payer = winner,
+        space = AuditLog::space(MAX_PREFIX_LEN, MAX_AUDIT_ENTRIES),
+        seeds = [AUDIT_LOG_SEED, prefix.as_bytes()],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    /// CHECK: revocation tombstone for this prefix's skeleton; may be uninitialized
+    #[account(seeds = [TOMBSTONE_SEED, (skeleton_prefix(&prefix).as_bytes())], bump)]
+    pub tombstone: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar for Ed25519 signature verification
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn settle_auction_handler(
+    ctx: Context<SettleAuction>,
+    prefix: String,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
+    authority_keys: Vec<Pubkey>,
+    intent_categories: u32,
+) -> Result<()> {
+    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+    validate_metadata(&metadata_uri, &metadata_hash)?;
+    validate_intent_categories(intent_categories)?;
+    require!(authority_keys.len() <= MAX_AUTH_KEYS, ErrorCode::AuthorityKeysTooMany);
+    require!(
+        ctx.accounts.treasury.owner == ctx.program_id,
+        ErrorCode::InvalidTreasuryAccount
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    assert_not_tombstoned(&ctx.accounts.tombstone.to_account_info(), ctx.program_id, now)?;
+    let auction = &ctx.accounts.auction;
+    require!(now >= auction.end_at, ErrorCode::AuctionNotEnded);
+    let winner = auction.highest_bidder.ok_or(ErrorCode::AuctionNoBids)?;
+    require_keys_eq!(
+        ctx.accounts.winner.key(),
+        winner,
+        ErrorCode::UnauthorizedOwnerAction
+    );
+    // Same proof of metadata ownership as a regular submission
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &winner,
+        &metadata_hash,
+    )?;
+    let price = auction.highest_bid;
+    let activate = auction.activate_on_settle;
+
+    // Escrowed bid goes to the treasury; the auction's rent returns to the admin on close
+    let auction_info = ctx.accounts.auction.to_account_info();
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    **auction_info.lamports.borrow_mut() -= price;
+    **treasury_info.lamports.borrow_mut() += price;
+
+    let data = &mut ctx.accounts.prefix_account;
+    data.owner = winner;
+    data.prefix = prefix.clone();
+    data.metadata_uri = metadata_uri;
+    data.metadata_hash = metadata_hash;
+    data.ref_hash = [0u8; 32];
+    data.authority_keys = authority_keys;
+    // The winning bid stands in for the submission fee, refundable on the same terms
+    data.fee_paid = price;
+    data.created_at = now;
+    data.updated_at = now;
+    data.bump = ctx.bumps.prefix_account;
+    data.review_round = 0;
+    data.review_started_at = now;
+    data.claimed_by = None;
+    data.claim_expires_at = 0;
+    data.rejection_reason = None;
+    data.rejection_evidence = None;
+    data.resubmission_count = 0;
+    data.appeal_evidence = None;
+    data.decided_by = None;
+    data.info_request_hash = [0u8; 32];
+    data.expiry_extension_used = 0;
+    data.intent_categories = intent_categories;
+    data.parent = None;
+    data.depth = 0;
+    data.sub_prefix_count = 0;
+    data.rejected_at = 0;
+    data.clear_suspension();
+    data.frozen_by = None;
+    data.deactivated_from = None;
+    data.listed = false;
+    if activate {
+        data.status = PrefixStatus::Active;
+        data.expiry_at = now;
+        data.verified_until = ctx.accounts.review_config.verified_until(now);
+    } else {
+        data.status = PrefixStatus::Pending;
+        data.expiry_at = now + ctx.accounts.protocol_config.max_expiry_duration;
+        data.verified_until = 0;
+    }
+
+    let review = &mut ctx.accounts.review_state;
+    review.round = 0;
+    review.approvals = Vec::new();
+    review.rejections = Vec::new();
+    review.bump = ctx.bumps.review_state;
+    review.created_at = now;
+    review.updated_at = now;
+
+    let log = &mut ctx.accounts.audit_log;
+    log.open(&prefix, ctx.bumps.audit_log);
+    log.record(
+        winner,
+        AuditAction::AuctionSettled,
+        None,
+        None,
+        &ctx.accounts.prefix_account,
+    )?;
+
+    emit!(AuctionSettled {
+        prefix: prefix.clone(),
+        winner,
+        price,
+        activated: activate,
+        settled_at: now,
+    });
+    if activate {
+        emit!(PrefixActivated {
+            prefix,
+            owner: winner,
+            authority_keys_len: ctx.accounts.prefix_account.authority_keys.len() as u8,
+            activated_at: now,
+        });
+    }
+    Ok(())
+}
@@ -3,8 +3,14 @@ use anchor_lang::system_program;
 use crate::constants::*;
 use anchor_lang::prelude::*;
 use crate::events::PrefixSubmitted;
-use crate::state::{FeeRegistry, PrefixAccount};
-use crate::utils::{normalize_prefix, validate_metadata, verify_ed25519_signature};
+use crate::state::{
+    AuditAction, AuditLog, FeeRegistry, PrefixAccount, ProtocolConfig, ReviewState, SkeletonGuard,
+};
+use crate::utils::{
+    assert_not_tombstoned, assert_prefix_available, normalize_prefix, prefix_root,
+    resolve_review_window, skeleton_prefix, validate_intent_categories, validate_metadata,
+    verify_ed25519_signature,
+};
 
 
 #[derive(Accounts)]
@@ -17,6 +23,9 @@ pub struct SubmitPrefixWithFee<'info> {
     #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
     pub fee_registry: Account<'info, FeeRegistry>,
 
+    #[account(seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     /// CHECK: PDA escrow; ownership asserted at runtime
     #[account(mut, seeds = [TREASURY_SEED, fee_registry.key().as_ref()], bump)]
     pub treasury: UncheckedAccount<'info>,
@@ -30,6 +39,59 @@ pub struct SubmitPrefixWithFee<'info> {
     )]
     pub prefix_account: Account<'info, PrefixAccount>,
 
+    #[account(
+        init,
+        payer = owner,
+        space = ReviewState::space(MAX_QUORUM),
+        seeds = [REVIEW_SEED, prefix.as_bytes()],
+        bump,
+    )]
+    pub review_state: Account<'info, ReviewState>,
+
+    /// Fails to initialize if a confusable prefix already holds the same skeleton
+    #[account(
+        init,
+        payer = owner,
+        space = SkeletonGuard::space(MAX_PREFIX_LEN),
+        seeds = [SKELETON_SEED, (skeleton_prefix(&prefix).as_bytes())],
+        bump,
+    )]
+    pub skeleton_guard: Account<'info, SkeletonGuard>,
+
+    /// Kept from an earlier registration of the name if it was revoked
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = AuditLog::space(MAX_PREFIX_LEN, MAX_AUDIT_ENTRIES),
+        seeds = [AUDIT_LOG_SEED, prefix.as_bytes()],
+        bump,
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
+    /// CHECK: reserved-names registry entry for this prefix's skeleton (covering confusable
+    /// variants); may be uninitialized
+    #[account(seeds = [RESERVED_SEED, (skeleton_prefix(&prefix).as_bytes())], bump)]
+    pub reserved_prefix: UncheckedAccount<'info>,
+
+    /// CHECK: skeleton guard of the part before the first hyphen; required for hyphenated
+    /// names and must be uninitialized, so neither the root nor a confusable of it is registered
+    #[account(seeds = [SKELETON_SEED, (skeleton_prefix(prefix_root(&prefix)).as_bytes())], bump)]
+    pub root_skeleton_guard: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: reserved-names registry entry for the root's skeleton; required for hyphenated
+    /// names; may be uninitialized
+    #[account(seeds = [RESERVED_SEED, (skeleton_prefix(prefix_root(&prefix)).as_bytes())], bump)]
+    pub root_reserved_prefix: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: revocation tombstone for the root's skeleton; required for hyphenated names;
+    /// may be uninitialized
+    #[account(seeds = [TOMBSTONE_SEED, (skeleton_prefix(prefix_root(&prefix)).as_bytes())], bump)]
+    pub root_tombstone: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: revocation tombstone for this prefix's skeleton; may be uninitialized
+    #[account(seeds = [TOMBSTONE_SEED, (skeleton_prefix(&prefix).as_bytes())], bump)]
+    pub tombstone: UncheckedAccount<'info>,
+
     /// CHECK: Instructions sysvar for Ed25519 signature verification
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
     pub instructions_sysvar: UncheckedAccount<'info>,
@@ -43,28 +105,100 @@ pub fn submit_prefix_with_fee_handler(
     prefix: String,
     metadata_uri: String,
     metadata_hash: [u8; 32],
-    authority_keys: Vec<Pubkey>
+    authority_keys: Vec<Pubkey>,
+    intent_categories: u32,
+    review_window: Option<i64>,
+) -> Result<()> {
+    // With commit-reveal enforced, a plain submission would expose the name to front-running
+    require!(
+        !ctx.accounts.protocol_config.commit_required,
+        ErrorCode::CommitRevealRequired
+    );
+    register_prefix(
+        ctx.accounts,
+        &ctx.bumps,
+        ctx.program_id,
+        prefix,
+        metadata_uri,
+        metadata_hash,
+        authority_keys,
+        intent_categories,
+        review_window,
+    )
+}
+
+/// Registration shared by submit_prefix_with_fee and reveal_prefix, so both apply the same checks
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn register_prefix<'info>(
+    accounts: &mut SubmitPrefixWithFee<'info>,
+    bumps: &SubmitPrefixWithFeeBumps,
+    program_id: &Pubkey,
+    prefix: String,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
+    authority_keys: Vec<Pubkey>,
+    intent_categories: u32,
+    review_window: Option<i64>,
 ) -> Result<()> {
     // Pause check
-    require!(!ctx.accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
+    require!(!accounts.fee_registry.pause, ErrorCode::FeeOperationsPaused);
 
     // Normalize and validate inputs
-    let normalized = normalize_prefix(&prefix)?;
+    let normalized = normalize_prefix(&prefix, &accounts.protocol_config)?;
     require!(prefix == normalized, ErrorCode::InvalidPrefixFormat);
     validate_metadata(&metadata_uri, &metadata_hash)?;
+    validate_intent_categories(intent_categories)?;
     require!(authority_keys.len() <= MAX_AUTH_KEYS, ErrorCode::AuthorityKeysTooMany);
+    let review_window = resolve_review_window(review_window, &accounts.protocol_config)?;
+
+    // A hyphenated top-level name must not pose as a sub-prefix of a registered prefix, of a
+    // confusable of one, or of a name the root itself could not be registered as
+    if normalized.contains(SUB_PREFIX_SEPARATOR) {
+        let guard = accounts
+            .root_skeleton_guard
+            .as_ref()
+            .ok_or(error!(ErrorCode::PrefixRootRegistered))?;
+        let reserved = accounts
+            .root_reserved_prefix
+            .as_ref()
+            .ok_or(error!(ErrorCode::PrefixRootRegistered))?;
+        let tombstone = accounts
+            .root_tombstone
+            .as_ref()
+            .ok_or(error!(ErrorCode::PrefixRootRegistered))?;
+        require!(guard.data_is_empty(), ErrorCode::PrefixRootRegistered);
+        assert_prefix_available(&reserved.to_account_info(), program_id, &accounts.owner.key())?;
+        assert_not_tombstoned(
+            &tombstone.to_account_info(),
+            program_id,
+            Clock::get()?.unix_timestamp,
+        )?;
+    }
+
+    // Blocked names are refused outright; reserved ones only accept their claimant
+    assert_prefix_available(
+        &accounts.reserved_prefix.to_account_info(),
+        program_id,
+        &accounts.owner.key(),
+    )?;
+    // Revoked names stay unavailable until their cooldown ends
+    assert_not_tombstoned(
+        &accounts.tombstone.to_account_info(),
+        program_id,
+        Clock::get()?.unix_timestamp,
+    )?;
     
     // Verify treasury is owned by this program (PDA)
     require!(
-        ctx.accounts.treasury.owner == ctx.program_id,
+        accounts.treasury.owner == program_id,
         ErrorCode::InvalidTreasuryAccount
     );
 
     // Verify Ed25519 signature to ensure owner signed the metadata hash
     // This is required for all submissions to ensure cryptographic proof of ownership
     verify_ed25519_signature(
-        &ctx.accounts.instructions_sysvar.to_account_info(),
-        &ctx.accounts.owner.key(),
+        &accounts.instructions_sysvar.to_account_info(),
+        &accounts.owner.key(),
         &metadata_hash,
     )?;
  
@@ -72,22 +206,22 @@ pub fn submit_prefix_with_fee_handler(
     // This program-level check relies on comparing lamports delta is not directly accessible.
     // As a pragmatic approach, require that fee is transferred via a separate ix before this handler
     // OR attach the transfer here using CPI signed by owner. We do the latter.
-    let fee = ctx.accounts.fee_registry.current_fee;
+    let fee = accounts.fee_registry.current_fee;
     require!(fee > 0, ErrorCode::InsufficientFee);
 
     // owner is signer, treasury is destination PDA
     let cpi_accounts = system_program::Transfer {
-        from: ctx.accounts.owner.to_account_info(),
-        to: ctx.accounts.treasury.to_account_info(),
+        from: accounts.owner.to_account_info(),
+        to: accounts.treasury.to_account_info(),
     };
-    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    let cpi_ctx = CpiContext::new(accounts.system_program.to_account_info(), cpi_accounts);
     system_program::transfer(cpi_ctx, fee)?;
 
     // Populate account
     let now = Clock::get()?.unix_timestamp;
-    let bump = ctx.bumps.prefix_account;
-    let data = &mut ctx.accounts.prefix_account;
-    data.owner = ctx.accounts.owner.key();
+    let bump = bumps.prefix_account;
+    let data = &mut accounts.prefix_account;
+    data.owner = accounts.owner.key();
     data.prefix = normalized.clone();
     data.metadata_uri = metadata_uri;
     data.metadata_hash = metadata_hash;
@@ -95,19 +229,64 @@ pub fn submit_prefix_with_fee_handler(
     data.status = crate::state::prefix_account::PrefixStatus::Pending;
     data.authority_keys = authority_keys;
     data.fee_paid = fee;
-    data.expiry_at = now + MAX_EXPIRY_DURATION as i64;
+    data.expiry_at = now + review_window;
     data.created_at = now;
     data.updated_at = now;
     data.bump = bump;
+    data.review_round = 0;
+    data.review_started_at = now;
+    data.claimed_by = None;
+    data.claim_expires_at = 0;
+    data.rejection_reason = None;
+    data.rejection_evidence = None;
+    data.resubmission_count = 0;
+    data.appeal_evidence = None;
+    data.decided_by = None;
+    data.info_request_hash = [0u8; 32];
+    data.expiry_extension_used = 0;
+    data.verified_until = 0;
+    data.intent_categories = intent_categories;
+    data.parent = None;
+    data.depth = 0;
+    data.sub_prefix_count = 0;
+    data.rejected_at = 0;
+    data.clear_suspension();
+    data.frozen_by = None;
+    data.deactivated_from = None;
+    data.listed = false;
+
+    let review = &mut accounts.review_state;
+    review.round = 0;
+    review.approvals = Vec::new();
+    review.rejections = Vec::new();
+    review.bump = bumps.review_state;
+    review.created_at = now;
+    review.updated_at = now;
+
+    let guard = &mut accounts.skeleton_guard;
+    guard.prefix = normalized.clone();
+    guard.bump = bumps.skeleton_guard;
+
+    let log = &mut accounts.audit_log;
+    log.open(&normalized, bumps.audit_log);
+    log.record(
+        accounts.owner.key(),
+        AuditAction::Submitted,
+        None,
+        None,
+        &accounts.prefix_account,
+    )?;
 
+    let data = &accounts.prefix_account;
     emit!(PrefixSubmitted {
+        skeleton: skeleton_prefix(&normalized),
         prefix: normalized,
-        owner: ctx.accounts.owner.key(),
+        owner: accounts.owner.key(),
         metadata_hash: data.metadata_hash,
         metadata_uri: data.metadata_uri.clone(),
         fee_paid: fee,
         created_at: now,
-        pending_pda: ctx.accounts.prefix_account.key(),
+        pending_pda: accounts.prefix_account.key(),
     });
     Ok(())
 }
@@ -0,0 +1,75 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixSuspended;
+use crate::state::{
+    prefix_account::PrefixStatus, AuditAction, AuditLog, FeeRegistry, PrefixAccount,
+    SuspensionReason,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct SuspendPrefix<'info> {
+    pub admin: Signer<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+}
+
+pub fn suspend_prefix_handler(
+    ctx: Context<SuspendPrefix>,
+    prefix: String,
+    duration: i64,
+    reason: SuspensionReason,
+    evidence_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    let admin = ctx.accounts.admin.key();
+    require_keys_eq!(
+        admin,
+        ctx.accounts.fee_registry.admin,
+        ErrorCode::UnauthorizedAdmin
+    );
+    require!(
+        duration > 0 && duration <= MAX_SUSPENSION_DURATION,
+        ErrorCode::InvalidSuspensionDuration
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let acct = &mut ctx.accounts.prefix_account;
+    require!(
+        acct.status == PrefixStatus::Active,
+        ErrorCode::InvalidPrefixStatus
+    );
+    // A running suspension is lengthened with extend_suspension instead
+    require!(!acct.is_suspended(now), ErrorCode::PrefixAlreadySuspended);
+
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
+    // Status stays Active so the prefix is trusted again once suspended_until passes
+    acct.suspended_until = now.saturating_add(duration);
+    acct.suspension_reason = Some(reason);
+    acct.suspension_evidence = evidence_hash;
+    acct.suspended_by = Some(admin);
+    acct.updated_at = now;
+
+    ctx.accounts.audit_log.record(
+        admin,
+        AuditAction::Suspended,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
+    emit!(PrefixSuspended {
+        prefix,
+        admin,
+        reason,
+        evidence_hash,
+        suspended_until: acct.suspended_until,
+        at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixSwept;
+use crate::state::{
+    prefix_account::PrefixStatus, AuditAction, AuditLog, FeeRegistry, PrefixAccount, ProtocolConfig, ReviewState,
+    SkeletonGuard,
+};
+use crate::utils::skeleton_prefix;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct SweepExpiredPrefix<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    /// CHECK: Recorded prefix owner, receives the refund and rent
+    #[account(mut, address = prefix_account.owner @ ErrorCode::UnauthorizedOwnerAction)]
+    pub owner: UncheckedAccount<'info>,
+    #[account(seeds = [FEE_REGISTRY_SEED], bump = fee_registry.bump)]
+    pub fee_registry: Account<'info, FeeRegistry>,
+    #[account(seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    /// CHECK: Treasury PDA
+    #[account(mut, seeds = [TREASURY_SEED, fee_registry.key().as_ref()], bump)]
+    pub treasury: UncheckedAccount<'info>,
+    #[account(mut, close = owner, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, close = owner, seeds = [REVIEW_SEED, prefix.as_bytes()], bump = review_state.bump)]
+    pub review_state: Account<'info, ReviewState>,
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SKELETON_SEED, (skeleton_prefix(&prefix).as_bytes())],
+        bump = skeleton_guard.bump,
+    )]
+    pub skeleton_guard: Account<'info, SkeletonGuard>,
+    /// Kept open so the sweep stays on record
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+    /// Required for a sub-prefix; its child count is decremented
+    #[account(mut)]
+    pub parent_account: Option<Box<Account<'info, PrefixAccount>>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn sweep_expired_prefix_handler(
+    ctx: Context<SweepExpiredPrefix>,
+    _prefix: String,
+) -> Result<()> {
+    // Sweeping refunds a fee, so it follows the fee pause like refund_prefix_fee
+    require!(
+        !ctx.accounts.fee_registry.pause,
+        ErrorCode::FeeOperationsPaused
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let acct = &ctx.accounts.prefix_account;
+    // Abandoned reviews once they expire, rejections once the owner had time to appeal or refund
+    let is_expired = matches!(acct.status, PrefixStatus::Pending | PrefixStatus::NeedsInfo)
+        && now > acct.expiry_at;
+    let is_stale_rejection = acct.status == PrefixStatus::Rejected
+        && now
+            > acct
+                .rejected_at
+                .saturating_add(ctx.accounts.protocol_config.rejected_grace_period);
+    require!(is_expired || is_stale_rejection, ErrorCode::SweepNotAllowed);
+    // A parent would leave its children dangling; a sub-prefix frees its slot in the parent
+    require!(acct.sub_prefix_count == 0, ErrorCode::SweepNotAllowed);
+    if let Some(parent_key) = acct.parent {
+        let parent = ctx
+            .accounts
+            .parent_account
+            .as_mut()
+            .ok_or(error!(ErrorCode::MissingAncestorAccount))?;
+        require_keys_eq!(parent.key(), parent_key, ErrorCode::MissingAncestorAccount);
+        parent.sub_prefix_count = parent.sub_prefix_count.saturating_sub(1);
+        parent.updated_at = now;
+    }
+
+    // Treasury is a PDA owned by this program
+    require!(
+        ctx.accounts.treasury.to_account_info().owner == ctx.program_id,
+        ErrorCode::InvalidTreasuryAccount
+    );
+
+    let amount = acct.fee_paid;
+    require!(
+        ctx.accounts.treasury.to_account_info().lamports() >= amount,
+        ErrorCode::InsufficientTreasuryBalance
+    );
+
+    // The caller's reward comes out of the fee, never out of the treasury's own funds
+    let reward = ctx.accounts.protocol_config.sweep_reward.min(amount);
+    let refund = amount - reward;
+
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    let owner_info = ctx.accounts.owner.to_account_info();
+    let caller_info = ctx.accounts.caller.to_account_info();
+
+    **treasury_info.lamports.borrow_mut() -= amount;
+    **owner_info.lamports.borrow_mut() += refund;
+    **caller_info.lamports.borrow_mut() += reward;
+
+    // The prefix account closes; the kept log records it as inactive
+    let caller = ctx.accounts.caller.key();
+    let acct = &mut ctx.accounts.prefix_account;
+    let old_status = acct.status.clone();
+    acct.status = PrefixStatus::Inactive;
+    ctx.accounts.audit_log.record(
+        caller,
+        AuditAction::Swept,
+        Some(old_status),
+        Some(acct.owner),
+        acct,
+    )?;
+
+    emit!(PrefixSwept {
+        prefix: acct.prefix.clone(),
+        owner: acct.owner,
+        caller,
+        refund,
+        reward,
+        swept_at: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PrefixUnfrozen;
+use crate::state::{prefix_account::PrefixStatus, AuditAction, AuditLog, PrefixAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct UnfreezePrefix<'info> {
+    pub owner: Signer<'info>,
+    #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
+    pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
+}
+
+pub fn unfreeze_prefix_handler(ctx: Context<UnfreezePrefix>, prefix: String) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let acct = &mut ctx.accounts.prefix_account;
+    require_keys_eq!(owner, acct.owner, ErrorCode::UnauthorizedOwnerAction);
+    require!(
+        acct.status == PrefixStatus::Frozen,
+        ErrorCode::InvalidPrefixStatus
+    );
+    // The key that raised the alarm has to be rotated out via update_prefix_authority first
+    let frozen_by = acct.frozen_by.ok_or(ErrorCode::InvalidPrefixStatus)?;
+    require!(
+        !acct.authority_keys.contains(&frozen_by),
+        ErrorCode::FreezingKeyStillAuthorized
+    );
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
+    acct.status = PrefixStatus::Active;
+    acct.frozen_by = None;
+    acct.updated_at = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.audit_log.record(
+        owner,
+        AuditAction::Unfrozen,
+        Some(old_status),
+        Some(old_owner),
+        acct,
+    )?;
+
+    emit!(PrefixUnfrozen {
+        prefix,
+        owner,
+        frozen_by,
+        at: acct.updated_at,
+    });
+    Ok(())
+}
@@ -32,6 +32,7 @@ pub fn update_prefix_authority_handler(
         acct.status != PrefixStatus::Rejected,
         ErrorCode::InvalidPrefixStatus
     );
+    require!(!acct.listed, ErrorCode::PrefixListed);
     acct.authority_keys = authority_keys.clone();
     // Authority updates do NOT change status - they are seamless for devs/users
     // Only metadata updates require re-approval (trust context change)
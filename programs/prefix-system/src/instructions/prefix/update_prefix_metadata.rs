@@ -1,16 +1,24 @@
 use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::events::PrefixMetadataUpdated;
-use crate::state::{prefix_account::PrefixStatus, PrefixAccount};
-use crate::utils::{validate_metadata, verify_ed25519_signature};
+use crate::events::{PrefixInfoProvided, PrefixMetadataUpdated};
+use crate::state::{
+    prefix_account::PrefixStatus, AuditAction, AuditLog, PrefixAccount, ProtocolConfig,
+};
+use crate::utils::{
+    resolve_review_window, validate_intent_categories, validate_metadata, verify_ed25519_signature,
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 #[instruction(prefix: String)]
 pub struct UpdatePrefixMetadata<'info> {
     pub owner: Signer<'info>,
+    #[account(seeds = [PROTOCOL_CONFIG_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
     #[account(mut, seeds = [PREFIX_SEED, prefix.as_bytes()], bump = prefix_account.bump)]
     pub prefix_account: Account<'info, PrefixAccount>,
+    #[account(mut, seeds = [AUDIT_LOG_SEED, prefix.as_bytes()], bump = audit_log.bump)]
+    pub audit_log: Account<'info, AuditLog>,
     
     /// CHECK: Instructions sysvar for Ed25519 signature verification
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
@@ -22,6 +30,7 @@ pub fn update_prefix_metadata_handler(
     _prefix: String,
     new_uri: String,
     new_hash: [u8; 32],
+    intent_categories: u32,
 ) -> Result<()> {
     let acct = &mut ctx.accounts.prefix_account;
     require_keys_eq!(
@@ -29,12 +38,22 @@ pub fn update_prefix_metadata_handler(
         acct.owner,
         ErrorCode::UnauthorizedOwnerAction
     );
-    // Rejected prefixes cannot be updated
+    // Rejected prefixes cannot be updated, nor can one whose appeal is being decided;
+    // paused or frozen ones must return to Active first so a metadata change can't ride
+    // an owner resume past re-approval
     require!(
-        acct.status != PrefixStatus::Rejected,
+        !matches!(
+            acct.status,
+            PrefixStatus::Rejected
+                | PrefixStatus::UnderAppeal
+                | PrefixStatus::OwnerPaused
+                | PrefixStatus::Frozen
+        ),
         ErrorCode::InvalidPrefixStatus
     );
+    require!(!acct.listed, ErrorCode::PrefixListed);
     validate_metadata(&new_uri, &new_hash)?;
+    validate_intent_categories(intent_categories)?;
 
     // Verify Ed25519 signature to ensure owner signed the new metadata hash
     // This is required for all metadata updates to ensure cryptographic proof of ownership
@@ -46,16 +65,53 @@ pub fn update_prefix_metadata_handler(
 
     let now = Clock::get()?.unix_timestamp;
     let old_hash = acct.metadata_hash;
+    let old_status = acct.status.clone();
+    let old_owner = acct.owner;
     acct.metadata_uri = new_uri;
     acct.metadata_hash = new_hash;
+    acct.intent_categories = intent_categories;
     // If Active, flip back to Pending for re-approval (metadata affects trust context)
-    // If Pending, Rejected, or Inactive, status remains unchanged
+    // If NeedsInfo, this is the owner's answer and review resumes
+    // If Pending, votes already cast were for the old content and start over
+    // If Inactive, status remains unchanged
     if acct.status == PrefixStatus::Active {
         acct.status = PrefixStatus::Pending;
         acct.ref_hash = [0u8; 32];
+        acct.review_round = acct.review_round.wrapping_add(1);
+        acct.review_started_at = now;
+        acct.decided_by = None;
+        // A fresh review window; the one from the original submission is long past
+        acct.expiry_at = now + resolve_review_window(None, &ctx.accounts.protocol_config)?;
+        acct.expiry_extension_used = 0;
+    } else if acct.status == PrefixStatus::NeedsInfo {
+        acct.status = PrefixStatus::Pending;
+        acct.review_round = acct.review_round.wrapping_add(1);
+        acct.review_started_at = now;
+        emit!(PrefixInfoProvided {
+            prefix: acct.prefix.clone(),
+            owner: acct.owner,
+            request_hash: acct.info_request_hash,
+            new_metadata_hash: new_hash,
+            provided_at: now,
+        });
+        acct.info_request_hash = [0u8; 32];
+    } else if acct.status == PrefixStatus::Pending {
+        acct.review_round = acct.review_round.wrapping_add(1);
+        acct.review_started_at = now;
     }
     acct.updated_at = now;
 
+    // Only metadata changes that move the prefix back into review are transitions
+    if acct.status != old_status {
+        ctx.accounts.audit_log.record(
+            ctx.accounts.owner.key(),
+            AuditAction::MetadataUpdated,
+            Some(old_status),
+            Some(old_owner),
+            acct,
+        )?;
+    }
+
     emit!(PrefixMetadataUpdated {
         prefix: acct.prefix.clone(),
         owner: acct.owner,
@@ -0,0 +1,50 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::VerifierBondDeposited;
+use crate::state::VerifierRecord;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+pub struct DepositVerifierBond<'info> {
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+    #[account(mut, seeds = [VERIFIER_SEED, verifier.key().as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    ///CHECK: Verifier vault PDA owned by the program (created rent-exempt on first deposit)
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = 0, // lamports only, like the treasury
+        seeds = [VERIFIER_VAULT_SEED, verifier.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn deposit_verifier_bond_handler(ctx: Context<DepositVerifierBond>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.verifier_record.active,
+        ErrorCode::UnauthorizedVerifier
+    );
+    require!(amount > 0, ErrorCode::InsufficientVerifierBond);
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.verifier.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    let record = &mut ctx.accounts.verifier_record;
+    record.bond = record.bond.saturating_add(amount);
+
+    emit!(VerifierBondDeposited {
+        verifier: record.verifier,
+        amount,
+        total_bond: record.bond,
+        deposited_at: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod deposit_verifier_bond;
+pub mod resign_verifier;
+pub mod withdraw_verifier_bond;
@@ -0,0 +1,33 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::VerifierResigned;
+use crate::state::{ReviewConfig, VerifierRecord};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ResignVerifier<'info> {
+    pub verifier: Signer<'info>,
+    #[account(seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+    #[account(mut, seeds = [VERIFIER_SEED, verifier.key().as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+}
+
+pub fn resign_verifier_handler(ctx: Context<ResignVerifier>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let record = &mut ctx.accounts.verifier_record;
+    require!(record.active, ErrorCode::UnauthorizedVerifier);
+    // Claimed reviews must be handed back with release_review first
+    require!(record.live_claim(now).is_none(), ErrorCode::VerifierClaimActive);
+
+    record.clear_claim();
+    record.active = false;
+    record.removed_at = Some(now);
+
+    emit!(VerifierResigned {
+        verifier: record.verifier,
+        unbonds_at: now.saturating_add(ctx.accounts.review_config.unbonding_period),
+        resigned_at: now,
+    });
+    Ok(())
+}
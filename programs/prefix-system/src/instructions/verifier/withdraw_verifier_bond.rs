@@ -0,0 +1,52 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::VerifierBondWithdrawn;
+use crate::state::{ReviewConfig, VerifierRecord};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct WithdrawVerifierBond<'info> {
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+    #[account(seeds = [REVIEW_CONFIG_SEED], bump = review_config.bump)]
+    pub review_config: Account<'info, ReviewConfig>,
+    #[account(mut, seeds = [VERIFIER_SEED, verifier.key().as_ref()], bump = verifier_record.bump)]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    /// CHECK: Verifier vault PDA
+    #[account(mut, seeds = [VERIFIER_VAULT_SEED, verifier.key().as_ref()], bump)]
+    pub vault: UncheckedAccount<'info>,
+}
+
+pub fn withdraw_verifier_bond_handler(ctx: Context<WithdrawVerifierBond>) -> Result<()> {
+    let record = &mut ctx.accounts.verifier_record;
+    // Only once the verifier has left (resigned or removed) and the unbonding period has passed
+    require!(!record.active, ErrorCode::BondStillLocked);
+    let now = Clock::get()?.unix_timestamp;
+    let left_at = record.removed_at.ok_or(error!(ErrorCode::BondStillLocked))?;
+    require!(
+        now >= left_at.saturating_add(ctx.accounts.review_config.unbonding_period),
+        ErrorCode::BondStillLocked
+    );
+    require!(
+        ctx.accounts.vault.owner == ctx.program_id,
+        ErrorCode::InvalidTreasuryAccount
+    );
+
+    // The vault is drained entirely, its rent included, which closes it; a later deposit
+    // recreates it
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let amount = vault_info.lamports();
+    require!(amount > 0, ErrorCode::InsufficientVerifierBond);
+    record.bond = 0;
+
+    let verifier_info = ctx.accounts.verifier.to_account_info();
+    **vault_info.lamports.borrow_mut() = 0;
+    **verifier_info.lamports.borrow_mut() += amount;
+
+    emit!(VerifierBondWithdrawn {
+        verifier: record.verifier,
+        amount,
+        withdrawn_at: now,
+    });
+    Ok(())
+}
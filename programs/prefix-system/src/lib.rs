@@ -32,8 +32,49 @@ pub mod prefix_system {
         metadata_uri: String,
         metadata_hash: [u8; 32],
         authority_keys: Vec<Pubkey>,
+        intent_categories: u32,
+        review_window: Option<i64>,
     ) -> Result<()> {
-        submit_prefix_with_fee_handler(ctx, prefix, metadata_uri, metadata_hash, authority_keys)
+        submit_prefix_with_fee_handler(
+            ctx,
+            prefix,
+            metadata_uri,
+            metadata_hash,
+            authority_keys,
+            intent_categories,
+            review_window,
+        )
+    }
+
+    pub fn commit_prefix(ctx: Context<CommitPrefix>, commitment: [u8; 32]) -> Result<()> {
+        commit_prefix_handler(ctx, commitment)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn reveal_prefix(
+        ctx: Context<RevealPrefix>,
+        prefix: String,
+        salt: [u8; 32],
+        metadata_uri: String,
+        metadata_hash: [u8; 32],
+        authority_keys: Vec<Pubkey>,
+        intent_categories: u32,
+        review_window: Option<i64>,
+    ) -> Result<()> {
+        reveal_prefix_handler(
+            ctx,
+            prefix,
+            salt,
+            metadata_uri,
+            metadata_hash,
+            authority_keys,
+            intent_categories,
+            review_window,
+        )
+    }
+
+    pub fn refund_commitment(ctx: Context<RefundCommitment>) -> Result<()> {
+        refund_commitment_handler(ctx)
     }
 
     pub fn approve_prefix(
@@ -44,21 +85,111 @@ pub mod prefix_system {
         approve_prefix_handler(ctx, prefix, ref_hash)
     }
 
-    pub fn reject_prefix(ctx: Context<RejectPrefix>, prefix: String, reason: String) -> Result<()> {
-        reject_prefix_handler(ctx, prefix, reason)
+    pub fn reject_prefix(
+        ctx: Context<RejectPrefix>,
+        prefix: String,
+        reason_code: state::RejectionReason,
+        evidence_hash: Option<[u8; 32]>,
+        reason: String,
+    ) -> Result<()> {
+        reject_prefix_handler(ctx, prefix, reason_code, evidence_hash, reason)
+    }
+
+    pub fn resubmit_prefix(
+        ctx: Context<ResubmitPrefix>,
+        prefix: String,
+        metadata_uri: String,
+        metadata_hash: [u8; 32],
+        intent_categories: u32,
+        review_window: Option<i64>,
+    ) -> Result<()> {
+        resubmit_prefix_handler(
+            ctx,
+            prefix,
+            metadata_uri,
+            metadata_hash,
+            intent_categories,
+            review_window,
+        )
+    }
+
+    pub fn appeal_rejection(
+        ctx: Context<AppealRejection>,
+        prefix: String,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        appeal_rejection_handler(ctx, prefix, evidence_hash)
+    }
+
+    pub fn resolve_appeal(ctx: Context<ResolveAppeal>, prefix: String, uphold: bool) -> Result<()> {
+        resolve_appeal_handler(ctx, prefix, uphold)
+    }
+
+    pub fn request_prefix_info(
+        ctx: Context<RequestPrefixInfo>,
+        prefix: String,
+        request_hash: [u8; 32],
+    ) -> Result<()> {
+        request_prefix_info_handler(ctx, prefix, request_hash)
+    }
+
+    pub fn extend_review_window(
+        ctx: Context<ExtendReviewWindow>,
+        prefix: String,
+        extension: i64,
+    ) -> Result<()> {
+        extend_review_window_handler(ctx, prefix, extension)
+    }
+
+    pub fn reverify_prefix(ctx: Context<ReverifyPrefix>, prefix: String) -> Result<()> {
+        reverify_prefix_handler(ctx, prefix)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_sub_prefix(
+        ctx: Context<CreateSubPrefix>,
+        parent_prefix: String,
+        label: String,
+        owner: Pubkey,
+        metadata_uri: String,
+        metadata_hash: [u8; 32],
+        authority_keys: Vec<Pubkey>,
+        intent_categories: u32,
+    ) -> Result<()> {
+        create_sub_prefix_handler(
+            ctx,
+            parent_prefix,
+            label,
+            owner,
+            metadata_uri,
+            metadata_hash,
+            authority_keys,
+            intent_categories,
+        )
     }
 
     pub fn refund_prefix_fee(ctx: Context<RefundPrefixFee>, prefix: String) -> Result<()> {
         refund_prefix_fee_handler(ctx, prefix)
     }
 
+    pub fn sweep_expired_prefix(ctx: Context<SweepExpiredPrefix>, prefix: String) -> Result<()> {
+        sweep_expired_prefix_handler(ctx, prefix)
+    }
+
     pub fn update_prefix_metadata(
         ctx: Context<UpdatePrefixMetadata>,
         prefix: String,
         new_metadata_uri: String,
         new_metadata_hash: [u8; 32],
+        intent_categories: u32,
     ) -> Result<()> {
-        update_prefix_metadata_handler(ctx, prefix, new_metadata_uri, new_metadata_hash)
+        update_prefix_metadata_handler(
+            ctx,
+            prefix,
+            new_metadata_uri,
+            new_metadata_hash,
+            intent_categories,
+        )
     }
 
     pub fn update_prefix_authority(
@@ -77,6 +208,78 @@ pub mod prefix_system {
         reactivate_prefix_handler(ctx, prefix)
     }
 
+    pub fn pause_prefix(ctx: Context<PausePrefix>, prefix: String) -> Result<()> {
+        pause_prefix_handler(ctx, prefix)
+    }
+
+    pub fn resume_prefix(ctx: Context<ResumePrefix>, prefix: String) -> Result<()> {
+        resume_prefix_handler(ctx, prefix)
+    }
+
+    pub fn emergency_freeze(ctx: Context<EmergencyFreeze>, prefix: String) -> Result<()> {
+        emergency_freeze_handler(ctx, prefix)
+    }
+
+    pub fn unfreeze_prefix(ctx: Context<UnfreezePrefix>, prefix: String) -> Result<()> {
+        unfreeze_prefix_handler(ctx, prefix)
+    }
+
+    pub fn list_prefix(ctx: Context<ListPrefix>, prefix: String, price: u64) -> Result<()> {
+        list_prefix_handler(ctx, prefix, price)
+    }
+
+    pub fn delist_prefix(ctx: Context<DelistPrefix>, prefix: String) -> Result<()> {
+        delist_prefix_handler(ctx, prefix)
+    }
+
+    pub fn buy_prefix(ctx: Context<BuyPrefix>, prefix: String, max_price: u64) -> Result<()> {
+        buy_prefix_handler(ctx, prefix, max_price)
+    }
+
+    pub fn place_bid(ctx: Context<PlaceBid>, prefix: String, amount: u64) -> Result<()> {
+        place_bid_handler(ctx, prefix, amount)
+    }
+
+    pub fn settle_auction(
+        ctx: Context<SettleAuction>,
+        prefix: String,
+        metadata_uri: String,
+        metadata_hash: [u8; 32],
+        authority_keys: Vec<Pubkey>,
+        intent_categories: u32,
+    ) -> Result<()> {
+        settle_auction_handler(
+            ctx,
+            prefix,
+            metadata_uri,
+            metadata_hash,
+            authority_keys,
+            intent_categories,
+        )
+    }
+
+    pub fn suspend_prefix(
+        ctx: Context<SuspendPrefix>,
+        prefix: String,
+        duration: i64,
+        reason: state::SuspensionReason,
+        evidence_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        suspend_prefix_handler(ctx, prefix, duration, reason, evidence_hash)
+    }
+
+    pub fn extend_suspension(
+        ctx: Context<ExtendSuspension>,
+        prefix: String,
+        additional: i64,
+    ) -> Result<()> {
+        extend_suspension_handler(ctx, prefix, additional)
+    }
+
+    pub fn end_suspension(ctx: Context<EndSuspension>, prefix: String) -> Result<()> {
+        end_suspension_handler(ctx, prefix)
+    }
+
     pub fn recover_prefix_owner_with_fee(
         ctx: Context<RecoverPrefixOwnerWithFee>,
         prefix: String,
@@ -87,19 +290,31 @@ pub mod prefix_system {
         )
     }
 
+    pub fn claim_review(ctx: Context<ClaimReview>, prefix: String) -> Result<()> {
+        claim_review_handler(ctx, prefix)
+    }
+
+    pub fn release_review(ctx: Context<ReleaseReview>, prefix: String) -> Result<()> {
+        release_review_handler(ctx, prefix)
+    }
+
     // Admin ops
     pub fn update_fee(ctx: Context<UpdateFee>, new_fee: u64) -> Result<()> {
         update_fee_handler(ctx, new_fee)
     }
 
-    pub fn add_verifier(ctx: Context<AddVerifier>, verifier: Pubkey) -> Result<()> {
-        add_verifier_handler(ctx, verifier)
+    pub fn add_verifier(ctx: Context<AddVerifier>, verifier: Pubkey, label: String) -> Result<()> {
+        add_verifier_handler(ctx, verifier, label)
     }
 
     pub fn remove_verifier(ctx: Context<RemoveVerifier>, verifier: Pubkey) -> Result<()> {
         remove_verifier_handler(ctx, verifier)
     }
 
+    pub fn migrate_verifier(ctx: Context<MigrateVerifier>, verifier: Pubkey) -> Result<()> {
+        migrate_verifier_handler(ctx, verifier)
+    }
+
     pub fn withdraw_treasury(
         ctx: Context<WithdrawTreasury>,
         amount: u64,
@@ -111,4 +326,181 @@ pub mod prefix_system {
     pub fn set_pause(ctx: Context<SetPause>, pause: bool) -> Result<()> {
         set_pause_handler(ctx, pause)
     }
+
+    pub fn set_review_quorum(
+        ctx: Context<SetReviewQuorum>,
+        default_quorum: u8,
+        quorum_tiers: Vec<state::QuorumTier>,
+    ) -> Result<()> {
+        set_review_quorum_handler(ctx, default_quorum, quorum_tiers)
+    }
+
+    pub fn init_review_config(ctx: Context<InitReviewConfig>) -> Result<()> {
+        init_review_config_handler(ctx)
+    }
+
+    pub fn init_protocol_config(ctx: Context<InitProtocolConfig>) -> Result<()> {
+        init_protocol_config_handler(ctx)
+    }
+
+    pub fn migrate_prefix_account(ctx: Context<MigratePrefixAccount>, prefix: String) -> Result<()> {
+        migrate_prefix_account_handler(ctx, prefix)
+    }
+
+    pub fn set_claim_window(ctx: Context<SetClaimWindow>, claim_window: i64) -> Result<()> {
+        set_claim_window_handler(ctx, claim_window)
+    }
+
+    pub fn set_appeal_bond(ctx: Context<SetAppealBond>, appeal_bond: u64) -> Result<()> {
+        set_appeal_bond_handler(ctx, appeal_bond)
+    }
+
+    pub fn set_verifier_bond_policy(
+        ctx: Context<SetVerifierBondPolicy>,
+        min_verifier_bond: u64,
+        unbonding_period: i64,
+    ) -> Result<()> {
+        set_verifier_bond_policy_handler(ctx, min_verifier_bond, unbonding_period)
+    }
+
+    pub fn slash_verifier(
+        ctx: Context<SlashVerifier>,
+        verifier: Pubkey,
+        amount: u64,
+        reason: String,
+    ) -> Result<()> {
+        slash_verifier_handler(ctx, verifier, amount, reason)
+    }
+
+    pub fn set_expiry_extension_policy(
+        ctx: Context<SetExpiryExtensionPolicy>,
+        needs_info_extension: i64,
+        max_expiry_extension: i64,
+    ) -> Result<()> {
+        set_expiry_extension_policy_handler(ctx, needs_info_extension, max_expiry_extension)
+    }
+
+    pub fn set_verification_validity(
+        ctx: Context<SetVerificationValidity>,
+        verification_validity: i64,
+    ) -> Result<()> {
+        set_verification_validity_handler(ctx, verification_validity)
+    }
+
+    pub fn set_verifier_scope(
+        ctx: Context<SetVerifierScope>,
+        verifier: Pubkey,
+        scope: state::VerifierScope,
+    ) -> Result<()> {
+        set_verifier_scope_handler(ctx, verifier, scope)
+    }
+
+    pub fn reserve_prefix(
+        ctx: Context<ReservePrefix>,
+        prefix: String,
+        kind: state::ReservationKind,
+        reason: String,
+        claimant: Option<Pubkey>,
+    ) -> Result<()> {
+        reserve_prefix_handler(ctx, prefix, kind, reason, claimant)
+    }
+
+    pub fn release_reserved_prefix(
+        ctx: Context<ReleaseReservedPrefix>,
+        prefix: String,
+    ) -> Result<()> {
+        release_reserved_prefix_handler(ctx, prefix)
+    }
+
+    pub fn update_protocol_config(
+        ctx: Context<UpdateProtocolConfig>,
+        min_prefix_len: u8,
+        max_prefix_len: u8,
+        allow_hyphen: bool,
+        allow_underscore: bool,
+        max_expiry_duration: i64,
+        min_review_window: i64,
+    ) -> Result<()> {
+        update_protocol_config_handler(
+            ctx,
+            min_prefix_len,
+            max_prefix_len,
+            allow_hyphen,
+            allow_underscore,
+            max_expiry_duration,
+            min_review_window,
+        )
+    }
+
+    pub fn set_sweep_policy(
+        ctx: Context<SetSweepPolicy>,
+        sweep_reward: u64,
+        rejected_grace_period: i64,
+    ) -> Result<()> {
+        set_sweep_policy_handler(ctx, sweep_reward, rejected_grace_period)
+    }
+
+    pub fn set_royalty(ctx: Context<SetRoyalty>, royalty_bps: u16) -> Result<()> {
+        set_royalty_handler(ctx, royalty_bps)
+    }
+
+    pub fn open_auction(
+        ctx: Context<OpenAuction>,
+        prefix: String,
+        reserve_price: u64,
+        min_increment: u64,
+        duration: i64,
+        activate_on_settle: bool,
+    ) -> Result<()> {
+        open_auction_handler(
+            ctx,
+            prefix,
+            reserve_price,
+            min_increment,
+            duration,
+            activate_on_settle,
+        )
+    }
+
+    pub fn cancel_auction(ctx: Context<CancelAuction>, prefix: String) -> Result<()> {
+        cancel_auction_handler(ctx, prefix)
+    }
+
+    pub fn set_commit_policy(
+        ctx: Context<SetCommitPolicy>,
+        commit_required: bool,
+        commit_deposit: u64,
+        min_reveal_delay: i64,
+        commitment_ttl: i64,
+    ) -> Result<()> {
+        set_commit_policy_handler(
+            ctx,
+            commit_required,
+            commit_deposit,
+            min_reveal_delay,
+            commitment_ttl,
+        )
+    }
+
+    pub fn revoke_prefix(
+        ctx: Context<RevokePrefix>,
+        prefix: String,
+        reason: String,
+        cooldown: Option<i64>,
+    ) -> Result<()> {
+        revoke_prefix_handler(ctx, prefix, reason, cooldown)
+    }
+
+    // Verifier ops
+    pub fn deposit_verifier_bond(ctx: Context<DepositVerifierBond>, amount: u64) -> Result<()> {
+        deposit_verifier_bond_handler(ctx, amount)
+    }
+
+    pub fn resign_verifier(ctx: Context<ResignVerifier>) -> Result<()> {
+        resign_verifier_handler(ctx)
+    }
+
+    pub fn withdraw_verifier_bond(ctx: Context<WithdrawVerifierBond>) -> Result<()> {
+        withdraw_verifier_bond_handler(ctx)
+    }
 }
@@ -0,0 +1,23 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+/// Open appeal against a rejection; holds the owner's bond until resolved
+#[account]
+pub struct Appeal {
+    pub owner: Pubkey,
+    pub bond: u64,
+    pub evidence_hash: [u8; 32],
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Appeal {
+    pub fn space() -> usize {
+        DISCRIMINATOR_SIZE +
+        PUBKEY_SIZE + // owner
+        U64_SIZE +    // bond
+        32 +          // evidence_hash
+        I64_SIZE +    // created_at
+        U8_SIZE       // bump
+    }
+}
@@ -0,0 +1,40 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+/// English auction for a reserved prefix; escrows the highest bid until settlement
+#[account]
+pub struct Auction {
+    pub prefix: String,
+    pub reserve_price: u64, // lowest acceptable first bid
+    pub min_increment: u64, // lamports each new bid must add over the current one
+    pub highest_bidder: Option<Pubkey>,
+    pub highest_bid: u64,
+    pub start_at: i64,
+    pub end_at: i64,              // pushed out by bids placed inside AUCTION_SNIPE_WINDOW
+    pub activate_on_settle: bool, // winner's prefix starts Active instead of Pending review
+    pub bump: u8,
+}
+
+impl Auction {
+    pub fn space() -> usize {
+        DISCRIMINATOR_SIZE +
+        STRING_PREFIX_SIZE + MAX_PREFIX_LEN + // prefix
+        U64_SIZE +          // reserve_price
+        U64_SIZE +          // min_increment
+        1 + PUBKEY_SIZE +   // highest_bidder
+        U64_SIZE +          // highest_bid
+        I64_SIZE +          // start_at
+        I64_SIZE +          // end_at
+        BOOL_SIZE +         // activate_on_settle
+        U8_SIZE             // bump
+    }
+
+    /// Smallest bid that would currently be accepted
+    pub fn min_next_bid(&self) -> u64 {
+        if self.highest_bidder.is_some() {
+            self.highest_bid.saturating_add(self.min_increment)
+        } else {
+            self.reserve_price
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::constants::*;
+use crate::state::prefix_account::{PrefixAccount, PrefixStatus};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    Submitted,
+    SubPrefixCreated,
+    AuctionSettled,
+    Approved,
+    Rejected,
+    InfoRequested,
+    MetadataUpdated,
+    Resubmitted,
+    Appealed,
+    AppealUpheld,
+    AppealDenied,
+    Deactivated,
+    Reactivated,
+    OwnerRecovered,
+    OwnerPaused,
+    OwnerResumed,
+    Frozen,
+    Unfrozen,
+    Suspended,
+    SuspensionExtended,
+    SuspensionEnded,
+    Sold,
+    Migrated,
+    Revoked,
+    Refunded,
+    Swept,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AuditEntry {
+    pub actor: Pubkey,
+    pub action: AuditAction,
+    pub old_status: Option<PrefixStatus>, // None when the prefix was created
+    pub new_status: PrefixStatus,
+    pub old_owner: Option<Pubkey>,
+    pub new_owner: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+impl AuditEntry {
+    pub fn space() -> usize {
+        PUBKEY_SIZE +     // actor
+        1 +               // action
+        1 + 1 +           // old_status
+        1 +               // new_status
+        1 + PUBKEY_SIZE + // old_owner
+        PUBKEY_SIZE +     // new_owner
+        U64_SIZE +        // slot
+        I64_SIZE          // timestamp
+    }
+}
+
+/// Per-prefix ring buffer of the last MAX_AUDIT_ENTRIES status and ownership transitions
+#[account]
+pub struct AuditLog {
+    pub prefix: String,
+    pub entries: Vec<AuditEntry>, // oldest entry sits at `head` once the buffer is full
+    pub head: u16,
+    pub total: u32, // transitions ever recorded, including overwritten ones
+    pub bump: u8,
+}
+
+impl AuditLog {
+    pub fn space(max_prefix_len: usize, max_entries: usize) -> usize {
+        DISCRIMINATOR_SIZE +
+        STRING_PREFIX_SIZE + max_prefix_len +               // prefix
+        VEC_PREFIX_SIZE + max_entries * AuditEntry::space() + // entries
+        2 +                                                 // head
+        U32_SIZE +                                          // total
+        U8_SIZE                                             // bump
+    }
+
+    /// Sets up a freshly created log; a log kept from an earlier registration of the same name
+    /// (it survives revocation) keeps its history
+    pub fn open(&mut self, prefix: &str, bump: u8) {
+        if self.prefix.is_empty() {
+            self.prefix = prefix.to_string();
+            self.entries = Vec::new();
+            self.head = 0;
+            self.total = 0;
+            self.bump = bump;
+        }
+    }
+
+    /// Appends a transition of `prefix` from its previous status/owner, overwriting the oldest
+    /// entry once the buffer is full
+    pub fn record(
+        &mut self,
+        actor: Pubkey,
+        action: AuditAction,
+        old_status: Option<PrefixStatus>,
+        old_owner: Option<Pubkey>,
+        prefix: &PrefixAccount,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let entry = AuditEntry {
+            actor,
+            action,
+            old_status,
+            new_status: prefix.status.clone(),
+            old_owner,
+            new_owner: prefix.owner,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        };
+        if self.entries.len() < MAX_AUDIT_ENTRIES {
+            self.entries.push(entry);
+        } else {
+            self.entries[self.head as usize] = entry;
+            self.head = ((self.head as usize + 1) % MAX_AUDIT_ENTRIES) as u16;
+        }
+        self.total = self.total.saturating_add(1);
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+/// Fixed-price sale offer for a prefix; closed on purchase or delisting
+#[account]
+pub struct Listing {
+    pub prefix: String,
+    pub seller: Pubkey,
+    pub price: u64, // lamports
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Listing {
+    pub fn space() -> usize {
+        DISCRIMINATOR_SIZE +
+        STRING_PREFIX_SIZE + MAX_PREFIX_LEN + // prefix
+        PUBKEY_SIZE + // seller
+        U64_SIZE +    // price
+        I64_SIZE +    // created_at
+        U8_SIZE       // bump
+    }
+}
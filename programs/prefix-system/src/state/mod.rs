@@ -1,8 +1,31 @@
 pub mod fee_registry;
 pub mod verifiers_list;
 pub mod prefix_account;
+pub mod review_config;
+pub mod review_state;
+pub mod verifier_record;
+pub mod appeal;
+pub mod reserved_prefix;
+pub mod skeleton_guard;
+pub mod protocol_config;
+pub mod listing;
+pub mod auction;
+pub mod prefix_commitment;
+pub mod tombstone;
+pub mod audit_log;
 
 pub use fee_registry::*;
 pub use verifiers_list::*;
 pub use prefix_account::*;
-
+pub use review_config::*;
+pub use review_state::*;
+pub use verifier_record::*;
+pub use appeal::*;
+pub use reserved_prefix::*;
+pub use skeleton_guard::*;
+pub use protocol_config::*;
+pub use listing::*;
+pub use auction::*;
+pub use prefix_commitment::*;
+pub use tombstone::*;
+pub use audit_log::*;
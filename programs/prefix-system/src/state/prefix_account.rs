@@ -7,6 +7,29 @@ pub enum PrefixStatus {
     Active,
     Rejected,
     Inactive,
+    UnderAppeal,
+    NeedsInfo,
+    OwnerPaused,
+    Frozen,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RejectionReason {
+    SchemaInvalid,
+    HashMismatch,
+    Impersonation,
+    Trademark,
+    Offensive,
+    Other,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SuspensionReason {
+    Phishing,
+    Impersonation,
+    CompromisedKeys,
+    LegalRequest,
+    Other,
 }
 
 #[account]
@@ -23,10 +46,64 @@ pub struct PrefixAccount {
     pub created_at: i64,
     pub updated_at: i64,
     pub bump: u8,
+    pub review_round: u32, // bumped every time the prefix (re)enters Pending
+    pub claimed_by: Option<Pubkey>, // verifier holding the review claim
+    pub claim_expires_at: i64,
+    pub rejection_reason: Option<RejectionReason>,
+    pub rejection_evidence: Option<[u8; 32]>,
+    pub resubmission_count: u16,
+    pub appeal_evidence: Option<[u8; 32]>, // evidence of the last resolved appeal
+    pub decided_by: Option<Pubkey>, // verifier (or appeal resolver) whose action decided the review
+    pub review_started_at: i64, // when the current review round began
+    pub info_request_hash: [u8; 32], // hash of the verifier's open information request
+    pub expiry_extension_used: i64,  // seconds added to expiry_at by verifiers since the prefix (re)entered review
+    pub verified_until: i64,         // approval stops being trusted after this timestamp
+    pub intent_categories: u32,      // INTENT_* bitmask declared by the owner
+    pub parent: Option<Pubkey>,      // parent PrefixAccount for sub-prefixes
+    pub depth: u8,                   // 0 for top-level prefixes
+    pub sub_prefix_count: u16,       // direct children created under this prefix
+    pub rejected_at: i64,            // when the current rejection was decided; 0 if never rejected
+    pub suspended_until: i64,        // untrusted until this timestamp; lapses on its own
+    pub suspension_reason: Option<SuspensionReason>,
+    pub suspension_evidence: Option<[u8; 32]>,
+    pub suspended_by: Option<Pubkey>,
+    pub frozen_by: Option<Pubkey>, // authority key that triggered emergency_freeze
+    pub deactivated_from: Option<PrefixStatus>, // status reactivate_prefix restores
+    pub listed: bool,                // an open marketplace Listing locks the prefix
 }
 
 impl PrefixAccount {
     pub fn space(max_prefix_len: usize, max_uri_len: usize, max_auth_keys: usize) -> usize {
+        Self::v1_space(max_prefix_len, max_uri_len, max_auth_keys) +
+        U32_SIZE + // review_round
+        1 + PUBKEY_SIZE + // claimed_by
+        I64_SIZE + // claim_expires_at
+        1 + 1 +  // rejection_reason
+        1 + 32 + // rejection_evidence
+        2 + // resubmission_count
+        1 + 32 + // appeal_evidence
+        1 + PUBKEY_SIZE + // decided_by
+        I64_SIZE + // review_started_at
+        32 + // info_request_hash
+        I64_SIZE + // expiry_extension_used
+        I64_SIZE + // verified_until
+        U32_SIZE + // intent_categories
+        1 + PUBKEY_SIZE + // parent
+        U8_SIZE + // depth
+        2 + // sub_prefix_count
+        I64_SIZE + // rejected_at
+        I64_SIZE + // suspended_until
+        1 + 1 + // suspension_reason
+        1 + 32 + // suspension_evidence
+        1 + PUBKEY_SIZE + // suspended_by
+        1 + PUBKEY_SIZE + // frozen_by
+        1 + 1 + // deactivated_from
+        BOOL_SIZE // listed
+    }
+
+    /// Size of accounts created before the review fields were appended (up to `bump`);
+    /// such accounts are grown in place by migrate_prefix_account
+    pub fn v1_space(max_prefix_len: usize, max_uri_len: usize, max_auth_keys: usize) -> usize {
         DISCRIMINATOR_SIZE +
         PUBKEY_SIZE +
         STRING_PREFIX_SIZE + max_prefix_len +
@@ -41,4 +118,32 @@ impl PrefixAccount {
         I64_SIZE +
         U8_SIZE
     }
+
+    /// Relayer-facing trust check: active, not suspended and verification not yet lapsed.
+    /// A sub-prefix is only trusted while every ancestor along `parent` is trusted too;
+    /// on-chain consumers pair this with `utils::assert_ancestors_trusted`.
+    pub fn is_trusted(&self, now: i64) -> bool {
+        self.status == PrefixStatus::Active && !self.is_suspended(now) && now < self.verified_until
+    }
+
+    pub fn is_suspended(&self, now: i64) -> bool {
+        now < self.suspended_until
+    }
+
+    pub fn clear_suspension(&mut self) {
+        self.suspended_until = 0;
+        self.suspension_reason = None;
+        self.suspension_evidence = None;
+        self.suspended_by = None;
+    }
+
+    /// Verifier whose review claim is still within its window; expired claims release themselves
+    pub fn live_claim(&self, now: i64) -> Option<Pubkey> {
+        self.claimed_by.filter(|_| now < self.claim_expires_at)
+    }
+
+    pub fn clear_claim(&mut self) {
+        self.claimed_by = None;
+        self.claim_expires_at = 0;
+    }
 }
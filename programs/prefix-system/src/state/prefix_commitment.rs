@@ -0,0 +1,28 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+/// Sealed intent to register a prefix; `commitment` is hashv(prefix, owner, salt).
+/// Holds the owner's deposit until it is revealed or refunded.
+#[account]
+pub struct PrefixCommitment {
+    pub owner: Pubkey,
+    pub commitment: [u8; 32],
+    pub deposit: u64,
+    pub reveal_after: i64, // earliest reveal, so the commit lands in an earlier slot than the reveal
+    pub expires_at: i64,   // reveal deadline; afterwards only a refund is possible
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl PrefixCommitment {
+    pub fn space() -> usize {
+        DISCRIMINATOR_SIZE +
+        PUBKEY_SIZE + // owner
+        32 +          // commitment
+        U64_SIZE +    // deposit
+        I64_SIZE +    // reveal_after
+        I64_SIZE +    // expires_at
+        I64_SIZE +    // created_at
+        U8_SIZE       // bump
+    }
+}
@@ -0,0 +1,73 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+/// Admin-tunable prefix rules
+#[account]
+pub struct ProtocolConfig {
+    pub min_prefix_len: u8,
+    pub max_prefix_len: u8, // never above MAX_PREFIX_LEN, which sizes PrefixAccount
+    pub allow_hyphen: bool,
+    pub allow_underscore: bool,
+    pub max_expiry_duration: i64, // longest review window a submitter may request
+    pub min_review_window: i64,
+    pub sweep_reward: u64,           // lamports paid to whoever sweeps an abandoned prefix
+    pub rejected_grace_period: i64,  // how long a rejected prefix is left for its owner
+    pub royalty_bps: u16,            // treasury cut of marketplace sales
+    pub commit_required: bool,       // refuse direct submissions; only reveal_prefix registers
+    pub commit_deposit: u64,
+    pub min_reveal_delay: i64,
+    pub commitment_ttl: i64,
+    pub bump: u8,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl ProtocolConfig {
+    pub fn space() -> usize {
+        DISCRIMINATOR_SIZE +
+        U8_SIZE +   // min_prefix_len
+        U8_SIZE +   // max_prefix_len
+        BOOL_SIZE + // allow_hyphen
+        BOOL_SIZE + // allow_underscore
+        I64_SIZE +  // max_expiry_duration
+        I64_SIZE +  // min_review_window
+        U64_SIZE +  // sweep_reward
+        I64_SIZE +  // rejected_grace_period
+        2 +         // royalty_bps
+        BOOL_SIZE + // commit_required
+        U64_SIZE +  // commit_deposit
+        I64_SIZE +  // min_reveal_delay
+        I64_SIZE +  // commitment_ttl
+        U8_SIZE +   // bump
+        I64_SIZE +  // created_at
+        I64_SIZE    // updated_at
+    }
+
+    /// Defaults shared by `initialize` and `init_protocol_config`
+    pub fn set_defaults(&mut self, bump: u8, now: i64) {
+        self.min_prefix_len = MIN_PREFIX_LEN as u8;
+        self.max_prefix_len = MAX_PREFIX_LEN as u8;
+        self.allow_hyphen = false;
+        self.allow_underscore = false;
+        self.max_expiry_duration = MAX_EXPIRY_DURATION as i64;
+        self.min_review_window = DEFAULT_MIN_REVIEW_WINDOW;
+        self.sweep_reward = DEFAULT_SWEEP_REWARD;
+        self.rejected_grace_period = DEFAULT_REJECTED_GRACE_PERIOD;
+        self.royalty_bps = 0;
+        self.commit_required = true; // direct submissions can be front-run; the admin may opt out
+        self.commit_deposit = DEFAULT_COMMIT_DEPOSIT;
+        self.min_reveal_delay = DEFAULT_MIN_REVEAL_DELAY;
+        self.commitment_ttl = DEFAULT_COMMITMENT_TTL;
+        self.bump = bump;
+        self.created_at = now;
+        self.updated_at = now;
+    }
+
+    pub fn royalty_for(&self, price: u64) -> u64 {
+        (price as u128 * self.royalty_bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+
+    pub fn allows_separator(&self, c: char) -> bool {
+        (c == '-' && self.allow_hyphen) || (c == '_' && self.allow_underscore)
+    }
+}
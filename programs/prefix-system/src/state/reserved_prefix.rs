@@ -0,0 +1,31 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReservationKind {
+    Reserved, // only the designated claimant may submit
+    Blocked,  // nobody may submit
+}
+
+/// Admin-managed entry in the reserved / blocked names registry
+#[account]
+pub struct ReservedPrefix {
+    pub prefix: String,
+    pub kind: ReservationKind,
+    pub reason: String,
+    pub claimant: Option<Pubkey>,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl ReservedPrefix {
+    pub fn space(max_prefix_len: usize, max_reason_len: usize) -> usize {
+        DISCRIMINATOR_SIZE +
+        STRING_PREFIX_SIZE + max_prefix_len + // prefix
+        1 +                                   // kind
+        STRING_PREFIX_SIZE + max_reason_len + // reason
+        1 + PUBKEY_SIZE +                     // claimant
+        I64_SIZE +                            // created_at
+        U8_SIZE                               // bump
+    }
+}
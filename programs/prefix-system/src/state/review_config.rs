@@ -0,0 +1,83 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+/// Quorum override for prefixes whose length falls in `min_len..=max_len`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct QuorumTier {
+    pub min_len: u8,
+    pub max_len: u8,
+    pub threshold: u8,
+}
+
+#[account]
+pub struct ReviewConfig {
+    pub default_quorum: u8,
+    pub quorum_tiers: Vec<QuorumTier>,
+    pub claim_window: i64, // seconds a verifier's review claim stays exclusive
+    pub appeal_bond: u64,  // lamports posted by an owner appealing a rejection
+    pub min_verifier_bond: u64, // lamports a verifier must have bonded to review
+    pub unbonding_period: i64,  // seconds between resignation and bond withdrawal
+    pub needs_info_extension: i64, // seconds added to expiry_at per information request
+    pub max_expiry_extension: i64, // cap on total seconds verifiers may add to a prefix's expiry_at
+    pub verification_validity: i64, // seconds an approval stays trusted; 0 = no expiry
+    pub bump: u8,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl ReviewConfig {
+    pub fn space(max_tiers: usize) -> usize {
+        DISCRIMINATOR_SIZE +
+        U8_SIZE +                                  // default_quorum
+        VEC_PREFIX_SIZE + max_tiers * 3 * U8_SIZE + // quorum_tiers
+        I64_SIZE +                                 // claim_window
+        U64_SIZE +                                 // appeal_bond
+        U64_SIZE +                                 // min_verifier_bond
+        I64_SIZE +                                 // unbonding_period
+        I64_SIZE +                                 // needs_info_extension
+        I64_SIZE +                                 // max_expiry_extension
+        I64_SIZE +                                 // verification_validity
+        U8_SIZE +                                  // bump
+        I64_SIZE +                                 // created_at
+        I64_SIZE                                   // updated_at
+    }
+
+    /// Defaults shared by `initialize` and `init_review_config`
+    pub fn set_defaults(&mut self, bump: u8, now: i64) {
+        self.default_quorum = DEFAULT_QUORUM;
+        self.quorum_tiers = Vec::new();
+        self.claim_window = DEFAULT_CLAIM_WINDOW;
+        self.appeal_bond = DEFAULT_APPEAL_BOND;
+        self.min_verifier_bond = DEFAULT_MIN_VERIFIER_BOND;
+        self.unbonding_period = DEFAULT_UNBONDING_PERIOD;
+        self.needs_info_extension = DEFAULT_NEEDS_INFO_EXTENSION;
+        self.max_expiry_extension = DEFAULT_MAX_EXPIRY_EXTENSION;
+        self.verification_validity = DEFAULT_VERIFICATION_VALIDITY;
+        self.bump = bump;
+        self.created_at = now;
+        self.updated_at = now;
+    }
+
+    /// Number of matching verifier votes needed to approve or reject a prefix of `prefix_len`
+    pub fn quorum_for(&self, prefix_len: usize) -> u8 {
+        self.quorum_tiers
+            .iter()
+            .find(|t| (t.min_len as usize..=t.max_len as usize).contains(&prefix_len))
+            .map(|t| t.threshold)
+            .unwrap_or(self.default_quorum)
+    }
+
+    /// Timestamp until which a verification performed at `now` is trusted
+    pub fn verified_until(&self, now: i64) -> i64 {
+        if self.verification_validity == 0 {
+            i64::MAX
+        } else {
+            now.saturating_add(self.verification_validity)
+        }
+    }
+
+    /// Extension still available to a prefix that has already been granted `used` seconds
+    pub fn remaining_extension(&self, used: i64) -> i64 {
+        self.max_expiry_extension.saturating_sub(used).max(0)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ReviewState {
+    pub round: u32, // mirrors PrefixAccount::review_round; votes from older rounds are discarded
+    pub approvals: Vec<Pubkey>,
+    pub rejections: Vec<Pubkey>,
+    pub bump: u8,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl ReviewState {
+    pub fn space(max_votes: usize) -> usize {
+        DISCRIMINATOR_SIZE +
+        U32_SIZE +                                   // round
+        VEC_PREFIX_SIZE + max_votes * PUBKEY_SIZE +  // approvals
+        VEC_PREFIX_SIZE + max_votes * PUBKEY_SIZE +  // rejections
+        U8_SIZE +                                    // bump
+        I64_SIZE +                                   // created_at
+        I64_SIZE                                     // updated_at
+    }
+
+    /// Clears recorded votes when the prefix has re-entered review since they were cast
+    pub fn sync_round(&mut self, round: u32) {
+        if self.round != round {
+            self.round = round;
+            self.approvals.clear();
+            self.rejections.clear();
+        }
+    }
+
+    pub fn has_voted(&self, verifier: &Pubkey) -> bool {
+        self.approvals.contains(verifier) || self.rejections.contains(verifier)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+/// Claims a confusable-character skeleton for one prefix; seeded by the skeleton so
+/// no second prefix collapsing to the same form can be registered.
+#[account]
+pub struct SkeletonGuard {
+    pub prefix: String,
+    pub bump: u8,
+}
+
+impl SkeletonGuard {
+    pub fn space(max_prefix_len: usize) -> usize {
+        DISCRIMINATOR_SIZE +
+        STRING_PREFIX_SIZE + max_prefix_len + // prefix
+        U8_SIZE                               // bump
+    }
+}
@@ -0,0 +1,31 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+/// Left behind by revoke_prefix; blocks re-registration of the name until `blocked_until`
+#[account]
+pub struct Tombstone {
+    pub prefix: String,
+    pub previous_owner: Pubkey,
+    pub reason: String,
+    pub blocked_until: i64, // i64::MAX for a permanent block
+    pub revoked_by: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Tombstone {
+    pub fn space(max_prefix_len: usize, max_reason_len: usize) -> usize {
+        DISCRIMINATOR_SIZE +
+        STRING_PREFIX_SIZE + max_prefix_len + // prefix
+        PUBKEY_SIZE +                         // previous_owner
+        STRING_PREFIX_SIZE + max_reason_len + // reason
+        I64_SIZE +                            // blocked_until
+        PUBKEY_SIZE +                         // revoked_by
+        I64_SIZE +                            // created_at
+        U8_SIZE                               // bump
+    }
+
+    pub fn is_blocking(&self, now: i64) -> bool {
+        now < self.blocked_until
+    }
+}
@@ -0,0 +1,101 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+/// Jurisdiction of a verifier; a zeroed scope places no restriction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq)]
+pub struct VerifierScope {
+    pub category_mask: u32, // prefix must declare at least one of these intents; 0 = any
+    pub min_len: u8,
+    pub max_len: u8,           // 0 = no upper bound
+    pub patterns: Vec<String>, // prefix must start with one of these; empty = any
+}
+
+impl VerifierScope {
+    pub fn space(max_patterns: usize, max_pattern_len: usize) -> usize {
+        U32_SIZE + // category_mask
+        U8_SIZE +  // min_len
+        U8_SIZE +  // max_len
+        VEC_PREFIX_SIZE + max_patterns * (STRING_PREFIX_SIZE + max_pattern_len)
+    }
+
+    pub fn covers(&self, prefix: &str, intent_categories: u32) -> bool {
+        let len = prefix.len();
+        (self.category_mask == 0 || self.category_mask & intent_categories != 0)
+            && len >= self.min_len as usize
+            && (self.max_len == 0 || len <= self.max_len as usize)
+            && (self.patterns.is_empty()
+                || self.patterns.iter().any(|p| prefix.starts_with(p.as_str())))
+    }
+}
+
+#[account]
+pub struct VerifierRecord {
+    pub verifier: Pubkey,
+    pub label: String,
+    pub active: bool,
+    pub added_at: i64,
+    pub removed_at: Option<i64>,
+    pub bump: u8,
+    pub claimed_prefix: Option<Pubkey>, // PrefixAccount under this verifier's review claim
+    pub claim_expires_at: i64,
+    pub bond: u64, // lamports held in the verifier vault PDA, excluding its rent
+    pub approvals: u64,
+    pub rejections: u64,
+    pub total_review_secs: u64, // sum of (vote time - review round start) over all votes
+    pub last_action_at: i64,
+    pub scope: VerifierScope,
+}
+
+impl VerifierRecord {
+    pub fn space(max_label_len: usize) -> usize {
+        DISCRIMINATOR_SIZE +
+        PUBKEY_SIZE +                        // verifier
+        STRING_PREFIX_SIZE + max_label_len + // label
+        BOOL_SIZE +                          // active
+        I64_SIZE +                           // added_at
+        1 + I64_SIZE +                       // removed_at
+        U8_SIZE +                            // bump
+        1 + PUBKEY_SIZE +                    // claimed_prefix
+        I64_SIZE +                           // claim_expires_at
+        U64_SIZE +                           // bond
+        U64_SIZE +                           // approvals
+        U64_SIZE +                           // rejections
+        U64_SIZE +                           // total_review_secs
+        I64_SIZE +                           // last_action_at
+        VerifierScope::space(MAX_SCOPE_PATTERNS, MAX_PREFIX_LEN)
+    }
+
+    /// Active and bonded at or above the protocol minimum
+    pub fn can_review(&self, min_bond: u64) -> bool {
+        self.active && self.bond >= min_bond
+    }
+
+    pub fn record_vote(&mut self, approve: bool, review_started_at: i64, now: i64) {
+        if approve {
+            self.approvals = self.approvals.saturating_add(1);
+        } else {
+            self.rejections = self.rejections.saturating_add(1);
+        }
+        let elapsed = now.saturating_sub(review_started_at).max(0) as u64;
+        self.total_review_secs = self.total_review_secs.saturating_add(elapsed);
+        self.last_action_at = now;
+    }
+
+    /// Mean seconds from the start of the review round to this verifier's vote
+    pub fn average_review_secs(&self) -> u64 {
+        let votes = self.approvals.saturating_add(self.rejections);
+        if votes == 0 {
+            return 0;
+        }
+        self.total_review_secs / votes
+    }
+
+    pub fn live_claim(&self, now: i64) -> Option<Pubkey> {
+        self.claimed_prefix.filter(|_| now < self.claim_expires_at)
+    }
+
+    pub fn clear_claim(&mut self) {
+        self.claimed_prefix = None;
+        self.claim_expires_at = 0;
+    }
+}
@@ -1,6 +1,8 @@
 use crate::constants::*;
 use anchor_lang::prelude::*;
 
+/// Legacy single-account verifier registry. New deployments use one `VerifierRecord`
+/// per verifier; existing entries are moved over with `migrate_verifier`.
 #[account]
 pub struct VerifiersList {
     pub admin: Pubkey,
@@ -1,24 +1,76 @@
 use crate::constants::*;
 use crate::errors::ErrorCode;
+use crate::state::{
+    PrefixAccount, ProtocolConfig, ReservationKind, ReservedPrefix, Tombstone, VerifierRecord,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{
     ed25519_program, sysvar::instructions::load_instruction_at_checked,
 };
 
-pub fn normalize_prefix(input: &str) -> Result<String> {
+pub fn normalize_prefix(input: &str, config: &ProtocolConfig) -> Result<String> {
     let upper = input
         .chars()
         .map(|c| c.to_ascii_uppercase())
         .collect::<String>();
-    if upper.len() < MIN_PREFIX_LEN || upper.len() > MAX_PREFIX_LEN {
+    let len = upper.len();
+    if len < config.min_prefix_len as usize || len > config.max_prefix_len as usize {
+        return err!(ErrorCode::InvalidPrefixFormat);
+    }
+    if !upper
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || config.allows_separator(c))
+    {
         return err!(ErrorCode::InvalidPrefixFormat);
     }
-    if !upper.chars().all(|c| c.is_ascii_alphanumeric()) {
+    // Separators only between alphanumerics: not leading, trailing or doubled
+    let bytes = upper.as_bytes();
+    let is_sep = |b: u8| !b.is_ascii_alphanumeric();
+    if is_sep(bytes[0])
+        || is_sep(bytes[bytes.len() - 1])
+        || bytes.windows(2).any(|w| is_sep(w[0]) && is_sep(w[1]))
+    {
         return err!(ErrorCode::InvalidPrefixFormat);
     }
     Ok(upper)
 }
 
+/// Full name of a sub-prefix, e.g. `ACME` + `PAY` -> `ACME-PAY`
+pub fn sub_prefix_name(parent: &str, label: &str) -> String {
+    format!("{}{}{}", parent, SUB_PREFIX_SEPARATOR, label)
+}
+
+/// Part of a name before its first separator, e.g. `ACME-PAY` -> `ACME`
+pub fn prefix_root(name: &str) -> &str {
+    name.split(SUB_PREFIX_SEPARATOR).next().unwrap_or(name)
+}
+
+/// Canonical "skeleton" of a normalized prefix: visually confusable characters collapse
+/// to one representative, so e.g. `G00GLE` and `GOOGLE` share the skeleton `GOOGIE`.
+/// Separators are dropped, so `ACME-PAY` and `ACMEPAY` collide as well.
+///
+/// Account seeds built from this (or the other name helpers) are written as
+/// `(skeleton_prefix(&prefix).as_bytes())`: IDL generation cannot resolve a function
+/// call seed, and the parenthesized form makes it leave that PDA for the client to derive.
+pub fn skeleton_prefix(normalized: &str) -> String {
+    normalized
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| match c {
+            '0' => 'O',
+            '1' | 'L' => 'I',
+            '2' => 'Z',
+            '3' => 'E',
+            '4' => 'A',
+            '5' => 'S',
+            '6' => 'G',
+            '7' => 'T',
+            '8' => 'B',
+            other => other,
+        })
+        .collect()
+}
+
 pub fn validate_metadata(metadata_uri: &str, metadata_hash: &[u8]) -> Result<()> {
     if metadata_hash.len() != 32 {
         return err!(ErrorCode::InvalidMetadataHashLength);
@@ -31,6 +83,128 @@ pub fn validate_metadata(metadata_uri: &str, metadata_hash: &[u8]) -> Result<()>
     Ok(())
 }
 
+pub fn validate_intent_categories(intent_categories: u32) -> Result<()> {
+    require!(
+        intent_categories != 0 && intent_categories & !INTENT_ALL == 0,
+        ErrorCode::InvalidIntentCategories
+    );
+    Ok(())
+}
+
+/// Review window for a (re)submission: the protocol maximum unless the submitter asks for less.
+pub fn resolve_review_window(requested: Option<i64>, config: &ProtocolConfig) -> Result<i64> {
+    let window = requested.unwrap_or(config.max_expiry_duration);
+    require!(
+        window >= config.min_review_window,
+        ErrorCode::ReviewWindowTooShort
+    );
+    require!(
+        window <= config.max_expiry_duration,
+        ErrorCode::ReviewWindowTooLong
+    );
+    Ok(window)
+}
+
+/// Refuses blocked names and reserved names for anyone but the designated claimant.
+/// `reserved_info` is the (possibly uninitialized) reserved-prefix PDA for the name.
+pub fn assert_prefix_available(
+    reserved_info: &AccountInfo,
+    program_id: &Pubkey,
+    submitter: &Pubkey,
+) -> Result<()> {
+    if reserved_info.data_is_empty() {
+        return Ok(());
+    }
+    assert_program_owned(reserved_info, program_id)?;
+    let data = reserved_info.try_borrow_data()?;
+    let reserved = ReservedPrefix::try_deserialize(&mut &data[..])?;
+    match reserved.kind {
+        ReservationKind::Blocked => err!(ErrorCode::PrefixBlocked),
+        ReservationKind::Reserved => {
+            require!(
+                reserved.claimant == Some(*submitter),
+                ErrorCode::PrefixReserved
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Refuses names whose tombstone is still within its cooldown (or permanent).
+/// `tombstone_info` is the (possibly uninitialized) tombstone PDA for the name.
+pub fn assert_not_tombstoned(
+    tombstone_info: &AccountInfo,
+    program_id: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    if tombstone_info.data_is_empty() {
+        return Ok(());
+    }
+    assert_program_owned(tombstone_info, program_id)?;
+    let data = tombstone_info.try_borrow_data()?;
+    let tombstone = Tombstone::try_deserialize(&mut &data[..])?;
+    require!(!tombstone.is_blocking(now), ErrorCode::PrefixRevoked);
+    Ok(())
+}
+
+/// Walks the `parent` chain of `acct` and refuses unless every ancestor is trusted.
+/// Each ancestor PrefixAccount must be supplied in `ancestors` (order does not matter).
+pub fn assert_ancestors_trusted(
+    acct: &PrefixAccount,
+    ancestors: &[AccountInfo],
+    program_id: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    let mut next = acct.parent;
+    while let Some(key) = next {
+        let info = ancestors
+            .iter()
+            .find(|a| *a.key == key)
+            .ok_or(error!(ErrorCode::MissingAncestorAccount))?;
+        assert_program_owned(info, program_id)?;
+        let data = info.try_borrow_data()?;
+        let ancestor = PrefixAccount::try_deserialize(&mut &data[..])?;
+        require!(ancestor.is_trusted(now), ErrorCode::ParentPrefixNotTrusted);
+        next = ancestor.parent;
+    }
+    Ok(())
+}
+
+/// Drops votes whose verifier can no longer review, so a verifier removed, resigned or
+/// slashed below the minimum bond after voting neither counts toward quorum nor keeps one
+/// of the `MAX_QUORUM` vote slots. Every earlier voter must be proven through its
+/// VerifierRecord in `records`; a vote cast without them is refused rather than left
+/// untallied, since the voter could not vote again once recorded.
+pub fn prune_ineligible_votes(
+    votes: &mut Vec<Pubkey>,
+    records: &[AccountInfo],
+    program_id: &Pubkey,
+    min_bond: u64,
+) -> Result<()> {
+    let mut eligible = Vec::with_capacity(votes.len());
+    for vote in votes.iter() {
+        let record = records
+            .iter()
+            .filter(|info| info.owner == program_id && !info.data_is_empty())
+            .find_map(|info| {
+                let data = info.try_borrow_data().ok()?;
+                let record = VerifierRecord::try_deserialize(&mut &data[..]).ok()?;
+                let expected = Pubkey::create_program_address(
+                    &[VERIFIER_SEED, vote.as_ref(), &[record.bump]],
+                    program_id,
+                )
+                .ok()?;
+                (record.verifier == *vote && expected == *info.key).then_some(record)
+            })
+            .ok_or(error!(ErrorCode::VoterRecordRequired))?;
+        if record.can_review(min_bond) {
+            eligible.push(*vote);
+        }
+    }
+    *votes = eligible;
+    Ok(())
+}
+
 pub fn assert_program_owned(account_info: &AccountInfo, program_id: &Pubkey) -> Result<()> {
     require_keys_eq!(
         *account_info.owner,
@@ -39,7 +39,8 @@ async function main() {
         `📦 Fee Registry: ${JSON.stringify(existingFeeRegistry, null, 2)}`
       );
       console.log(`📦 Treasury: ${JSON.stringify(await client.getTreasury(), null, 2)}`);
-      console.log(`👥 Verifiers List: ${JSON.stringify(await client.getVerifiersList(), null, 2)}`);
+      console.log(`🔍 Review Config: ${JSON.stringify(await client.getReviewConfig(), null, 2)}`);
+      console.log(`📐 Protocol Config: ${JSON.stringify(await client.getProtocolConfig(), null, 2)}`);
       console.log(`🏦 Treasury: ${JSON.stringify(await client.getTreasury(), null, 2)}`);
       return;
     } catch (error) {
@@ -17,16 +17,39 @@ import IDL from "./idl/prefix_system.json";
 import {
   FEE_REGISTRY_SEED,
   VERIFIERS_SEED,
+  VERIFIER_SEED,
+  VERIFIER_VAULT_SEED,
   TREASURY_SEED,
   PREFIX_SEED,
+  REVIEW_CONFIG_SEED,
+  PROTOCOL_CONFIG_SEED,
+  REVIEW_SEED,
+  RESERVED_SEED,
+  SKELETON_SEED,
+  COMMITMENT_SEED,
+  TOMBSTONE_SEED,
+  AUDIT_LOG_SEED,
+  SUB_PREFIX_SEPARATOR,
   MAX_AUTH_KEYS,
+  INTENT_PAYMENT,
 } from "./constants.js";
-import { FeeRegistry, PrefixAccount, Treasury, VerifiersList } from "./types";
+import {
+  AuditLog,
+  FeeRegistry,
+  PrefixAccount,
+  ProtocolConfig,
+  RejectionReason,
+  ReviewConfig,
+  Treasury,
+  VerifierRecord,
+  VerifiersList,
+} from "./types";
 import {
   validateMetadataHash,
   validateMetadataUri,
   validatePrefix,
 } from "./validation";
+import { prefixRoot, skeletonPrefix } from "./utils";
 import { PrefixSystemClientError } from "./errors";
 
 export interface PrefixSystemClientConfig {
@@ -42,6 +65,8 @@ export class PrefixSystemClient {
   private anchorProvider: AnchorProvider;
   private staticPdas: {
     feeRegistry: PublicKey;
+    reviewConfig: PublicKey;
+    protocolConfig: PublicKey;
     verifiers: PublicKey;
     treasury: PublicKey;
   };
@@ -69,6 +94,17 @@ export class PrefixSystemClient {
       this._program.programId
     )[0];
 
+    const reviewConfig = PublicKey.findProgramAddressSync(
+      [Buffer.from(REVIEW_CONFIG_SEED)],
+      this._program.programId
+    )[0];
+
+    const protocolConfig = PublicKey.findProgramAddressSync(
+      [Buffer.from(PROTOCOL_CONFIG_SEED)],
+      this._program.programId
+    )[0];
+
+    // Legacy verifier list; only read while migrating to verifier records
     const verifiers = PublicKey.findProgramAddressSync(
       [Buffer.from(VERIFIERS_SEED)],
       this._program.programId
@@ -81,6 +117,8 @@ export class PrefixSystemClient {
 
     this.staticPdas = {
       feeRegistry,
+      reviewConfig,
+      protocolConfig,
       verifiers,
       treasury,
     };
@@ -127,8 +165,33 @@ export class PrefixSystemClient {
     return this._program.account.feeRegistry.fetch(this.staticPdas.feeRegistry);
   }
 
-  public async getVerifiersList(): Promise<VerifiersList> {
-    return this._program.account.verifiersList.fetch(this.staticPdas.verifiers);
+  public async getReviewConfig(): Promise<ReviewConfig> {
+    return this._program.account.reviewConfig.fetch(
+      this.staticPdas.reviewConfig
+    ) as unknown as ReviewConfig;
+  }
+
+  public async getProtocolConfig(): Promise<ProtocolConfig> {
+    return this._program.account.protocolConfig.fetch(
+      this.staticPdas.protocolConfig
+    ) as unknown as ProtocolConfig;
+  }
+
+  /**
+   * Legacy verifier list, present only on deployments that predate verifier records
+   */
+  public async getVerifiersList(): Promise<VerifiersList | null> {
+    return this._program.account.verifiersList.fetchNullable(
+      this.staticPdas.verifiers
+    );
+  }
+
+  public async getVerifierRecord(
+    verifierPublicKey: PublicKey
+  ): Promise<VerifierRecord | null> {
+    return this._program.account.verifierRecord.fetchNullable(
+      this.getVerifierRecordPda(verifierPublicKey)
+    ) as unknown as VerifierRecord | null;
   }
 
   public async getTreasury(): Promise<Treasury | null> {
@@ -149,6 +212,50 @@ export class PrefixSystemClient {
     }
   }
 
+  /**
+   * Status and ownership transitions of a prefix; only the last MAX_AUDIT_ENTRIES are kept
+   */
+  public async getAuditLog(prefix: string): Promise<AuditLog | null> {
+    validatePrefix(prefix);
+
+    return this._program.account.auditLog.fetchNullable(
+      this.getAuditLogPda(prefix)
+    ) as unknown as AuditLog | null;
+  }
+
+  /**
+   * Check whether a prefix can currently be trusted: active, not suspended,
+   * verification not lapsed, and the same holds for every ancestor of a sub-prefix
+   * @param prefix the prefix to check
+   * @param now unix timestamp in seconds to check against (defaults to the local clock)
+   * @returns true if the prefix and all its ancestors are trusted
+   */
+  public async isPrefixTrusted(
+    prefix: string,
+    now: number = Math.floor(Date.now() / 1000)
+  ): Promise<boolean> {
+    let account = (await this._program.account.prefixAccount.fetchNullable(
+      this.getPrefixPda(prefix)
+    )) as unknown as PrefixAccount | null;
+
+    while (account) {
+      const trusted =
+        "active" in account.status &&
+        account.suspendedUntil.lten(now) &&
+        account.verifiedUntil.gtn(now); // i64::MAX when verification never lapses
+      if (!trusted) {
+        return false;
+      }
+      if (!account.parent) {
+        return true;
+      }
+      account = (await this._program.account.prefixAccount.fetchNullable(
+        account.parent
+      )) as unknown as PrefixAccount | null;
+    }
+    return false;
+  }
+
   // !!!! Admin functions !!!!
   /**
    * Initialize the prefix system
@@ -166,7 +273,8 @@ export class PrefixSystemClient {
         payer: this.anchorProvider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
         feeRegistry: this.staticPdas.feeRegistry,
-        verifiers: this.staticPdas.verifiers,
+        reviewConfig: this.staticPdas.reviewConfig,
+        protocolConfig: this.staticPdas.protocolConfig,
         treasury: this.staticPdas.treasury,
       })
       .signers([this.anchorProvider.wallet.payer!])
@@ -176,21 +284,24 @@ export class PrefixSystemClient {
   }
 
   /**
-   * Add a verifier to the verifiers list
+   * Add a verifier; it can review once its bond reaches the protocol minimum
    * @param adminPublicKey public key of the admin who will be the admin of the prefix system
    * @param verifierPublicKey public key of the verifier to add
+   * @param label display name stored on the verifier record
    * @returns unsigned transaction
    */
   public async addVerifier(
     adminPublicKey: PublicKey,
-    verifierPublicKey: PublicKey
+    verifierPublicKey: PublicKey,
+    label: string = ""
   ): Promise<Transaction> {
     const tx = await this._program.methods
-      .addVerifier(verifierPublicKey)
+      .addVerifier(verifierPublicKey, label)
       .accountsStrict({
         admin: adminPublicKey,
-        verifiers: this.staticPdas.verifiers,
         feeRegistry: this.staticPdas.feeRegistry,
+        verifierRecord: this.getVerifierRecordPda(verifierPublicKey),
+        systemProgram: SystemProgram.programId,
       })
       .signers([this.anchorProvider.wallet.payer!])
       .transaction();
@@ -199,21 +310,24 @@ export class PrefixSystemClient {
   }
 
   /**
-   * Remove a verifier from the verifiers list
+   * Remove a verifier
    * @param adminPublicKey public key of the admin who will be the admin of the prefix system
    * @param verifierPublicKey public key of the verifier to remove
+   * @param claimedPrefix prefix under the verifier's live review claim, if any
    * @returns unsigned transaction
    */
   public async removeVerifier(
     adminPublicKey: PublicKey,
-    verifierPublicKey: PublicKey
+    verifierPublicKey: PublicKey,
+    claimedPrefix: string | null = null
   ): Promise<Transaction> {
     const tx = await this._program.methods
       .removeVerifier(verifierPublicKey)
       .accountsStrict({
         admin: adminPublicKey,
-        verifiers: this.staticPdas.verifiers,
         feeRegistry: this.staticPdas.feeRegistry,
+        verifierRecord: this.getVerifierRecordPda(verifierPublicKey),
+        claimedPrefix: claimedPrefix ? this.getPrefixPda(claimedPrefix) : null,
       })
       .signers([this.anchorProvider.wallet.payer!])
       .transaction();
@@ -243,6 +357,40 @@ export class PrefixSystemClient {
     return tx;
   }
 
+  /**
+   * Set the commit-reveal policy; with `commitRequired` direct submissions are refused
+   * @param adminPublicKey public key of the admin who will be the admin of the prefix system
+   * @param commitRequired whether registration must go through commit and reveal
+   * @param commitDeposit lamports escrowed with each commitment
+   * @param minRevealDelay seconds before a commitment can be revealed
+   * @param commitmentTtl seconds after which an unrevealed commitment expires
+   * @returns unsigned transaction
+   */
+  public async setCommitPolicy(
+    adminPublicKey: PublicKey,
+    commitRequired: boolean,
+    commitDeposit: number,
+    minRevealDelay: number,
+    commitmentTtl: number
+  ): Promise<Transaction> {
+    const tx = await this._program.methods
+      .setCommitPolicy(
+        commitRequired,
+        new BN(commitDeposit),
+        new BN(minRevealDelay),
+        new BN(commitmentTtl)
+      )
+      .accountsStrict({
+        admin: adminPublicKey,
+        feeRegistry: this.staticPdas.feeRegistry,
+        protocolConfig: this.staticPdas.protocolConfig,
+      })
+      .signers([this.anchorProvider.wallet.payer!])
+      .transaction();
+
+    return tx;
+  }
+
   /**
    * Withdraw from the treasury
    * @param adminPublicKey public key of the admin who will be the admin of the prefix system
@@ -270,30 +418,61 @@ export class PrefixSystemClient {
     return tx;
   }
 
-  // !!! Prefix functions for admin authority !!!!
+  // !!! Verifier functions !!!!
   /**
-   * Approve a prefix
-   * @param adminPublicKey public key of the admin who will be the admin of the prefix system
+   * Deposit lamports into the verifier's bond vault
+   * @param verifierPublicKey public key of the verifier
+   * @param amount lamports to bond
+   * @returns unsigned transaction
+   */
+  public async depositVerifierBond(
+    verifierPublicKey: PublicKey,
+    amount: number
+  ): Promise<Transaction> {
+    const tx = await this._program.methods
+      .depositVerifierBond(new BN(amount))
+      .accountsStrict({
+        verifier: verifierPublicKey,
+        verifierRecord: this.getVerifierRecordPda(verifierPublicKey),
+        vault: this.getVerifierVaultPda(verifierPublicKey),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([this.anchorProvider.wallet.payer!])
+      .transaction();
+
+    return tx;
+  }
+
+  /**
+   * Approve a prefix; it becomes active once the quorum of approvals is reached
+   * @param verifierPublicKey public key of the verifier casting the vote
    * @param prefix the prefix to approve
    * @param refHash the reference hash to approve the prefix with
+   * @param otherVoters verifiers who already voted this round; all of them are required so
+   * the program can drop votes from verifiers who can no longer review
    * @returns unsigned transaction
    */
   public async approvePrefix(
-    adminPublicKey: PublicKey,
+    verifierPublicKey: PublicKey,
     prefix: string,
-    refHash: Array<number>
+    refHash: Array<number>,
+    otherVoters: Array<PublicKey> = []
   ): Promise<Transaction> {
     validatePrefix(prefix);
 
     const tx = await this._program.methods
       .approvePrefix(prefix, refHash)
       .accountsStrict({
-        verifier: adminPublicKey,
+        verifier: verifierPublicKey,
         feeRegistry: this.staticPdas.feeRegistry,
-        verifiers: this.staticPdas.verifiers,
-        prefixAccount: this.getPrefixPda(prefix),
+        verifierRecord: this.getVerifierRecordPda(verifierPublicKey),
+        reviewConfig: this.staticPdas.reviewConfig,
         treasury: this.staticPdas.treasury,
+        prefixAccount: this.getPrefixPda(prefix),
+        auditLog: this.getAuditLogPda(prefix),
+        reviewState: this.getReviewStatePda(prefix),
       })
+      .remainingAccounts(this.voterRecordMetas(otherVoters))
       .signers([this.anchorProvider.wallet.payer!])
       .transaction();
 
@@ -301,33 +480,44 @@ export class PrefixSystemClient {
   }
 
   /**
-   * Reject a prefix
-   * @param adminPublicKey public key of the admin who will be the admin of the prefix system
+   * Reject a prefix; it is rejected once the quorum of rejections is reached
+   * @param verifierPublicKey public key of the verifier casting the vote
    * @param prefix the prefix to reject
-   * @param reason the reason for rejecting the prefix
+   * @param reasonCode structured reason, e.g. RejectionReason.Impersonation
+   * @param evidenceHash hash of off-chain evidence, if any
+   * @param reason free-form note for the owner
+   * @param otherVoters verifiers who already voted this round; all of them are required
    * @returns unsigned transaction
    */
   public async rejectPrefix(
-    adminPublicKey: PublicKey,
+    verifierPublicKey: PublicKey,
     prefix: string,
-    reason: string
+    reasonCode: RejectionReason,
+    evidenceHash: Array<number> | null,
+    reason: string,
+    otherVoters: Array<PublicKey> = []
   ): Promise<Transaction> {
     validatePrefix(prefix);
 
     const tx = await this._program.methods
-      .rejectPrefix(prefix, reason)
+      .rejectPrefix(prefix, reasonCode as any, evidenceHash, reason)
       .accountsStrict({
-        verifier: adminPublicKey,
+        verifier: verifierPublicKey,
         feeRegistry: this.staticPdas.feeRegistry,
-        verifiers: this.staticPdas.verifiers,
+        verifierRecord: this.getVerifierRecordPda(verifierPublicKey),
+        reviewConfig: this.staticPdas.reviewConfig,
         prefixAccount: this.getPrefixPda(prefix),
+        auditLog: this.getAuditLogPda(prefix),
+        reviewState: this.getReviewStatePda(prefix),
       })
+      .remainingAccounts(this.voterRecordMetas(otherVoters))
       .signers([this.anchorProvider.wallet.payer!])
       .transaction();
 
     return tx;
   }
 
+  // !!! Prefix functions for admin authority !!!!
   /**
    * Deactivate a prefix
    * @param adminPublicKey public key of the admin who will be the admin of the prefix system
@@ -346,6 +536,7 @@ export class PrefixSystemClient {
         admin: adminPublicKey,
         feeRegistry: this.staticPdas.feeRegistry,
         prefixAccount: this.getPrefixPda(prefix),
+        auditLog: this.getAuditLogPda(prefix),
       })
       .signers([this.anchorProvider.wallet.payer!])
       .transaction();
@@ -371,6 +562,7 @@ export class PrefixSystemClient {
         admin: adminPublicKey,
         feeRegistry: this.staticPdas.feeRegistry,
         prefixAccount: this.getPrefixPda(prefix),
+        auditLog: this.getAuditLogPda(prefix),
       })
       .signers([this.anchorProvider.wallet.payer!])
       .transaction();
@@ -380,12 +572,12 @@ export class PrefixSystemClient {
 
   /**
    * Refund the fee for a prefix
-   * @param adminPublicKey public key of the admin who will be the admin of the prefix system
+   * @param ownerPublicKey public key of the owner of the prefix
    * @param prefix the prefix to refund the fee for
    * @returns unsigned transaction
    */
   public async refundPrefixFee(
-    adminPublicKey: PublicKey,
+    ownerPublicKey: PublicKey,
     prefix: string
   ): Promise<Transaction> {
     validatePrefix(prefix);
@@ -393,10 +585,13 @@ export class PrefixSystemClient {
     const tx = await this._program.methods
       .refundPrefixFee(prefix)
       .accountsStrict({
-        owner: adminPublicKey,
-        prefixAccount: this.getPrefixPda(prefix),
-        treasury: this.staticPdas.treasury,
+        owner: ownerPublicKey,
         feeRegistry: this.staticPdas.feeRegistry,
+        treasury: this.staticPdas.treasury,
+        prefixAccount: this.getPrefixPda(prefix),
+        reviewState: this.getReviewStatePda(prefix),
+        skeletonGuard: this.getSkeletonGuardPda(prefix),
+        auditLog: this.getAuditLogPda(prefix),
         systemProgram: SystemProgram.programId,
       })
       .signers([this.anchorProvider.wallet.payer!])
@@ -427,6 +622,7 @@ export class PrefixSystemClient {
         feeRegistry: this.staticPdas.feeRegistry,
         treasury: this.staticPdas.treasury,
         prefixAccount: this.getPrefixPda(prefix),
+        auditLog: this.getAuditLogPda(prefix),
         systemProgram: SystemProgram.programId,
       })
       .signers([this.anchorProvider.wallet.payer!])
@@ -437,10 +633,16 @@ export class PrefixSystemClient {
 
   // !!! Prefix functions for user/owner authority !!!!
   /**
-   * Submit a prefix with a fee
+   * Submit a prefix with a fee; only accepted while the protocol does not require
+   * commit-reveal (see commitPrefix / revealPrefix)
    * @param ownerPublicKey public key of the owner who will be the owner of the prefix
    * @param prefix the prefix to submit
-   * @param fee the fee to submit
+   * @param metadataUri the metadata URI
+   * @param metadataHash the metadata hash
+   * @param signatureOverMetadataHash owner's Ed25519 signature over the metadata hash
+   * @param authorityKeys the authority keys
+   * @param intentCategories INTENT_* bitmask the prefix will be used for
+   * @param reviewWindow seconds verifiers have to decide; null for the protocol maximum
    * @returns unsigned transaction
    */
   public async submitPrefixWithFee(
@@ -449,7 +651,9 @@ export class PrefixSystemClient {
     metadataUri: string,
     metadataHash: Array<number>,
     signatureOverMetadataHash: Array<number>,
-    authorityKeys: Array<PublicKey>
+    authorityKeys: Array<PublicKey>,
+    intentCategories: number = INTENT_PAYMENT,
+    reviewWindow: number | null = null
   ): Promise<Transaction> {
     validateMetadataUri(metadataUri);
     validateMetadataHash(metadataHash);
@@ -462,15 +666,90 @@ export class PrefixSystemClient {
     });
 
     const tx = await this._program.methods
-      .submitPrefixWithFee(prefix, metadataUri, metadataHash, authorityKeys)
+      .submitPrefixWithFee(
+        prefix,
+        metadataUri,
+        metadataHash,
+        authorityKeys,
+        intentCategories,
+        reviewWindow === null ? null : new BN(reviewWindow)
+      )
+      .accountsStrict(this.submitAccounts(ownerPublicKey, prefix))
+      .preInstructions([ed25519Ix])
+      .signers([this.anchorProvider.wallet.payer!])
+      .transaction();
+
+    return tx;
+  }
+
+  /**
+   * Commit to registering a prefix without revealing it; see prefixCommitment
+   * @param ownerPublicKey public key of the owner who will reveal the prefix
+   * @param commitment sha256(prefix || owner || salt)
+   * @returns unsigned transaction
+   */
+  public async commitPrefix(
+    ownerPublicKey: PublicKey,
+    commitment: Array<number>
+  ): Promise<Transaction> {
+    const tx = await this._program.methods
+      .commitPrefix(commitment)
       .accountsStrict({
         owner: ownerPublicKey,
         feeRegistry: this.staticPdas.feeRegistry,
-        treasury: this.staticPdas.treasury,
-        prefixAccount: this.getPrefixPda(prefix),
-        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        protocolConfig: this.staticPdas.protocolConfig,
+        prefixCommitment: this.getCommitmentPda(ownerPublicKey, commitment),
         systemProgram: SystemProgram.programId,
       })
+      .signers([this.anchorProvider.wallet.payer!])
+      .transaction();
+
+    return tx;
+  }
+
+  /**
+   * Reveal a committed prefix and register it with the same checks as a direct submission
+   * @param ownerPublicKey public key of the owner who committed
+   * @param prefix the committed prefix
+   * @param salt the salt used for the commitment
+   * @param commitment the commitment being revealed
+   * @returns unsigned transaction
+   */
+  public async revealPrefix(
+    ownerPublicKey: PublicKey,
+    prefix: string,
+    salt: Array<number>,
+    commitment: Array<number>,
+    metadataUri: string,
+    metadataHash: Array<number>,
+    signatureOverMetadataHash: Array<number>,
+    authorityKeys: Array<PublicKey>,
+    intentCategories: number = INTENT_PAYMENT,
+    reviewWindow: number | null = null
+  ): Promise<Transaction> {
+    validateMetadataUri(metadataUri);
+    validateMetadataHash(metadataHash);
+
+    const ed25519Ix = Ed25519Program.createInstructionWithPublicKey({
+      publicKey: ownerPublicKey.toBytes(),
+      message: new Uint8Array(metadataHash),
+      signature: new Uint8Array(signatureOverMetadataHash),
+    });
+
+    const tx = await this._program.methods
+      .revealPrefix(
+        prefix,
+        salt,
+        metadataUri,
+        metadataHash,
+        authorityKeys,
+        intentCategories,
+        reviewWindow === null ? null : new BN(reviewWindow)
+      )
+      .accountsStrict({
+        submit: this.submitAccounts(ownerPublicKey, prefix),
+        prefixCommitment: this.getCommitmentPda(ownerPublicKey, commitment),
+      })
       .preInstructions([ed25519Ix])
       .signers([this.anchorProvider.wallet.payer!])
       .transaction();
@@ -484,6 +763,7 @@ export class PrefixSystemClient {
    * @param prefix the prefix to update the metadata for
    * @param newMetadataUri the new metadata URI
    * @param newMetadataHash the new metadata hash
+   * @param intentCategories INTENT_* bitmask the prefix will be used for
    * @returns unsigned transaction
    */
   public async updatePrefixMetadata(
@@ -491,7 +771,8 @@ export class PrefixSystemClient {
     prefix: string,
     newMetadataUri: string,
     newMetadataHash: Array<number>,
-    signatureOverMetadataHash: Array<number>
+    signatureOverMetadataHash: Array<number>,
+    intentCategories: number = INTENT_PAYMENT
   ): Promise<Transaction> {
     validatePrefix(prefix);
     validateMetadataUri(newMetadataUri);
@@ -505,10 +786,17 @@ export class PrefixSystemClient {
     });
 
     const tx = await this._program.methods
-      .updatePrefixMetadata(prefix, newMetadataUri, newMetadataHash)
+      .updatePrefixMetadata(
+        prefix,
+        newMetadataUri,
+        newMetadataHash,
+        intentCategories
+      )
       .accountsStrict({
         owner: ownerPublicKey,
+        protocolConfig: this.staticPdas.protocolConfig,
         prefixAccount: this.getPrefixPda(prefix),
+        auditLog: this.getAuditLogPda(prefix),
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
       })
       .preInstructions([ed25519Ix])
@@ -566,4 +854,109 @@ export class PrefixSystemClient {
       this._program.programId
     )[0];
   }
+
+  public getReviewStatePda(prefix: string): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(REVIEW_SEED), Buffer.from(prefix.toUpperCase())],
+      this._program.programId
+    )[0];
+  }
+
+  public getAuditLogPda(prefix: string): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(AUDIT_LOG_SEED), Buffer.from(prefix.toUpperCase())],
+      this._program.programId
+    )[0];
+  }
+
+  /**
+   * Skeleton guard, reserved-name and tombstone PDAs are keyed by the prefix skeleton,
+   * so confusable names share them
+   */
+  public getSkeletonGuardPda(prefix: string): PublicKey {
+    return this.skeletonPda(SKELETON_SEED, prefix);
+  }
+
+  public getReservedPrefixPda(prefix: string): PublicKey {
+    return this.skeletonPda(RESERVED_SEED, prefix);
+  }
+
+  public getTombstonePda(prefix: string): PublicKey {
+    return this.skeletonPda(TOMBSTONE_SEED, prefix);
+  }
+
+  public getVerifierRecordPda(verifierPublicKey: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(VERIFIER_SEED), verifierPublicKey.toBuffer()],
+      this._program.programId
+    )[0];
+  }
+
+  public getVerifierVaultPda(verifierPublicKey: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(VERIFIER_VAULT_SEED), verifierPublicKey.toBuffer()],
+      this._program.programId
+    )[0];
+  }
+
+  public getCommitmentPda(
+    ownerPublicKey: PublicKey,
+    commitment: Array<number>
+  ): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(COMMITMENT_SEED),
+        ownerPublicKey.toBuffer(),
+        Buffer.from(commitment),
+      ],
+      this._program.programId
+    )[0];
+  }
+
+  private skeletonPda(seed: string, prefix: string): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(seed), Buffer.from(skeletonPrefix(prefix.toUpperCase()))],
+      this._program.programId
+    )[0];
+  }
+
+  // Accounts shared by submitPrefixWithFee and revealPrefix
+  private submitAccounts(ownerPublicKey: PublicKey, prefix: string) {
+    const normalized = prefix.toUpperCase();
+    return {
+      owner: ownerPublicKey,
+      feeRegistry: this.staticPdas.feeRegistry,
+      protocolConfig: this.staticPdas.protocolConfig,
+      treasury: this.staticPdas.treasury,
+      prefixAccount: this.getPrefixPda(normalized),
+      reviewState: this.getReviewStatePda(normalized),
+      skeletonGuard: this.getSkeletonGuardPda(normalized),
+      auditLog: this.getAuditLogPda(normalized),
+      reservedPrefix: this.getReservedPrefixPda(normalized),
+      // Hyphenated names must prove the part before the hyphen (or a confusable of it) is not
+      // registered, reserved for someone else or revoked
+      ...this.rootAccounts(normalized),
+      tombstone: this.getTombstonePda(normalized),
+      instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  private rootAccounts(normalized: string) {
+    const hyphenated = normalized.includes(SUB_PREFIX_SEPARATOR);
+    const root = prefixRoot(normalized);
+    return {
+      rootSkeletonGuard: hyphenated ? this.getSkeletonGuardPda(root) : null,
+      rootReservedPrefix: hyphenated ? this.getReservedPrefixPda(root) : null,
+      rootTombstone: hyphenated ? this.getTombstonePda(root) : null,
+    };
+  }
+
+  private voterRecordMetas(voters: Array<PublicKey>) {
+    return voters.map((voter) => ({
+      pubkey: this.getVerifierRecordPda(voter),
+      isSigner: false,
+      isWritable: false,
+    }));
+  }
 }
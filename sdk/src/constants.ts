@@ -1,10 +1,32 @@
 export const FEE_REGISTRY_SEED = "fee_registry";
-export const VERIFIERS_SEED = "verifiers";
+export const VERIFIERS_SEED = "verifiers"; // legacy VerifiersList, superseded by VerifierRecord
+export const VERIFIER_SEED = "verifier";
+export const VERIFIER_VAULT_SEED = "verifier_vault";
 export const TREASURY_SEED = "treasury";
 export const PREFIX_SEED = "prefix";
+export const REVIEW_CONFIG_SEED = "review_config";
+export const PROTOCOL_CONFIG_SEED = "protocol_config";
+export const REVIEW_SEED = "review";
+export const APPEAL_SEED = "appeal";
+export const RESERVED_SEED = "reserved";
+export const SKELETON_SEED = "skeleton";
+export const LISTING_SEED = "listing";
+export const AUCTION_SEED = "auction";
+export const COMMITMENT_SEED = "commitment";
+export const TOMBSTONE_SEED = "tombstone";
+export const AUDIT_LOG_SEED = "audit_log";
 
 export const MAX_PREFIX_LEN = 12; // A-Z0-9 up to 12
 export const MIN_PREFIX_LEN = 3;
 export const MAX_URI_LEN = 255; // conservative cap
 export const MAX_AUTH_KEYS = 10;
-export const MAX_VERIFIERS = 256;
+export const SUB_PREFIX_SEPARATOR = "-";
+export const MAX_AUDIT_ENTRIES = 16;
+
+// Intent category bits declared on a prefix (mirrors metadata `intentCategories`)
+export const INTENT_PAYMENT = 1 << 0;
+export const INTENT_STAKE = 1 << 1;
+export const INTENT_VOTE = 1 << 2;
+export const INTENT_SWAP = 1 << 3;
+export const INTENT_MINT = 1 << 4;
+export const INTENT_OTHER = 1 << 5;
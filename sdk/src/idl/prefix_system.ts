@@ -28,6 +28,7 @@ export type PrefixSystem = {
       "accounts": [
         {
           "name": "admin",
+          "writable": true,
           "signer": true
         },
         {
@@ -55,7 +56,10 @@ export type PrefixSystem = {
           }
         },
         {
-          "name": "verifiers",
+          "name": "verifierRecord",
+          "docs": [
+            "Re-adding a removed verifier reuses its existing record"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -69,36 +73,48 @@ export type PrefixSystem = {
                   102,
                   105,
                   101,
-                  114,
-                  115
+                  114
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "verifier"
               }
             ]
           }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
           "name": "verifier",
           "type": "pubkey"
+        },
+        {
+          "name": "label",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "approvePrefix",
+      "name": "appealRejection",
       "discriminator": [
-        155,
-        130,
-        58,
-        144,
-        235,
-        206,
-        247,
-        80
+        170,
+        147,
+        55,
+        166,
+        33,
+        79,
+        205,
+        12
       ],
       "accounts": [
         {
-          "name": "verifier",
+          "name": "owner",
+          "writable": true,
           "signer": true
         },
         {
@@ -126,29 +142,30 @@ export type PrefixSystem = {
           }
         },
         {
-          "name": "verifiers",
+          "name": "reviewConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  101,
                   114,
+                  101,
+                  118,
                   105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
                   102,
                   105,
-                  101,
-                  114,
-                  115
+                  103
                 ]
               }
             ]
           }
         },
-        {
-          "name": "treasury"
-        },
         {
           "name": "prefixAccount",
           "writable": true,
@@ -171,6 +188,62 @@ export type PrefixSystem = {
               }
             ]
           }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "appeal",
+          "docs": [
+            "Appeal PDA; escrows the bond until resolution"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  112,
+                  112,
+                  101,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
@@ -179,7 +252,7 @@ export type PrefixSystem = {
           "type": "string"
         },
         {
-          "name": "refHash",
+          "name": "evidenceHash",
           "type": {
             "array": [
               "u8",
@@ -190,20 +263,20 @@ export type PrefixSystem = {
       ]
     },
     {
-      "name": "deactivatePrefix",
+      "name": "approvePrefix",
       "discriminator": [
-        204,
-        8,
-        250,
-        229,
-        95,
-        93,
-        67,
-        107
+        155,
+        130,
+        58,
+        144,
+        235,
+        206,
+        247,
+        80
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "verifier",
           "signer": true
         },
         {
@@ -230,6 +303,59 @@ export type PrefixSystem = {
             ]
           }
         },
+        {
+          "name": "verifierRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury"
+        },
         {
           "name": "prefixAccount",
           "writable": true,
@@ -252,42 +378,97 @@ export type PrefixSystem = {
               }
             ]
           }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
         }
       ],
       "args": [
         {
           "name": "prefix",
           "type": "string"
+        },
+        {
+          "name": "refHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
         }
       ]
     },
     {
-      "name": "initialize",
+      "name": "buyPrefix",
       "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
+        128,
+        67,
+        196,
+        227,
+        167,
+        82,
+        37,
+        137
       ],
       "accounts": [
         {
-          "name": "payer",
-          "docs": [
-            "Payer who funds the initial accounts (must be a signer)"
-          ],
+          "name": "buyer",
           "writable": true,
           "signer": true
         },
+        {
+          "name": "seller",
+          "writable": true
+        },
         {
           "name": "feeRegistry",
-          "docs": [
-            "Fee registry PDA"
-          ],
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -311,25 +492,27 @@ export type PrefixSystem = {
           }
         },
         {
-          "name": "verifiers",
-          "docs": [
-            "Verifiers list PDA"
-          ],
-          "writable": true,
+          "name": "protocolConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  101,
+                  112,
                   114,
-                  105,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
                   102,
                   105,
-                  101,
-                  114,
-                  115
+                  103
                 ]
               }
             ]
@@ -361,36 +544,110 @@ export type PrefixSystem = {
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "adminPubkey",
-          "type": "pubkey"
-        },
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
         {
-          "name": "initialFee",
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "maxPrice",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "reactivatePrefix",
+      "name": "cancelAuction",
       "discriminator": [
-        7,
-        33,
-        55,
-        144,
-        195,
-        34,
-        242,
-        239
+        156,
+        43,
+        197,
+        110,
+        218,
+        105,
+        143,
+        182
       ],
       "accounts": [
         {
           "name": "admin",
+          "writable": true,
           "signer": true
         },
         {
@@ -418,19 +675,20 @@ export type PrefixSystem = {
           }
         },
         {
-          "name": "prefixAccount",
+          "name": "auction",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  101,
-                  102,
+                  97,
+                  117,
+                  99,
+                  116,
                   105,
-                  120
+                  111,
+                  110
                 ]
               },
               {
@@ -439,6 +697,15 @@ export type PrefixSystem = {
               }
             ]
           }
+        },
+        {
+          "name": "skeletonGuard",
+          "writable": true
+        },
+        {
+          "name": "bidder",
+          "writable": true,
+          "optional": true
         }
       ],
       "args": [
@@ -449,31 +716,20 @@ export type PrefixSystem = {
       ]
     },
     {
-      "name": "recoverPrefixOwnerWithFee",
+      "name": "claimReview",
       "discriminator": [
-        161,
-        252,
-        183,
-        129,
-        28,
-        153,
-        208,
-        98
+        228,
+        40,
+        159,
+        26,
+        50,
+        4,
+        163,
+        156
       ],
       "accounts": [
         {
-          "name": "newOwner",
-          "docs": [
-            "New owner who will pay the recovery fee"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "admin",
-          "docs": [
-            "Admin/multisig who authorizes the recovery"
-          ],
+          "name": "verifier",
           "signer": true
         },
         {
@@ -501,26 +757,51 @@ export type PrefixSystem = {
           }
         },
         {
-          "name": "treasury",
-          "writable": true,
+          "name": "reviewConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  116,
                   114,
                   101,
-                  97,
-                  115,
-                  117,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
                   114,
-                  121
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "feeRegistry"
+                "path": "verifier"
               }
             ]
           }
@@ -549,32 +830,46 @@ export type PrefixSystem = {
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "reviewState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
         }
       ],
       "args": [
         {
           "name": "prefix",
           "type": "string"
-        },
-        {
-          "name": "newOwner",
-          "type": "pubkey"
         }
       ]
     },
     {
-      "name": "refundPrefixFee",
+      "name": "commitPrefix",
       "discriminator": [
-        235,
-        130,
-        86,
-        154,
-        26,
-        65,
-        65,
-        249
+        147,
+        13,
+        255,
+        20,
+        64,
+        53,
+        55,
+        53
       ],
       "accounts": [
         {
@@ -607,49 +902,62 @@ export type PrefixSystem = {
           }
         },
         {
-          "name": "treasury",
-          "writable": true,
+          "name": "protocolConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  116,
-                  114,
-                  101,
-                  97,
-                  115,
-                  117,
+                  112,
                   114,
-                  121
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "feeRegistry"
               }
             ]
           }
         },
         {
-          "name": "prefixAccount",
+          "name": "prefixCommitment",
+          "docs": [
+            "Commitment PDA; escrows the deposit until reveal or refund"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  101,
-                  102,
+                  99,
+                  111,
+                  109,
+                  109,
                   105,
-                  120
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
                 ]
               },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
               {
                 "kind": "arg",
-                "path": "prefix"
+                "path": "commitment"
               }
             ]
           }
@@ -661,26 +969,35 @@ export type PrefixSystem = {
       ],
       "args": [
         {
-          "name": "prefix",
-          "type": "string"
+          "name": "commitment",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
         }
       ]
     },
     {
-      "name": "rejectPrefix",
+      "name": "createSubPrefix",
       "discriminator": [
-        27,
-        106,
-        152,
-        79,
-        143,
         142,
-        116,
-        46
+        164,
+        164,
+        86,
+        5,
+        38,
+        123,
+        249
       ],
       "accounts": [
         {
-          "name": "verifier",
+          "name": "parentOwner",
+          "docs": [
+            "Owner of the parent prefix; pays for the child accounts"
+          ],
+          "writable": true,
           "signer": true
         },
         {
@@ -708,28 +1025,34 @@ export type PrefixSystem = {
           }
         },
         {
-          "name": "verifiers",
+          "name": "protocolConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  101,
+                  112,
                   114,
-                  105,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
                   102,
                   105,
-                  101,
-                  114,
-                  115
+                  103
                 ]
               }
             ]
           }
         },
         {
-          "name": "prefixAccount",
+          "name": "parentAccount",
           "writable": true,
           "pda": {
             "seeds": [
@@ -746,34 +1069,91 @@ export type PrefixSystem = {
               },
               {
                 "kind": "arg",
-                "path": "prefix"
+                "path": "parentPrefix"
               }
             ]
           }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true
+        },
+        {
+          "name": "reviewState",
+          "writable": true
+        },
+        {
+          "name": "skeletonGuard",
+          "writable": true
+        },
+        {
+          "name": "auditLog",
+          "docs": [
+            "Kept from an earlier registration of the name if it was revoked; sized like every",
+            "other log since it outlives the prefix"
+          ],
+          "writable": true
+        },
+        {
+          "name": "reservedPrefix"
+        },
+        {
+          "name": "tombstone"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "prefix",
+          "name": "parentPrefix",
           "type": "string"
         },
         {
-          "name": "reason",
+          "name": "label",
+          "type": "string"
+        },
+        {
+          "name": "owner",
+          "type": "pubkey"
+        },
+        {
+          "name": "metadataUri",
           "type": "string"
+        },
+        {
+          "name": "metadataHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "authorityKeys",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "intentCategories",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "removeVerifier",
+      "name": "deactivatePrefix",
       "discriminator": [
-        179,
-        9,
-        132,
-        183,
-        233,
-        23,
-        172,
-        111
+        204,
+        8,
+        250,
+        229,
+        95,
+        93,
+        67,
+        107
       ],
       "accounts": [
         {
@@ -805,23 +1185,50 @@ export type PrefixSystem = {
           }
         },
         {
-          "name": "verifiers",
+          "name": "prefixAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  101,
+                  112,
                   114,
-                  105,
+                  101,
                   102,
                   105,
-                  101,
-                  114,
-                  115
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
               }
             ]
           }
@@ -829,49 +1236,75 @@ export type PrefixSystem = {
       ],
       "args": [
         {
-          "name": "verifier",
-          "type": "pubkey"
+          "name": "prefix",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "setPause",
+      "name": "delistPrefix",
       "discriminator": [
-        63,
-        32,
-        154,
-        2,
-        56,
-        103,
-        79,
-        45
+        197,
+        212,
+        30,
+        191,
+        57,
+        242,
+        214,
+        224
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "signer",
           "signer": true
         },
         {
-          "name": "feeRegistry",
+          "name": "seller",
+          "writable": true
+        },
+        {
+          "name": "prefixAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  102,
-                  101,
-                  101,
-                  95,
+                  112,
                   114,
                   101,
-                  103,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
                   105,
                   115,
                   116,
-                  114,
-                  121
+                  105,
+                  110,
+                  103
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
               }
             ]
           }
@@ -879,81 +1312,114 @@ export type PrefixSystem = {
       ],
       "args": [
         {
-          "name": "pause",
-          "type": "bool"
+          "name": "prefix",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "submitPrefixWithFee",
+      "name": "depositVerifierBond",
       "discriminator": [
-        36,
-        227,
-        120,
-        228,
-        189,
-        42,
-        195,
-        166
+        98,
+        150,
+        167,
+        127,
+        239,
+        205,
+        201,
+        42
       ],
       "accounts": [
         {
-          "name": "owner",
-          "docs": [
-            "Owner must be signer to pay for account creation"
-          ],
+          "name": "verifier",
           "writable": true,
           "signer": true
         },
         {
-          "name": "feeRegistry",
+          "name": "verifierRecord",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  102,
-                  101,
+                  118,
                   101,
-                  95,
                   114,
-                  101,
-                  103,
                   105,
-                  115,
-                  116,
-                  114,
-                  121
+                  102,
+                  105,
+                  101,
+                  114
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "verifier"
               }
             ]
           }
         },
         {
-          "name": "treasury",
+          "name": "vault",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  116,
+                  118,
+                  101,
                   114,
+                  105,
+                  102,
+                  105,
                   101,
+                  114,
+                  95,
+                  118,
                   97,
-                  115,
                   117,
-                  114,
-                  121
+                  108,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "feeRegistry"
+                "path": "verifier"
               }
             ]
           }
         },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "emergencyFreeze",
+      "discriminator": [
+        179,
+        69,
+        168,
+        100,
+        173,
+        7,
+        136,
+        112
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
         {
           "name": "prefixAccount",
           "writable": true,
@@ -978,51 +1444,50 @@ export type PrefixSystem = {
           }
         },
         {
-          "name": "instructionsSysvar",
-          "address": "Sysvar1nstructions1111111111111111111111111"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "prefix",
-          "type": "string"
-        },
-        {
-          "name": "metadataUri",
-          "type": "string"
-        },
-        {
-          "name": "metadataHash",
-          "type": {
-            "array": [
-              "u8",
-              32
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
             ]
           }
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "authorityKeys",
-          "type": {
-            "vec": "pubkey"
-          }
+          "name": "prefix",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "updateFee",
+      "name": "endSuspension",
       "discriminator": [
-        232,
-        253,
-        195,
-        247,
-        148,
         212,
-        73,
-        222
+        129,
+        231,
+        203,
+        21,
+        175,
+        230,
+        22
       ],
       "accounts": [
         {
@@ -1031,7 +1496,6 @@ export type PrefixSystem = {
         },
         {
           "name": "feeRegistry",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1053,31 +1517,6 @@ export type PrefixSystem = {
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newFee",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "updatePrefixAuthority",
-      "discriminator": [
-        81,
-        34,
-        201,
-        205,
-        160,
-        31,
-        146,
-        85
-      ],
-      "accounts": [
-        {
-          "name": "owner",
-          "signer": true
         },
         {
           "name": "prefixAccount",
@@ -1101,38 +1540,131 @@ export type PrefixSystem = {
               }
             ]
           }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
         }
       ],
       "args": [
         {
           "name": "prefix",
           "type": "string"
-        },
-        {
-          "name": "authorityKeys",
-          "type": {
-            "vec": "pubkey"
-          }
         }
       ]
     },
     {
-      "name": "updatePrefixMetadata",
+      "name": "extendReviewWindow",
       "discriminator": [
-        208,
-        18,
-        7,
-        217,
-        10,
-        21,
-        141,
-        252
+        36,
+        157,
+        185,
+        233,
+        90,
+        164,
+        197,
+        173
       ],
       "accounts": [
         {
-          "name": "owner",
+          "name": "verifier",
           "signer": true
         },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
         {
           "name": "prefixAccount",
           "writable": true,
@@ -1155,10 +1687,6 @@ export type PrefixSystem = {
               }
             ]
           }
-        },
-        {
-          "name": "instructionsSysvar",
-          "address": "Sysvar1nstructions1111111111111111111111111"
         }
       ],
       "args": [
@@ -1167,31 +1695,22 @@ export type PrefixSystem = {
           "type": "string"
         },
         {
-          "name": "newMetadataUri",
-          "type": "string"
-        },
-        {
-          "name": "newMetadataHash",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
+          "name": "extension",
+          "type": "i64"
         }
       ]
     },
     {
-      "name": "withdrawTreasury",
+      "name": "extendSuspension",
       "discriminator": [
-        40,
-        63,
-        122,
-        158,
-        144,
-        216,
-        83,
-        96
+        211,
+        21,
+        238,
+        208,
+        55,
+        26,
+        117,
+        163
       ],
       "accounts": [
         {
@@ -1223,380 +1742,9408 @@ export type PrefixSystem = {
           }
         },
         {
-          "name": "treasury",
+          "name": "prefixAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  116,
+                  112,
                   114,
                   101,
-                  97,
-                  115,
-                  117,
-                  114,
-                  121
+                  102,
+                  105,
+                  120
                 ]
               },
               {
-                "kind": "account",
-                "path": "feeRegistry"
+                "kind": "arg",
+                "path": "prefix"
               }
             ]
           }
         },
         {
-          "name": "to",
-          "writable": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "prefix",
+          "type": "string"
         },
         {
-          "name": "to",
-          "type": "pubkey"
+          "name": "additional",
+          "type": "i64"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "feeRegistry",
-      "discriminator": [
-        201,
-        150,
-        48,
-        215,
-        144,
-        177,
-        181,
-        213
-      ]
     },
     {
-      "name": "prefixAccount",
+      "name": "initProtocolConfig",
       "discriminator": [
-        57,
+        91,
+        97,
+        211,
         137,
-        99,
-        5,
-        57,
-        10,
-        236,
-        73
-      ]
+        96,
+        222,
+        139,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "verifiersList",
-      "discriminator": [
-        75,
-        207,
-        103,
-        108,
-        203,
-        15,
-        15,
-        249
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "feeUpdated",
+      "name": "initReviewConfig",
       "discriminator": [
-        228,
-        75,
-        43,
+        72,
+        136,
+        220,
+        110,
+        158,
         103,
-        9,
-        196,
-        182,
-        4
-      ]
-    },
-    {
-      "name": "prefixActivated",
-      "discriminator": [
-        117,
-        212,
-        125,
-        79,
-        252,
-        18,
-        79,
-        134
-      ]
+        254,
+        101
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "prefixApproved",
+      "name": "initialize",
       "discriminator": [
-        61,
+        175,
+        175,
+        109,
+        31,
+        13,
         152,
-        118,
-        94,
-        46,
-        118,
-        252,
-        190
-      ]
-    },
-    {
-      "name": "prefixAuthorityUpdated",
-      "discriminator": [
-        25,
-        94,
-        192,
-        163,
-        229,
-        29,
-        255,
-        39
-      ]
-    },
-    {
-      "name": "prefixDeactivated",
-      "discriminator": [
-        243,
-        73,
-        179,
-        248,
-        189,
-        255,
-        67,
-        57
-      ]
-    },
-    {
-      "name": "prefixMetadataUpdated",
-      "discriminator": [
-        187,
-        126,
-        200,
-        26,
-        142,
-        203,
-        246,
-        0
-      ]
-    },
-    {
-      "name": "prefixOwnerRecovered",
-      "discriminator": [
-        159,
-        47,
-        147,
-        172,
-        233,
-        220,
-        89,
-        135
-      ]
-    },
-    {
-      "name": "prefixReactivated",
-      "discriminator": [
-        67,
-        17,
-        46,
-        224,
-        226,
-        173,
-        49,
-        90
+        155,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer who funds the initial accounts (must be a signer)"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "docs": [
+            "Fee registry PDA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "docs": [
+            "Review policy PDA (verifier quorum)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Prefix rules PDA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeRegistry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "adminPubkey",
+          "type": "pubkey"
+        },
+        {
+          "name": "initialFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "listPrefix",
+      "discriminator": [
+        115,
+        103,
+        228,
+        172,
+        54,
+        148,
+        183,
+        76
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        }
       ]
     },
+    {
+      "name": "migratePrefixAccount",
+      "discriminator": [
+        35,
+        66,
+        170,
+        211,
+        33,
+        158,
+        91,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skeletonGuard",
+          "docs": [
+            "Left to its holder if a confusable prefix already claimed the skeleton"
+          ],
+          "writable": true
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "migrateVerifier",
+      "discriminator": [
+        199,
+        189,
+        94,
+        249,
+        38,
+        233,
+        148,
+        246
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifiers",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "verifier",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "openAuction",
+      "discriminator": [
+        48,
+        60,
+        204,
+        12,
+        175,
+        130,
+        173,
+        33
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reservedPrefix"
+        },
+        {
+          "name": "auction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skeletonGuard",
+          "docs": [
+            "Held for the auctioned name so no confusable (or the name itself) is registered meanwhile"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tombstone"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "reservePrice",
+          "type": "u64"
+        },
+        {
+          "name": "minIncrement",
+          "type": "u64"
+        },
+        {
+          "name": "duration",
+          "type": "i64"
+        },
+        {
+          "name": "activateOnSettle",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "pausePrefix",
+      "discriminator": [
+        54,
+        136,
+        104,
+        212,
+        209,
+        211,
+        248,
+        23
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "placeBid",
+      "discriminator": [
+        238,
+        77,
+        148,
+        91,
+        200,
+        151,
+        92,
+        146
+      ],
+      "accounts": [
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auction",
+          "docs": [
+            "Auction PDA; escrows the highest bid"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "previousBidder",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "reactivatePrefix",
+      "discriminator": [
+        7,
+        33,
+        55,
+        144,
+        195,
+        34,
+        242,
+        239
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "recoverPrefixOwnerWithFee",
+      "discriminator": [
+        161,
+        252,
+        183,
+        129,
+        28,
+        153,
+        208,
+        98
+      ],
+      "accounts": [
+        {
+          "name": "newOwner",
+          "docs": [
+            "New owner who will pay the recovery fee"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "docs": [
+            "Admin/multisig who authorizes the recovery"
+          ],
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeRegistry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "newOwner",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "refundCommitment",
+      "discriminator": [
+        134,
+        124,
+        13,
+        105,
+        86,
+        177,
+        150,
+        100
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "prefixCommitment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "prefixCommitment.commitment",
+                "account": "prefixCommitment"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundPrefixFee",
+      "discriminator": [
+        235,
+        130,
+        86,
+        154,
+        26,
+        65,
+        65,
+        249
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeRegistry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skeletonGuard",
+          "writable": true
+        },
+        {
+          "name": "auditLog",
+          "docs": [
+            "Kept open so the refund stays on record"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "rejectPrefix",
+      "discriminator": [
+        27,
+        106,
+        152,
+        79,
+        143,
+        142,
+        116,
+        46
+      ],
+      "accounts": [
+        {
+          "name": "verifier",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "reasonCode",
+          "type": {
+            "defined": {
+              "name": "rejectionReason"
+            }
+          }
+        },
+        {
+          "name": "evidenceHash",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "releaseReservedPrefix",
+      "discriminator": [
+        106,
+        150,
+        217,
+        56,
+        171,
+        164,
+        105,
+        73
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reservedPrefix",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "releaseReview",
+      "discriminator": [
+        138,
+        118,
+        154,
+        93,
+        97,
+        183,
+        59,
+        219
+      ],
+      "accounts": [
+        {
+          "name": "verifier",
+          "signer": true
+        },
+        {
+          "name": "verifierRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "removeVerifier",
+      "discriminator": [
+        179,
+        9,
+        132,
+        183,
+        233,
+        23,
+        172,
+        111
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "claimedPrefix",
+          "docs": [
+            "Prefix under the verifier's live review claim, if any"
+          ],
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "verifier",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "requestPrefixInfo",
+      "discriminator": [
+        87,
+        61,
+        156,
+        179,
+        39,
+        38,
+        54,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "verifier",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "requestHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "reservePrefix",
+      "discriminator": [
+        135,
+        107,
+        155,
+        131,
+        158,
+        47,
+        202,
+        221
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reservedPrefix",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "kind",
+          "type": {
+            "defined": {
+              "name": "reservationKind"
+            }
+          }
+        },
+        {
+          "name": "reason",
+          "type": "string"
+        },
+        {
+          "name": "claimant",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "resignVerifier",
+      "discriminator": [
+        238,
+        185,
+        33,
+        140,
+        200,
+        113,
+        4,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "verifier",
+          "signer": true
+        },
+        {
+          "name": "reviewConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verifier"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resolveAppeal",
+      "discriminator": [
+        225,
+        133,
+        69,
+        13,
+        240,
+        143,
+        68,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "resolver",
+          "docs": [
+            "Admin, or an active verifier that did not vote to reject"
+          ],
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierRecord",
+          "docs": [
+            "Required when the resolver is not the admin"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "resolver"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeRegistry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "appeal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  112,
+                  112,
+                  101,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "uphold",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "resubmitPrefix",
+      "discriminator": [
+        97,
+        87,
+        21,
+        46,
+        169,
+        109,
+        137,
+        113
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeRegistry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructionsSysvar",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "metadataUri",
+          "type": "string"
+        },
+        {
+          "name": "metadataHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "intentCategories",
+          "type": "u32"
+        },
+        {
+          "name": "reviewWindow",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "resumePrefix",
+      "discriminator": [
+        160,
+        188,
+        21,
+        31,
+        31,
+        215,
+        126,
+        92
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "revealPrefix",
+      "discriminator": [
+        61,
+        80,
+        87,
+        82,
+        247,
+        198,
+        130,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "submit",
+          "accounts": [
+            {
+              "name": "owner",
+              "docs": [
+                "Owner must be signer to pay for account creation"
+              ],
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "feeRegistry",
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      102,
+                      101,
+                      101,
+                      95,
+                      114,
+                      101,
+                      103,
+                      105,
+                      115,
+                      116,
+                      114,
+                      121
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "protocolConfig",
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      114,
+                      111,
+                      116,
+                      111,
+                      99,
+                      111,
+                      108,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "treasury",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      116,
+                      114,
+                      101,
+                      97,
+                      115,
+                      117,
+                      114,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "feeRegistry"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "prefixAccount",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      114,
+                      101,
+                      102,
+                      105,
+                      120
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "prefix"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "reviewState",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      118,
+                      105,
+                      101,
+                      119
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "prefix"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "skeletonGuard",
+              "docs": [
+                "Fails to initialize if a confusable prefix already holds the same skeleton"
+              ],
+              "writable": true
+            },
+            {
+              "name": "auditLog",
+              "docs": [
+                "Kept from an earlier registration of the name if it was revoked"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      97,
+                      117,
+                      100,
+                      105,
+                      116,
+                      95,
+                      108,
+                      111,
+                      103
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "prefix"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "reservedPrefix",
+              "docs": [
+                "variants); may be uninitialized"
+              ]
+            },
+            {
+              "name": "rootSkeletonGuard",
+              "docs": [
+                "names and must be uninitialized, so neither the root nor a confusable of it is registered"
+              ],
+              "optional": true
+            },
+            {
+              "name": "rootReservedPrefix",
+              "docs": [
+                "names; may be uninitialized"
+              ],
+              "optional": true
+            },
+            {
+              "name": "rootTombstone",
+              "docs": [
+                "may be uninitialized"
+              ],
+              "optional": true
+            },
+            {
+              "name": "tombstone"
+            },
+            {
+              "name": "instructionsSysvar",
+              "address": "Sysvar1nstructions1111111111111111111111111"
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            }
+          ]
+        },
+        {
+          "name": "prefixCommitment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "submit.owner",
+                "account": "submitPrefixWithFee"
+              },
+              {
+                "kind": "account",
+                "path": "prefixCommitment.commitment",
+                "account": "prefixCommitment"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "metadataUri",
+          "type": "string"
+        },
+        {
+          "name": "metadataHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "authorityKeys",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "intentCategories",
+          "type": "u32"
+        },
+        {
+          "name": "reviewWindow",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "reverifyPrefix",
+      "discriminator": [
+        225,
+        75,
+        16,
+        250,
+        213,
+        137,
+        116,
+        117
+      ],
+      "accounts": [
+        {
+          "name": "verifier",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "revokePrefix",
+      "discriminator": [
+        47,
+        186,
+        248,
+        108,
+        212,
+        177,
+        95,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "writable": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skeletonGuard",
+          "writable": true
+        },
+        {
+          "name": "auditLog",
+          "docs": [
+            "Kept open so the revocation stays on record"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tombstone",
+          "docs": [
+            "Keyed by skeleton so confusable variants are blocked too; re-revoking a name whose",
+            "earlier cooldown lapsed overwrites the old tombstone"
+          ],
+          "writable": true
+        },
+        {
+          "name": "parentAccount",
+          "docs": [
+            "Required for a sub-prefix; its child count is decremented"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "claimHolder",
+          "docs": [
+            "Required while a verifier holds a live review claim on the prefix; the claim is released"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "claimHolder.verifier",
+                "account": "verifierRecord"
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "docs": [
+            "Required while the prefix is listed; closed to its seller"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "reason",
+          "type": "string"
+        },
+        {
+          "name": "cooldown",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setAppealBond",
+      "discriminator": [
+        64,
+        204,
+        37,
+        237,
+        77,
+        82,
+        181,
+        227
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "appealBond",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setClaimWindow",
+      "discriminator": [
+        190,
+        124,
+        26,
+        181,
+        134,
+        231,
+        61,
+        190
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "claimWindow",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setCommitPolicy",
+      "discriminator": [
+        244,
+        1,
+        199,
+        51,
+        13,
+        241,
+        170,
+        1
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "commitRequired",
+          "type": "bool"
+        },
+        {
+          "name": "commitDeposit",
+          "type": "u64"
+        },
+        {
+          "name": "minRevealDelay",
+          "type": "i64"
+        },
+        {
+          "name": "commitmentTtl",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setExpiryExtensionPolicy",
+      "discriminator": [
+        202,
+        76,
+        72,
+        47,
+        238,
+        161,
+        239,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "needsInfoExtension",
+          "type": "i64"
+        },
+        {
+          "name": "maxExpiryExtension",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setPause",
+      "discriminator": [
+        63,
+        32,
+        154,
+        2,
+        56,
+        103,
+        79,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setReviewQuorum",
+      "discriminator": [
+        90,
+        31,
+        27,
+        9,
+        171,
+        36,
+        94,
+        98
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "defaultQuorum",
+          "type": "u8"
+        },
+        {
+          "name": "quorumTiers",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "quorumTier"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRoyalty",
+      "discriminator": [
+        72,
+        35,
+        170,
+        162,
+        47,
+        13,
+        86,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "royaltyBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setSweepPolicy",
+      "discriminator": [
+        231,
+        182,
+        130,
+        117,
+        21,
+        147,
+        36,
+        147
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "sweepReward",
+          "type": "u64"
+        },
+        {
+          "name": "rejectedGracePeriod",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setVerificationValidity",
+      "discriminator": [
+        194,
+        169,
+        25,
+        254,
+        174,
+        228,
+        81,
+        171
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "verificationValidity",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setVerifierBondPolicy",
+      "discriminator": [
+        182,
+        75,
+        242,
+        37,
+        72,
+        99,
+        106,
+        54
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "minVerifierBond",
+          "type": "u64"
+        },
+        {
+          "name": "unbondingPeriod",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setVerifierScope",
+      "discriminator": [
+        2,
+        90,
+        160,
+        203,
+        249,
+        254,
+        11,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "verifier"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "verifier",
+          "type": "pubkey"
+        },
+        {
+          "name": "scope",
+          "type": {
+            "defined": {
+              "name": "verifierScope"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "settleAuction",
+      "discriminator": [
+        246,
+        196,
+        183,
+        98,
+        222,
+        139,
+        46,
+        133
+      ],
+      "accounts": [
+        {
+          "name": "winner",
+          "docs": [
+            "Winning bidder; pays rent for the new prefix accounts"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "writable": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeRegistry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skeletonGuard",
+          "docs": [
+            "Taken when the auction opened; now belongs to the winner's prefix"
+          ]
+        },
+        {
+          "name": "auditLog",
+          "docs": [
+            "Kept from an earlier registration of the name if it was revoked"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tombstone"
+        },
+        {
+          "name": "instructionsSysvar",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "metadataUri",
+          "type": "string"
+        },
+        {
+          "name": "metadataHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "authorityKeys",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "intentCategories",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "slashVerifier",
+      "discriminator": [
+        133,
+        226,
+        237,
+        187,
+        178,
+        10,
+        116,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeRegistry"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "verifier",
+          "type": "pubkey"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "submitPrefixWithFee",
+      "discriminator": [
+        36,
+        227,
+        120,
+        228,
+        189,
+        42,
+        195,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "Owner must be signer to pay for account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeRegistry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skeletonGuard",
+          "docs": [
+            "Fails to initialize if a confusable prefix already holds the same skeleton"
+          ],
+          "writable": true
+        },
+        {
+          "name": "auditLog",
+          "docs": [
+            "Kept from an earlier registration of the name if it was revoked"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reservedPrefix",
+          "docs": [
+            "variants); may be uninitialized"
+          ]
+        },
+        {
+          "name": "rootSkeletonGuard",
+          "docs": [
+            "names and must be uninitialized, so neither the root nor a confusable of it is registered"
+          ],
+          "optional": true
+        },
+        {
+          "name": "rootReservedPrefix",
+          "docs": [
+            "names; may be uninitialized"
+          ],
+          "optional": true
+        },
+        {
+          "name": "rootTombstone",
+          "docs": [
+            "may be uninitialized"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tombstone"
+        },
+        {
+          "name": "instructionsSysvar",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "metadataUri",
+          "type": "string"
+        },
+        {
+          "name": "metadataHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "authorityKeys",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "intentCategories",
+          "type": "u32"
+        },
+        {
+          "name": "reviewWindow",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "suspendPrefix",
+      "discriminator": [
+        106,
+        188,
+        7,
+        161,
+        32,
+        63,
+        38,
+        206
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "duration",
+          "type": "i64"
+        },
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "suspensionReason"
+            }
+          }
+        },
+        {
+          "name": "evidenceHash",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "sweepExpiredPrefix",
+      "discriminator": [
+        192,
+        115,
+        211,
+        103,
+        116,
+        107,
+        144,
+        147
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "writable": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeRegistry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skeletonGuard",
+          "writable": true
+        },
+        {
+          "name": "auditLog",
+          "docs": [
+            "Kept open so the sweep stays on record"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentAccount",
+          "docs": [
+            "Required for a sub-prefix; its child count is decremented"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "unfreezePrefix",
+      "discriminator": [
+        116,
+        31,
+        145,
+        208,
+        228,
+        40,
+        109,
+        63
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "updateFee",
+      "discriminator": [
+        232,
+        253,
+        195,
+        247,
+        148,
+        212,
+        73,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updatePrefixAuthority",
+      "discriminator": [
+        81,
+        34,
+        201,
+        205,
+        160,
+        31,
+        146,
+        85
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "authorityKeys",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePrefixMetadata",
+      "discriminator": [
+        208,
+        18,
+        7,
+        217,
+        10,
+        21,
+        141,
+        252
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "prefixAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  102,
+                  105,
+                  120
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  100,
+                  105,
+                  116,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructionsSysvar",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "newMetadataUri",
+          "type": "string"
+        },
+        {
+          "name": "newMetadataHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "intentCategories",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "updateProtocolConfig",
+      "discriminator": [
+        197,
+        97,
+        123,
+        54,
+        221,
+        168,
+        11,
+        135
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "minPrefixLen",
+          "type": "u8"
+        },
+        {
+          "name": "maxPrefixLen",
+          "type": "u8"
+        },
+        {
+          "name": "allowHyphen",
+          "type": "bool"
+        },
+        {
+          "name": "allowUnderscore",
+          "type": "bool"
+        },
+        {
+          "name": "maxExpiryDuration",
+          "type": "i64"
+        },
+        {
+          "name": "minReviewWindow",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawTreasury",
+      "discriminator": [
+        40,
+        63,
+        122,
+        158,
+        144,
+        216,
+        83,
+        96
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeRegistry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "to",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "to",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "withdrawVerifierBond",
+      "discriminator": [
+        45,
+        206,
+        203,
+        156,
+        80,
+        86,
+        29,
+        185
+      ],
+      "accounts": [
+        {
+          "name": "verifier",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "reviewConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  105,
+                  102,
+                  105,
+                  101,
+                  114,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verifier"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "appeal",
+      "discriminator": [
+        155,
+        196,
+        80,
+        143,
+        64,
+        220,
+        198,
+        177
+      ]
+    },
+    {
+      "name": "auction",
+      "discriminator": [
+        218,
+        94,
+        247,
+        242,
+        126,
+        233,
+        131,
+        81
+      ]
+    },
+    {
+      "name": "auditLog",
+      "discriminator": [
+        230,
+        207,
+        176,
+        233,
+        170,
+        130,
+        101,
+        244
+      ]
+    },
+    {
+      "name": "feeRegistry",
+      "discriminator": [
+        201,
+        150,
+        48,
+        215,
+        144,
+        177,
+        181,
+        213
+      ]
+    },
+    {
+      "name": "listing",
+      "discriminator": [
+        218,
+        32,
+        50,
+        73,
+        43,
+        134,
+        26,
+        58
+      ]
+    },
+    {
+      "name": "prefixAccount",
+      "discriminator": [
+        57,
+        137,
+        99,
+        5,
+        57,
+        10,
+        236,
+        73
+      ]
+    },
+    {
+      "name": "prefixCommitment",
+      "discriminator": [
+        180,
+        24,
+        60,
+        54,
+        98,
+        87,
+        75,
+        178
+      ]
+    },
+    {
+      "name": "protocolConfig",
+      "discriminator": [
+        207,
+        91,
+        250,
+        28,
+        152,
+        179,
+        215,
+        209
+      ]
+    },
+    {
+      "name": "reservedPrefix",
+      "discriminator": [
+        176,
+        234,
+        191,
+        65,
+        198,
+        177,
+        39,
+        75
+      ]
+    },
+    {
+      "name": "reviewConfig",
+      "discriminator": [
+        255,
+        168,
+        36,
+        168,
+        235,
+        238,
+        101,
+        88
+      ]
+    },
+    {
+      "name": "reviewState",
+      "discriminator": [
+        49,
+        232,
+        106,
+        146,
+        218,
+        116,
+        25,
+        231
+      ]
+    },
+    {
+      "name": "skeletonGuard",
+      "discriminator": [
+        138,
+        157,
+        168,
+        234,
+        49,
+        49,
+        174,
+        25
+      ]
+    },
+    {
+      "name": "tombstone",
+      "discriminator": [
+        45,
+        187,
+        252,
+        155,
+        232,
+        114,
+        36,
+        22
+      ]
+    },
+    {
+      "name": "verifierRecord",
+      "discriminator": [
+        122,
+        137,
+        61,
+        52,
+        254,
+        80,
+        137,
+        229
+      ]
+    },
+    {
+      "name": "verifiersList",
+      "discriminator": [
+        75,
+        207,
+        103,
+        108,
+        203,
+        15,
+        15,
+        249
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "appealBondUpdated",
+      "discriminator": [
+        109,
+        143,
+        12,
+        235,
+        108,
+        38,
+        187,
+        167
+      ]
+    },
+    {
+      "name": "appealFiled",
+      "discriminator": [
+        87,
+        171,
+        32,
+        112,
+        254,
+        40,
+        44,
+        130
+      ]
+    },
+    {
+      "name": "appealResolved",
+      "discriminator": [
+        130,
+        12,
+        137,
+        191,
+        189,
+        217,
+        26,
+        189
+      ]
+    },
+    {
+      "name": "auctionBidPlaced",
+      "discriminator": [
+        73,
+        16,
+        248,
+        198,
+        37,
+        53,
+        9,
+        90
+      ]
+    },
+    {
+      "name": "auctionCancelled",
+      "discriminator": [
+        22,
+        32,
+        51,
+        83,
+        215,
+        194,
+        171,
+        209
+      ]
+    },
+    {
+      "name": "auctionOpened",
+      "discriminator": [
+        25,
+        230,
+        140,
+        215,
+        100,
+        193,
+        14,
+        70
+      ]
+    },
+    {
+      "name": "auctionSettled",
+      "discriminator": [
+        61,
+        151,
+        131,
+        170,
+        95,
+        203,
+        219,
+        147
+      ]
+    },
+    {
+      "name": "claimWindowUpdated",
+      "discriminator": [
+        115,
+        153,
+        13,
+        169,
+        85,
+        240,
+        105,
+        127
+      ]
+    },
+    {
+      "name": "commitPolicyUpdated",
+      "discriminator": [
+        161,
+        170,
+        155,
+        2,
+        100,
+        58,
+        160,
+        150
+      ]
+    },
+    {
+      "name": "commitmentRefunded",
+      "discriminator": [
+        64,
+        158,
+        189,
+        37,
+        80,
+        219,
+        169,
+        48
+      ]
+    },
+    {
+      "name": "configInitialized",
+      "discriminator": [
+        181,
+        49,
+        200,
+        156,
+        19,
+        167,
+        178,
+        91
+      ]
+    },
+    {
+      "name": "expiryExtensionPolicyUpdated",
+      "discriminator": [
+        197,
+        140,
+        69,
+        74,
+        241,
+        150,
+        223,
+        199
+      ]
+    },
+    {
+      "name": "feeUpdated",
+      "discriminator": [
+        228,
+        75,
+        43,
+        103,
+        9,
+        196,
+        182,
+        4
+      ]
+    },
+    {
+      "name": "prefixAccountMigrated",
+      "discriminator": [
+        167,
+        205,
+        218,
+        127,
+        217,
+        66,
+        104,
+        50
+      ]
+    },
+    {
+      "name": "prefixActivated",
+      "discriminator": [
+        117,
+        212,
+        125,
+        79,
+        252,
+        18,
+        79,
+        134
+      ]
+    },
+    {
+      "name": "prefixApproved",
+      "discriminator": [
+        61,
+        152,
+        118,
+        94,
+        46,
+        118,
+        252,
+        190
+      ]
+    },
+    {
+      "name": "prefixAuthorityUpdated",
+      "discriminator": [
+        25,
+        94,
+        192,
+        163,
+        229,
+        29,
+        255,
+        39
+      ]
+    },
+    {
+      "name": "prefixCommitted",
+      "discriminator": [
+        97,
+        119,
+        63,
+        2,
+        79,
+        49,
+        231,
+        65
+      ]
+    },
+    {
+      "name": "prefixDeactivated",
+      "discriminator": [
+        243,
+        73,
+        179,
+        248,
+        189,
+        255,
+        67,
+        57
+      ]
+    },
+    {
+      "name": "prefixDelisted",
+      "discriminator": [
+        142,
+        155,
+        19,
+        167,
+        244,
+        52,
+        45,
+        35
+      ]
+    },
+    {
+      "name": "prefixFrozen",
+      "discriminator": [
+        34,
+        191,
+        180,
+        46,
+        32,
+        225,
+        171,
+        249
+      ]
+    },
+    {
+      "name": "prefixInfoProvided",
+      "discriminator": [
+        211,
+        159,
+        139,
+        220,
+        42,
+        40,
+        0,
+        85
+      ]
+    },
+    {
+      "name": "prefixInfoRequested",
+      "discriminator": [
+        139,
+        46,
+        135,
+        48,
+        148,
+        139,
+        71,
+        244
+      ]
+    },
+    {
+      "name": "prefixListed",
+      "discriminator": [
+        171,
+        78,
+        117,
+        61,
+        74,
+        176,
+        140,
+        8
+      ]
+    },
+    {
+      "name": "prefixMetadataUpdated",
+      "discriminator": [
+        187,
+        126,
+        200,
+        26,
+        142,
+        203,
+        246,
+        0
+      ]
+    },
+    {
+      "name": "prefixOwnerRecovered",
+      "discriminator": [
+        159,
+        47,
+        147,
+        172,
+        233,
+        220,
+        89,
+        135
+      ]
+    },
+    {
+      "name": "prefixPausedByOwner",
+      "discriminator": [
+        136,
+        44,
+        107,
+        124,
+        230,
+        18,
+        123,
+        87
+      ]
+    },
+    {
+      "name": "prefixReactivated",
+      "discriminator": [
+        67,
+        17,
+        46,
+        224,
+        226,
+        173,
+        49,
+        90
+      ]
+    },
+    {
+      "name": "prefixRefunded",
+      "discriminator": [
+        185,
+        77,
+        232,
+        59,
+        97,
+        175,
+        246,
+        251
+      ]
+    },
+    {
+      "name": "prefixRejected",
+      "discriminator": [
+        152,
+        238,
+        98,
+        180,
+        223,
+        147,
+        27,
+        171
+      ]
+    },
+    {
+      "name": "prefixReservationAdded",
+      "discriminator": [
+        72,
+        193,
+        137,
+        172,
+        108,
+        165,
+        0,
+        248
+      ]
+    },
+    {
+      "name": "prefixReservationRemoved",
+      "discriminator": [
+        35,
+        61,
+        37,
+        56,
+        136,
+        174,
+        188,
+        75
+      ]
+    },
+    {
+      "name": "prefixResubmitted",
+      "discriminator": [
+        118,
+        205,
+        134,
+        206,
+        148,
+        94,
+        224,
+        221
+      ]
+    },
+    {
+      "name": "prefixResumedByOwner",
+      "discriminator": [
+        188,
+        243,
+        11,
+        28,
+        88,
+        104,
+        229,
+        41
+      ]
+    },
+    {
+      "name": "prefixRevealed",
+      "discriminator": [
+        18,
+        219,
+        134,
+        27,
+        35,
+        72,
+        31,
+        44
+      ]
+    },
+    {
+      "name": "prefixReverified",
+      "discriminator": [
+        143,
+        251,
+        188,
+        227,
+        112,
+        140,
+        173,
+        14
+      ]
+    },
+    {
+      "name": "prefixRevoked",
+      "discriminator": [
+        77,
+        32,
+        96,
+        194,
+        53,
+        137,
+        19,
+        216
+      ]
+    },
+    {
+      "name": "prefixSold",
+      "discriminator": [
+        239,
+        97,
+        5,
+        252,
+        168,
+        131,
+        54,
+        87
+      ]
+    },
+    {
+      "name": "prefixSubmitted",
+      "discriminator": [
+        212,
+        152,
+        202,
+        170,
+        29,
+        251,
+        149,
+        39
+      ]
+    },
+    {
+      "name": "prefixSuspended",
+      "discriminator": [
+        56,
+        104,
+        246,
+        37,
+        171,
+        23,
+        227,
+        129
+      ]
+    },
+    {
+      "name": "prefixSuspensionEnded",
+      "discriminator": [
+        145,
+        184,
+        136,
+        140,
+        75,
+        247,
+        246,
+        158
+      ]
+    },
+    {
+      "name": "prefixSuspensionExtended",
+      "discriminator": [
+        227,
+        27,
+        144,
+        23,
+        23,
+        51,
+        80,
+        91
+      ]
+    },
+    {
+      "name": "prefixSwept",
+      "discriminator": [
+        127,
+        30,
+        94,
+        38,
+        216,
+        48,
+        55,
+        249
+      ]
+    },
+    {
+      "name": "prefixUnfrozen",
+      "discriminator": [
+        29,
+        240,
+        108,
+        148,
+        157,
+        89,
+        175,
+        75
+      ]
+    },
+    {
+      "name": "protocolConfigUpdated",
+      "discriminator": [
+        20,
+        99,
+        32,
+        237,
+        111,
+        86,
+        195,
+        199
+      ]
+    },
+    {
+      "name": "reviewClaimed",
+      "discriminator": [
+        44,
+        85,
+        35,
+        249,
+        196,
+        143,
+        65,
+        45
+      ]
+    },
+    {
+      "name": "reviewQuorumUpdated",
+      "discriminator": [
+        40,
+        250,
+        121,
+        109,
+        200,
+        132,
+        31,
+        110
+      ]
+    },
+    {
+      "name": "reviewReleased",
+      "discriminator": [
+        19,
+        205,
+        161,
+        173,
+        248,
+        240,
+        61,
+        62
+      ]
+    },
+    {
+      "name": "reviewVoteCast",
+      "discriminator": [
+        122,
+        233,
+        219,
+        82,
+        248,
+        138,
+        100,
+        24
+      ]
+    },
+    {
+      "name": "reviewWindowExtended",
+      "discriminator": [
+        136,
+        142,
+        168,
+        239,
+        70,
+        188,
+        191,
+        89
+      ]
+    },
+    {
+      "name": "royaltyUpdated",
+      "discriminator": [
+        81,
+        246,
+        203,
+        173,
+        134,
+        137,
+        21,
+        64
+      ]
+    },
+    {
+      "name": "subPrefixCreated",
+      "discriminator": [
+        136,
+        68,
+        48,
+        85,
+        138,
+        171,
+        63,
+        178
+      ]
+    },
+    {
+      "name": "sweepPolicyUpdated",
+      "discriminator": [
+        59,
+        0,
+        58,
+        137,
+        216,
+        122,
+        179,
+        51
+      ]
+    },
+    {
+      "name": "treasuryWithdraw",
+      "discriminator": [
+        164,
+        41,
+        149,
+        134,
+        248,
+        87,
+        41,
+        218
+      ]
+    },
+    {
+      "name": "verificationValidityUpdated",
+      "discriminator": [
+        126,
+        82,
+        162,
+        111,
+        206,
+        123,
+        91,
+        15
+      ]
+    },
+    {
+      "name": "verifierAdded",
+      "discriminator": [
+        113,
+        131,
+        132,
+        161,
+        53,
+        64,
+        96,
+        78
+      ]
+    },
+    {
+      "name": "verifierBondDeposited",
+      "discriminator": [
+        87,
+        119,
+        92,
+        95,
+        34,
+        162,
+        76,
+        108
+      ]
+    },
+    {
+      "name": "verifierBondPolicyUpdated",
+      "discriminator": [
+        187,
+        234,
+        202,
+        91,
+        14,
+        226,
+        126,
+        173
+      ]
+    },
+    {
+      "name": "verifierBondWithdrawn",
+      "discriminator": [
+        230,
+        231,
+        201,
+        28,
+        164,
+        77,
+        225,
+        225
+      ]
+    },
+    {
+      "name": "verifierMigrated",
+      "discriminator": [
+        91,
+        114,
+        189,
+        200,
+        184,
+        142,
+        222,
+        52
+      ]
+    },
+    {
+      "name": "verifierRemoved",
+      "discriminator": [
+        87,
+        0,
+        8,
+        47,
+        151,
+        131,
+        51,
+        99
+      ]
+    },
+    {
+      "name": "verifierResigned",
+      "discriminator": [
+        151,
+        145,
+        95,
+        39,
+        108,
+        249,
+        180,
+        251
+      ]
+    },
+    {
+      "name": "verifierScopeUpdated",
+      "discriminator": [
+        204,
+        24,
+        198,
+        132,
+        231,
+        33,
+        74,
+        58
+      ]
+    },
+    {
+      "name": "verifierSlashed",
+      "discriminator": [
+        41,
+        11,
+        233,
+        16,
+        87,
+        34,
+        95,
+        136
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "unauthorizedAdmin",
+      "msg": "Unauthorized admin"
+    },
+    {
+      "code": 6001,
+      "name": "unauthorizedVerifier",
+      "msg": "Unauthorized verifier"
+    },
+    {
+      "code": 6002,
+      "name": "invalidPrefixFormat",
+      "msg": "Invalid prefix format"
+    },
+    {
+      "code": 6003,
+      "name": "prefixAlreadyExists",
+      "msg": "Prefix already exists"
+    },
+    {
+      "code": 6004,
+      "name": "invalidPrefixStatus",
+      "msg": "Prefix not in pending state"
+    },
+    {
+      "code": 6005,
+      "name": "insufficientFee",
+      "msg": "Insufficient fee"
+    },
+    {
+      "code": 6006,
+      "name": "invalidMetadataHashLength",
+      "msg": "Invalid metadata hash"
+    },
+    {
+      "code": 6007,
+      "name": "invalidMetadataUri",
+      "msg": "Invalid metadata uri"
+    },
+    {
+      "code": 6008,
+      "name": "invalidTreasuryAccount",
+      "msg": "Treasury not owned by program"
+    },
+    {
+      "code": 6009,
+      "name": "insufficientTreasuryBalance",
+      "msg": "Insufficient treasury balance"
+    },
+    {
+      "code": 6010,
+      "name": "refundNotAllowed",
+      "msg": "Refund not allowed in current state"
+    },
+    {
+      "code": 6011,
+      "name": "unauthorizedOwnerAction",
+      "msg": "Only owner may perform this action"
+    },
+    {
+      "code": 6012,
+      "name": "missingBump",
+      "msg": "Account bump missing"
+    },
+    {
+      "code": 6013,
+      "name": "feeOperationsPaused",
+      "msg": "Fee operations paused"
+    },
+    {
+      "code": 6014,
+      "name": "prefixExpired",
+      "msg": "Prefix expired"
+    },
+    {
+      "code": 6015,
+      "name": "authorityKeysTooMany",
+      "msg": "Invalid authority keys length"
+    },
+    {
+      "code": 6016,
+      "name": "invalidEd25519Signature",
+      "msg": "Invalid Ed25519 signature"
+    },
+    {
+      "code": 6017,
+      "name": "invalidQuorum",
+      "msg": "Invalid quorum configuration"
+    },
+    {
+      "code": 6018,
+      "name": "alreadyVoted",
+      "msg": "Verifier already voted on this prefix"
+    },
+    {
+      "code": 6019,
+      "name": "prefixAlreadyMigrated",
+      "msg": "Prefix account is not in the legacy layout"
+    },
+    {
+      "code": 6020,
+      "name": "verifierAlreadyActive",
+      "msg": "Verifier already active"
+    },
+    {
+      "code": 6021,
+      "name": "invalidVerifierLabel",
+      "msg": "Invalid verifier label"
+    },
+    {
+      "code": 6022,
+      "name": "voterRecordRequired",
+      "msg": "Verifier record of an earlier voter required"
+    },
+    {
+      "code": 6023,
+      "name": "reviewAlreadyClaimed",
+      "msg": "Prefix review claimed by another verifier"
+    },
+    {
+      "code": 6024,
+      "name": "verifierClaimActive",
+      "msg": "Verifier already holds a review claim"
+    },
+    {
+      "code": 6025,
+      "name": "reviewNotClaimed",
+      "msg": "No review claim held by this verifier"
+    },
+    {
+      "code": 6026,
+      "name": "invalidClaimWindow",
+      "msg": "Invalid claim window"
+    },
+    {
+      "code": 6027,
+      "name": "claimedPrefixRequired",
+      "msg": "Claimed prefix account required"
+    },
+    {
+      "code": 6028,
+      "name": "rejectionNoteTooLong",
+      "msg": "Rejection note too long"
+    },
+    {
+      "code": 6029,
+      "name": "conflictedAppealResolver",
+      "msg": "Verifier took part in the rejection under appeal"
+    },
+    {
+      "code": 6030,
+      "name": "insufficientVerifierBond",
+      "msg": "Verifier bond below required minimum"
+    },
+    {
+      "code": 6031,
+      "name": "invalidSlashAmount",
+      "msg": "Slash amount exceeds verifier bond"
+    },
+    {
+      "code": 6032,
+      "name": "slashReasonTooLong",
+      "msg": "Slash reason too long"
+    },
+    {
+      "code": 6033,
+      "name": "bondStillLocked",
+      "msg": "Verifier bond still unbonding"
+    },
+    {
+      "code": 6034,
+      "name": "invalidBondPolicy",
+      "msg": "Invalid bond policy"
+    },
+    {
+      "code": 6035,
+      "name": "invalidExtensionPolicy",
+      "msg": "Invalid expiry extension policy"
+    },
+    {
+      "code": 6036,
+      "name": "extensionBudgetExhausted",
+      "msg": "Review extension budget exhausted"
+    },
+    {
+      "code": 6037,
+      "name": "invalidVerificationValidity",
+      "msg": "Invalid verification validity"
+    },
+    {
+      "code": 6038,
+      "name": "invalidIntentCategories",
+      "msg": "Invalid intent categories"
+    },
+    {
+      "code": 6039,
+      "name": "invalidVerifierScope",
+      "msg": "Invalid verifier scope"
+    },
+    {
+      "code": 6040,
+      "name": "outOfVerifierScope",
+      "msg": "Prefix outside verifier scope"
+    },
+    {
+      "code": 6041,
+      "name": "prefixBlocked",
+      "msg": "Prefix is blocked"
+    },
+    {
+      "code": 6042,
+      "name": "prefixReserved",
+      "msg": "Prefix is reserved for another claimant"
+    },
+    {
+      "code": 6043,
+      "name": "reservationReasonTooLong",
+      "msg": "Reservation reason too long"
+    },
+    {
+      "code": 6044,
+      "name": "invalidSubPrefixLabel",
+      "msg": "Invalid sub-prefix label"
+    },
+    {
+      "code": 6045,
+      "name": "subPrefixDepthExceeded",
+      "msg": "Sub-prefix depth limit reached"
+    },
+    {
+      "code": 6046,
+      "name": "subPrefixLimitReached",
+      "msg": "Sub-prefix count limit reached"
+    },
+    {
+      "code": 6047,
+      "name": "parentPrefixNotTrusted",
+      "msg": "Parent prefix not trusted"
+    },
+    {
+      "code": 6048,
+      "name": "missingAncestorAccount",
+      "msg": "Ancestor prefix account missing"
+    },
+    {
+      "code": 6049,
+      "name": "invalidProtocolConfig",
+      "msg": "Invalid protocol config"
+    },
+    {
+      "code": 6050,
+      "name": "prefixRootRegistered",
+      "msg": "Part before the hyphen is, or is confusable with, a registered prefix; create a sub-prefix instead"
+    },
+    {
+      "code": 6051,
+      "name": "sweepNotAllowed",
+      "msg": "Prefix cannot be swept yet"
+    },
+    {
+      "code": 6052,
+      "name": "reviewWindowTooShort",
+      "msg": "Review window too short"
+    },
+    {
+      "code": 6053,
+      "name": "reviewWindowTooLong",
+      "msg": "Review window too long"
+    },
+    {
+      "code": 6054,
+      "name": "invalidSuspensionDuration",
+      "msg": "Invalid suspension duration"
+    },
+    {
+      "code": 6055,
+      "name": "prefixAlreadySuspended",
+      "msg": "Prefix already suspended"
+    },
+    {
+      "code": 6056,
+      "name": "prefixNotSuspended",
+      "msg": "Prefix not suspended"
+    },
+    {
+      "code": 6057,
+      "name": "freezingKeyStillAuthorized",
+      "msg": "Freezing key is still an authority key"
+    },
+    {
+      "code": 6058,
+      "name": "unauthorizedAuthorityKey",
+      "msg": "Signer is not an authority key of this prefix"
+    },
+    {
+      "code": 6059,
+      "name": "invalidListingPrice",
+      "msg": "Invalid listing price"
+    },
+    {
+      "code": 6060,
+      "name": "staleListing",
+      "msg": "Listing no longer matches the prefix owner"
+    },
+    {
+      "code": 6061,
+      "name": "buyerIsSeller",
+      "msg": "Buyer already owns this prefix"
+    },
+    {
+      "code": 6062,
+      "name": "invalidRoyalty",
+      "msg": "Invalid royalty"
+    },
+    {
+      "code": 6063,
+      "name": "prefixNotTrusted",
+      "msg": "Prefix not trusted: inactive, suspended or verification lapsed"
+    },
+    {
+      "code": 6064,
+      "name": "prefixListed",
+      "msg": "Prefix is listed for sale; delist it first"
+    },
+    {
+      "code": 6065,
+      "name": "prefixHasSubPrefixes",
+      "msg": "Prefix has sub-prefixes"
+    },
+    {
+      "code": 6066,
+      "name": "invalidAuctionParams",
+      "msg": "Invalid auction parameters"
+    },
+    {
+      "code": 6067,
+      "name": "prefixNotReservedForAuction",
+      "msg": "Only reserved prefixes can be auctioned"
+    },
+    {
+      "code": 6068,
+      "name": "auctionEnded",
+      "msg": "Auction has ended"
+    },
+    {
+      "code": 6069,
+      "name": "auctionNotEnded",
+      "msg": "Auction has not ended"
+    },
+    {
+      "code": 6070,
+      "name": "bidTooLow",
+      "msg": "Bid too low"
+    },
+    {
+      "code": 6071,
+      "name": "invalidPreviousBidder",
+      "msg": "Outbid bidder account mismatch"
+    },
+    {
+      "code": 6072,
+      "name": "auctionHasBids",
+      "msg": "Auction has bids"
+    },
+    {
+      "code": 6073,
+      "name": "auctionNoBids",
+      "msg": "Auction has no winning bid"
+    },
+    {
+      "code": 6074,
+      "name": "highestBidderMismatch",
+      "msg": "Highest bidder account mismatch"
+    },
+    {
+      "code": 6075,
+      "name": "commitRevealRequired",
+      "msg": "Direct submission disabled; use commit and reveal"
+    },
+    {
+      "code": 6076,
+      "name": "commitmentMismatch",
+      "msg": "Revealed prefix does not match the commitment"
+    },
+    {
+      "code": 6077,
+      "name": "revealTooEarly",
+      "msg": "Commitment cannot be revealed yet"
+    },
+    {
+      "code": 6078,
+      "name": "commitmentExpired",
+      "msg": "Commitment expired"
+    },
+    {
+      "code": 6079,
+      "name": "commitmentNotExpired",
+      "msg": "Commitment has not expired"
+    },
+    {
+      "code": 6080,
+      "name": "invalidCommitPolicy",
+      "msg": "Invalid commit policy"
+    },
+    {
+      "code": 6081,
+      "name": "prefixRevoked",
+      "msg": "Prefix was revoked and cannot be registered yet"
+    },
+    {
+      "code": 6082,
+      "name": "revocationReasonTooLong",
+      "msg": "Revocation reason too long"
+    },
+    {
+      "code": 6083,
+      "name": "invalidRevocationCooldown",
+      "msg": "Invalid revocation cooldown"
+    },
+    {
+      "code": 6084,
+      "name": "claimHolderRequired",
+      "msg": "Verifier record of the review claim holder required"
+    }
+  ],
+  "types": [
+    {
+      "name": "appeal",
+      "docs": [
+        "Open appeal against a rejection; holds the owner's bond until resolved"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "bond",
+            "type": "u64"
+          },
+          {
+            "name": "evidenceHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "appealBondUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldBond",
+            "type": "u64"
+          },
+          {
+            "name": "newBond",
+            "type": "u64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "appealFiled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "bond",
+            "type": "u64"
+          },
+          {
+            "name": "evidenceHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "filedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "appealResolved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "resolver",
+            "type": "pubkey"
+          },
+          {
+            "name": "upheld",
+            "type": "bool"
+          },
+          {
+            "name": "bond",
+            "type": "u64"
+          },
+          {
+            "name": "resolvedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auction",
+      "docs": [
+        "English auction for a reserved prefix; escrows the highest bid until settlement"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "reservePrice",
+            "type": "u64"
+          },
+          {
+            "name": "minIncrement",
+            "type": "u64"
+          },
+          {
+            "name": "highestBidder",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "highestBid",
+            "type": "u64"
+          },
+          {
+            "name": "startAt",
+            "type": "i64"
+          },
+          {
+            "name": "endAt",
+            "type": "i64"
+          },
+          {
+            "name": "activateOnSettle",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionBidPlaced",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "outbid",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "endAt",
+            "type": "i64"
+          },
+          {
+            "name": "placedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "refundedBidder",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "refundedBid",
+            "type": "u64"
+          },
+          {
+            "name": "cancelledAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionOpened",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "reservePrice",
+            "type": "u64"
+          },
+          {
+            "name": "minIncrement",
+            "type": "u64"
+          },
+          {
+            "name": "endAt",
+            "type": "i64"
+          },
+          {
+            "name": "activateOnSettle",
+            "type": "bool"
+          },
+          {
+            "name": "openedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionSettled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "winner",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "activated",
+            "type": "bool"
+          },
+          {
+            "name": "settledAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auditAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "submitted"
+          },
+          {
+            "name": "subPrefixCreated"
+          },
+          {
+            "name": "auctionSettled"
+          },
+          {
+            "name": "approved"
+          },
+          {
+            "name": "rejected"
+          },
+          {
+            "name": "infoRequested"
+          },
+          {
+            "name": "metadataUpdated"
+          },
+          {
+            "name": "resubmitted"
+          },
+          {
+            "name": "appealed"
+          },
+          {
+            "name": "appealUpheld"
+          },
+          {
+            "name": "appealDenied"
+          },
+          {
+            "name": "deactivated"
+          },
+          {
+            "name": "reactivated"
+          },
+          {
+            "name": "ownerRecovered"
+          },
+          {
+            "name": "ownerPaused"
+          },
+          {
+            "name": "ownerResumed"
+          },
+          {
+            "name": "frozen"
+          },
+          {
+            "name": "unfrozen"
+          },
+          {
+            "name": "suspended"
+          },
+          {
+            "name": "suspensionExtended"
+          },
+          {
+            "name": "suspensionEnded"
+          },
+          {
+            "name": "sold"
+          },
+          {
+            "name": "migrated"
+          },
+          {
+            "name": "revoked"
+          },
+          {
+            "name": "refunded"
+          },
+          {
+            "name": "swept"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auditEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "actor",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "auditAction"
+              }
+            }
+          },
+          {
+            "name": "oldStatus",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "prefixStatus"
+                }
+              }
+            }
+          },
+          {
+            "name": "newStatus",
+            "type": {
+              "defined": {
+                "name": "prefixStatus"
+              }
+            }
+          },
+          {
+            "name": "oldOwner",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "newOwner",
+            "type": "pubkey"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auditLog",
+      "docs": [
+        "Per-prefix ring buffer of the last MAX_AUDIT_ENTRIES status and ownership transitions"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "auditEntry"
+                }
+              }
+            }
+          },
+          {
+            "name": "head",
+            "type": "u16"
+          },
+          {
+            "name": "total",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimReleaseReason",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "voluntary"
+          },
+          {
+            "name": "voted"
+          },
+          {
+            "name": "expired"
+          },
+          {
+            "name": "verifierRemoved"
+          },
+          {
+            "name": "prefixRevoked"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimWindowUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldWindow",
+            "type": "i64"
+          },
+          {
+            "name": "newWindow",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "commitPolicyUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "commitRequired",
+            "type": "bool"
+          },
+          {
+            "name": "commitDeposit",
+            "type": "u64"
+          },
+          {
+            "name": "minRevealDelay",
+            "type": "i64"
+          },
+          {
+            "name": "commitmentTtl",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "commitmentRefunded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          },
+          {
+            "name": "refundedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configInitialized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "initializedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "expiryExtensionPolicyUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "needsInfoExtension",
+            "type": "i64"
+          },
+          {
+            "name": "maxExpiryExtension",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeRegistry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "currentFee",
+            "type": "u64"
+          },
+          {
+            "name": "pause",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldFee",
+            "type": "u64"
+          },
+          {
+            "name": "newFee",
+            "type": "u64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "listing",
+      "docs": [
+        "Fixed-price sale offer for a prefix; closed on purchase or delisting"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "metadataUri",
+            "type": "string"
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "refHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "prefixStatus"
+              }
+            }
+          },
+          {
+            "name": "authorityKeys",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "feePaid",
+            "type": "u64"
+          },
+          {
+            "name": "expiryAt",
+            "type": "i64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "reviewRound",
+            "type": "u32"
+          },
+          {
+            "name": "claimedBy",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "claimExpiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "rejectionReason",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "rejectionReason"
+                }
+              }
+            }
+          },
+          {
+            "name": "rejectionEvidence",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "resubmissionCount",
+            "type": "u16"
+          },
+          {
+            "name": "appealEvidence",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "decidedBy",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "reviewStartedAt",
+            "type": "i64"
+          },
+          {
+            "name": "infoRequestHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "expiryExtensionUsed",
+            "type": "i64"
+          },
+          {
+            "name": "verifiedUntil",
+            "type": "i64"
+          },
+          {
+            "name": "intentCategories",
+            "type": "u32"
+          },
+          {
+            "name": "parent",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "depth",
+            "type": "u8"
+          },
+          {
+            "name": "subPrefixCount",
+            "type": "u16"
+          },
+          {
+            "name": "rejectedAt",
+            "type": "i64"
+          },
+          {
+            "name": "suspendedUntil",
+            "type": "i64"
+          },
+          {
+            "name": "suspensionReason",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "suspensionReason"
+                }
+              }
+            }
+          },
+          {
+            "name": "suspensionEvidence",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "suspendedBy",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "frozenBy",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "deactivatedFrom",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "prefixStatus"
+                }
+              }
+            }
+          },
+          {
+            "name": "listed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixAccountMigrated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "migratedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixActivated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "authorityKeysLen",
+            "type": "u8"
+          },
+          {
+            "name": "activatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixApproved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "skeleton",
+            "type": "string"
+          },
+          {
+            "name": "verifier",
+            "type": "pubkey"
+          },
+          {
+            "name": "refHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "verifiedUntil",
+            "type": "i64"
+          },
+          {
+            "name": "approvedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixAuthorityUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldAuthorityKeys",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "newAuthorityKeys",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixCommitment",
+      "docs": [
+        "Sealed intent to register a prefix; `commitment` is hashv(prefix, owner, salt).",
+        "Holds the owner's deposit until it is revealed or refunded."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          },
+          {
+            "name": "revealAfter",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixCommitted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          },
+          {
+            "name": "revealAfter",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixDeactivated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "at",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixDelisted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "delistedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "delistedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixFrozen",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "at",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixInfoProvided",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "requestHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "newMetadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "providedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixInfoRequested",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "verifier",
+            "type": "pubkey"
+          },
+          {
+            "name": "requestHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "expiryAt",
+            "type": "i64"
+          },
+          {
+            "name": "requestedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixListed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "listedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixMetadataUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldMetadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "newMetadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixOwnerRecovered",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "oldOwner",
+            "type": "pubkey"
+          },
+          {
+            "name": "newOwner",
+            "type": "pubkey"
+          },
+          {
+            "name": "feePaid",
+            "type": "u64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixPausedByOwner",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "at",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixReactivated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "at",
+            "type": "i64"
+          }
+        ]
+      }
+    },
     {
       "name": "prefixRefunded",
-      "discriminator": [
-        185,
-        77,
-        232,
-        59,
-        97,
-        175,
-        246,
-        251
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "refundedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
       "name": "prefixRejected",
-      "discriminator": [
-        152,
-        238,
-        98,
-        180,
-        223,
-        147,
-        27,
-        171
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "verifier",
+            "type": "pubkey"
+          },
+          {
+            "name": "reasonCode",
+            "type": {
+              "defined": {
+                "name": "rejectionReason"
+              }
+            }
+          },
+          {
+            "name": "evidenceHash",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "rejectedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "prefixSubmitted",
-      "discriminator": [
-        212,
-        152,
-        202,
-        170,
-        29,
-        251,
-        149,
-        39
-      ]
+      "name": "prefixReservationAdded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "reservationKind"
+              }
+            }
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "claimant",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "treasuryWithdraw",
-      "discriminator": [
-        164,
-        41,
-        149,
-        134,
-        248,
-        87,
-        41,
-        218
-      ]
+      "name": "prefixReservationRemoved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "removedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixResubmitted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataUri",
+            "type": "string"
+          },
+          {
+            "name": "feeTopUp",
+            "type": "u64"
+          },
+          {
+            "name": "resubmissionCount",
+            "type": "u16"
+          },
+          {
+            "name": "expiryAt",
+            "type": "i64"
+          },
+          {
+            "name": "resubmittedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixResumedByOwner",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "at",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prefixRevealed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "revealedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "verifierAdded",
-      "discriminator": [
-        113,
-        131,
-        132,
-        161,
-        53,
-        64,
-        96,
-        78
-      ]
+      "name": "prefixReverified",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "verifier",
+            "type": "pubkey"
+          },
+          {
+            "name": "verifiedUntil",
+            "type": "i64"
+          },
+          {
+            "name": "reverifiedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "verifierRemoved",
-      "discriminator": [
-        87,
-        0,
-        8,
-        47,
-        151,
-        131,
-        51,
-        99
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "unauthorizedAdmin",
-      "msg": "Unauthorized admin"
+      "name": "prefixRevoked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousOwner",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "blockedUntil",
+            "type": "i64"
+          },
+          {
+            "name": "revokedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "unauthorizedVerifier",
-      "msg": "Unauthorized verifier"
+      "name": "prefixSold",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "royalty",
+            "type": "u64"
+          },
+          {
+            "name": "soldAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "invalidPrefixFormat",
-      "msg": "Invalid prefix format"
+      "name": "prefixStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "pending"
+          },
+          {
+            "name": "active"
+          },
+          {
+            "name": "rejected"
+          },
+          {
+            "name": "inactive"
+          },
+          {
+            "name": "underAppeal"
+          },
+          {
+            "name": "needsInfo"
+          },
+          {
+            "name": "ownerPaused"
+          },
+          {
+            "name": "frozen"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "prefixAlreadyExists",
-      "msg": "Prefix already exists"
+      "name": "prefixSubmitted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "skeleton",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataUri",
+            "type": "string"
+          },
+          {
+            "name": "feePaid",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "pendingPda",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "invalidPrefixStatus",
-      "msg": "Prefix not in pending state"
+      "name": "prefixSuspended",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "suspensionReason"
+              }
+            }
+          },
+          {
+            "name": "evidenceHash",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "suspendedUntil",
+            "type": "i64"
+          },
+          {
+            "name": "at",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "insufficientFee",
-      "msg": "Insufficient fee"
+      "name": "prefixSuspensionEnded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "at",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "invalidMetadataHashLength",
-      "msg": "Invalid metadata hash"
+      "name": "prefixSuspensionExtended",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "suspendedUntil",
+            "type": "i64"
+          },
+          {
+            "name": "at",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "invalidMetadataUri",
-      "msg": "Invalid metadata uri"
+      "name": "prefixSwept",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "caller",
+            "type": "pubkey"
+          },
+          {
+            "name": "refund",
+            "type": "u64"
+          },
+          {
+            "name": "reward",
+            "type": "u64"
+          },
+          {
+            "name": "sweptAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "invalidTreasuryAccount",
-      "msg": "Treasury not owned by program"
+      "name": "prefixUnfrozen",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "frozenBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "at",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "insufficientTreasuryBalance",
-      "msg": "Insufficient treasury balance"
+      "name": "protocolConfig",
+      "docs": [
+        "Admin-tunable prefix rules"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minPrefixLen",
+            "type": "u8"
+          },
+          {
+            "name": "maxPrefixLen",
+            "type": "u8"
+          },
+          {
+            "name": "allowHyphen",
+            "type": "bool"
+          },
+          {
+            "name": "allowUnderscore",
+            "type": "bool"
+          },
+          {
+            "name": "maxExpiryDuration",
+            "type": "i64"
+          },
+          {
+            "name": "minReviewWindow",
+            "type": "i64"
+          },
+          {
+            "name": "sweepReward",
+            "type": "u64"
+          },
+          {
+            "name": "rejectedGracePeriod",
+            "type": "i64"
+          },
+          {
+            "name": "royaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "commitRequired",
+            "type": "bool"
+          },
+          {
+            "name": "commitDeposit",
+            "type": "u64"
+          },
+          {
+            "name": "minRevealDelay",
+            "type": "i64"
+          },
+          {
+            "name": "commitmentTtl",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "refundNotAllowed",
-      "msg": "Refund not allowed in current state"
+      "name": "protocolConfigUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "minPrefixLen",
+            "type": "u8"
+          },
+          {
+            "name": "maxPrefixLen",
+            "type": "u8"
+          },
+          {
+            "name": "allowHyphen",
+            "type": "bool"
+          },
+          {
+            "name": "allowUnderscore",
+            "type": "bool"
+          },
+          {
+            "name": "maxExpiryDuration",
+            "type": "i64"
+          },
+          {
+            "name": "minReviewWindow",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "unauthorizedOwnerAction",
-      "msg": "Only owner may perform this action"
+      "name": "quorumTier",
+      "docs": [
+        "Quorum override for prefixes whose length falls in `min_len..=max_len`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minLen",
+            "type": "u8"
+          },
+          {
+            "name": "maxLen",
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "missingBump",
-      "msg": "Account bump missing"
+      "name": "rejectionReason",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "schemaInvalid"
+          },
+          {
+            "name": "hashMismatch"
+          },
+          {
+            "name": "impersonation"
+          },
+          {
+            "name": "trademark"
+          },
+          {
+            "name": "offensive"
+          },
+          {
+            "name": "other"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "feeOperationsPaused",
-      "msg": "Fee operations paused"
+      "name": "reservationKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "reserved"
+          },
+          {
+            "name": "blocked"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "prefixExpired",
-      "msg": "Prefix expired"
+      "name": "reservedPrefix",
+      "docs": [
+        "Admin-managed entry in the reserved / blocked names registry"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "reservationKind"
+              }
+            }
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "claimant",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "authorityKeysTooMany",
-      "msg": "Invalid authority keys length"
+      "name": "reviewClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "verifier",
+            "type": "pubkey"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "claimedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "invalidEd25519Signature",
-      "msg": "Invalid Ed25519 signature"
-    }
-  ],
-  "types": [
-    {
-      "name": "feeRegistry",
+      "name": "reviewConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
-            "type": "pubkey"
+            "name": "defaultQuorum",
+            "type": "u8"
           },
           {
-            "name": "currentFee",
+            "name": "quorumTiers",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "quorumTier"
+                }
+              }
+            }
+          },
+          {
+            "name": "claimWindow",
+            "type": "i64"
+          },
+          {
+            "name": "appealBond",
             "type": "u64"
           },
           {
-            "name": "pause",
-            "type": "bool"
+            "name": "minVerifierBond",
+            "type": "u64"
+          },
+          {
+            "name": "unbondingPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "needsInfoExtension",
+            "type": "i64"
+          },
+          {
+            "name": "maxExpiryExtension",
+            "type": "i64"
+          },
+          {
+            "name": "verificationValidity",
+            "type": "i64"
           },
           {
             "name": "bump",
@@ -1614,7 +11161,7 @@ export type PrefixSystem = {
       }
     },
     {
-      "name": "feeUpdated",
+      "name": "reviewQuorumUpdated",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1623,12 +11170,12 @@ export type PrefixSystem = {
             "type": "pubkey"
           },
           {
-            "name": "oldFee",
-            "type": "u64"
+            "name": "defaultQuorum",
+            "type": "u8"
           },
           {
-            "name": "newFee",
-            "type": "u64"
+            "name": "tiersLen",
+            "type": "u8"
           },
           {
             "name": "updatedAt",
@@ -1638,69 +11185,169 @@ export type PrefixSystem = {
       }
     },
     {
-      "name": "prefixAccount",
+      "name": "reviewReleased",
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "owner",
-            "type": "pubkey"
-          },
           {
             "name": "prefix",
             "type": "string"
           },
           {
-            "name": "metadataUri",
-            "type": "string"
+            "name": "verifier",
+            "type": "pubkey"
           },
           {
-            "name": "metadataHash",
+            "name": "reason",
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "defined": {
+                "name": "claimReleaseReason"
+              }
             }
           },
           {
-            "name": "refHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "releasedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "reviewState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "round",
+            "type": "u32"
           },
           {
-            "name": "status",
+            "name": "approvals",
             "type": {
-              "defined": {
-                "name": "prefixStatus"
-              }
+              "vec": "pubkey"
             }
           },
           {
-            "name": "authorityKeys",
+            "name": "rejections",
             "type": {
               "vec": "pubkey"
             }
           },
           {
-            "name": "feePaid",
-            "type": "u64"
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "reviewVoteCast",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "verifier",
+            "type": "pubkey"
+          },
+          {
+            "name": "approve",
+            "type": "bool"
+          },
+          {
+            "name": "approvals",
+            "type": "u8"
+          },
+          {
+            "name": "rejections",
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          },
+          {
+            "name": "votedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "reviewWindowExtended",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "verifier",
+            "type": "pubkey"
+          },
+          {
+            "name": "extension",
+            "type": "i64"
           },
           {
             "name": "expiryAt",
             "type": "i64"
           },
           {
-            "name": "createdAt",
+            "name": "extendedAt",
             "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltyUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldBps",
+            "type": "u16"
+          },
+          {
+            "name": "newBps",
+            "type": "u16"
           },
           {
             "name": "updatedAt",
             "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "skeletonGuard",
+      "docs": [
+        "Claims a confusable-character skeleton for one prefix; seeded by the skeleton so",
+        "no second prefix collapsing to the same form can be registered."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
           },
           {
             "name": "bump",
@@ -1710,7 +11357,93 @@ export type PrefixSystem = {
       }
     },
     {
-      "name": "prefixActivated",
+      "name": "subPrefixCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "skeleton",
+            "type": "string"
+          },
+          {
+            "name": "parentPrefix",
+            "type": "string"
+          },
+          {
+            "name": "parent",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "depth",
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "suspensionReason",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "phishing"
+          },
+          {
+            "name": "impersonation"
+          },
+          {
+            "name": "compromisedKeys"
+          },
+          {
+            "name": "legalRequest"
+          },
+          {
+            "name": "other"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sweepPolicyUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "sweepReward",
+            "type": "u64"
+          },
+          {
+            "name": "rejectedGracePeriod",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tombstone",
+      "docs": [
+        "Left behind by revoke_prefix; blocks re-registration of the name until `blocked_until`"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
@@ -1719,73 +11452,72 @@ export type PrefixSystem = {
             "type": "string"
           },
           {
-            "name": "owner",
-            "type": "pubkey"
+            "name": "previousOwner",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "blockedUntil",
+            "type": "i64"
           },
           {
-            "name": "authorityKeysLen",
-            "type": "u8"
+            "name": "revokedBy",
+            "type": "pubkey"
           },
           {
-            "name": "activatedAt",
+            "name": "createdAt",
             "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "prefixApproved",
+      "name": "treasuryWithdraw",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "prefix",
-            "type": "string"
+            "name": "admin",
+            "type": "pubkey"
           },
           {
-            "name": "verifier",
+            "name": "to",
             "type": "pubkey"
           },
           {
-            "name": "refHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "approvedAt",
+            "name": "withdrawnAt",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "prefixAuthorityUpdated",
+      "name": "verificationValidityUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "prefix",
-            "type": "string"
-          },
-          {
-            "name": "owner",
+            "name": "admin",
             "type": "pubkey"
           },
           {
-            "name": "oldAuthorityKeys",
-            "type": {
-              "vec": "pubkey"
-            }
+            "name": "oldValidity",
+            "type": "i64"
           },
           {
-            "name": "newAuthorityKeys",
-            "type": {
-              "vec": "pubkey"
-            }
+            "name": "newValidity",
+            "type": "i64"
           },
           {
             "name": "updatedAt",
@@ -1795,83 +11527,69 @@ export type PrefixSystem = {
       }
     },
     {
-      "name": "prefixDeactivated",
+      "name": "verifierAdded",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "prefix",
-            "type": "string"
+            "name": "admin",
+            "type": "pubkey"
           },
           {
-            "name": "admin",
+            "name": "verifier",
             "type": "pubkey"
           },
           {
-            "name": "at",
+            "name": "label",
+            "type": "string"
+          },
+          {
+            "name": "addedAt",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "prefixMetadataUpdated",
+      "name": "verifierBondDeposited",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "prefix",
-            "type": "string"
-          },
-          {
-            "name": "owner",
+            "name": "verifier",
             "type": "pubkey"
           },
           {
-            "name": "oldMetadataHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "newMetadataHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "totalBond",
+            "type": "u64"
           },
           {
-            "name": "updatedAt",
+            "name": "depositedAt",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "prefixOwnerRecovered",
+      "name": "verifierBondPolicyUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "prefix",
-            "type": "string"
-          },
-          {
-            "name": "oldOwner",
+            "name": "admin",
             "type": "pubkey"
           },
           {
-            "name": "newOwner",
-            "type": "pubkey"
+            "name": "minVerifierBond",
+            "type": "u64"
           },
           {
-            "name": "feePaid",
-            "type": "u64"
+            "name": "unbondingPeriod",
+            "type": "i64"
           },
           {
             "name": "updatedAt",
@@ -1881,160 +11599,192 @@ export type PrefixSystem = {
       }
     },
     {
-      "name": "prefixReactivated",
+      "name": "verifierBondWithdrawn",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "prefix",
-            "type": "string"
+            "name": "verifier",
+            "type": "pubkey"
           },
           {
-            "name": "admin",
-            "type": "pubkey"
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "at",
+            "name": "withdrawnAt",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "prefixRefunded",
+      "name": "verifierMigrated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "prefix",
-            "type": "string"
+            "name": "admin",
+            "type": "pubkey"
           },
           {
-            "name": "owner",
+            "name": "verifier",
             "type": "pubkey"
           },
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "activated",
+            "type": "bool"
           },
           {
-            "name": "refundedAt",
+            "name": "migratedAt",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "prefixRejected",
+      "name": "verifierRecord",
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "prefix",
-            "type": "string"
-          },
           {
             "name": "verifier",
             "type": "pubkey"
           },
           {
-            "name": "reason",
+            "name": "label",
             "type": "string"
           },
           {
-            "name": "rejectedAt",
+            "name": "active",
+            "type": "bool"
+          },
+          {
+            "name": "addedAt",
             "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "prefixStatus",
-      "type": {
-        "kind": "enum",
-        "variants": [
+          },
           {
-            "name": "pending"
+            "name": "removedAt",
+            "type": {
+              "option": "i64"
+            }
           },
           {
-            "name": "active"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "rejected"
+            "name": "claimedPrefix",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "inactive"
+            "name": "claimExpiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "bond",
+            "type": "u64"
+          },
+          {
+            "name": "approvals",
+            "type": "u64"
+          },
+          {
+            "name": "rejections",
+            "type": "u64"
+          },
+          {
+            "name": "totalReviewSecs",
+            "type": "u64"
+          },
+          {
+            "name": "lastActionAt",
+            "type": "i64"
+          },
+          {
+            "name": "scope",
+            "type": {
+              "defined": {
+                "name": "verifierScope"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "prefixSubmitted",
+      "name": "verifierRemoved",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "prefix",
-            "type": "string"
-          },
-          {
-            "name": "owner",
+            "name": "admin",
             "type": "pubkey"
           },
           {
-            "name": "metadataHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "verifier",
+            "type": "pubkey"
           },
           {
-            "name": "metadataUri",
-            "type": "string"
-          },
+            "name": "removedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "verifierResigned",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "feePaid",
-            "type": "u64"
+            "name": "verifier",
+            "type": "pubkey"
           },
           {
-            "name": "createdAt",
+            "name": "unbondsAt",
             "type": "i64"
           },
           {
-            "name": "pendingPda",
-            "type": "pubkey"
+            "name": "resignedAt",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "treasuryWithdraw",
+      "name": "verifierScope",
+      "docs": [
+        "Jurisdiction of a verifier; a zeroed scope places no restriction"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
-            "type": "pubkey"
+            "name": "categoryMask",
+            "type": "u32"
           },
           {
-            "name": "to",
-            "type": "pubkey"
+            "name": "minLen",
+            "type": "u8"
           },
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "maxLen",
+            "type": "u8"
           },
           {
-            "name": "withdrawnAt",
-            "type": "i64"
+            "name": "patterns",
+            "type": {
+              "vec": "string"
+            }
           }
         ]
       }
     },
     {
-      "name": "verifierAdded",
+      "name": "verifierScopeUpdated",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2047,14 +11797,32 @@ export type PrefixSystem = {
             "type": "pubkey"
           },
           {
-            "name": "addedAt",
+            "name": "categoryMask",
+            "type": "u32"
+          },
+          {
+            "name": "minLen",
+            "type": "u8"
+          },
+          {
+            "name": "maxLen",
+            "type": "u8"
+          },
+          {
+            "name": "patterns",
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "updatedAt",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "verifierRemoved",
+      "name": "verifierSlashed",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2067,7 +11835,19 @@ export type PrefixSystem = {
             "type": "pubkey"
           },
           {
-            "name": "removedAt",
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "remainingBond",
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "slashedAt",
             "type": "i64"
           }
         ]
@@ -2075,6 +11855,10 @@ export type PrefixSystem = {
     },
     {
       "name": "verifiersList",
+      "docs": [
+        "Legacy single-account verifier registry. New deployments use one `VerifierRecord`",
+        "per verifier; existing entries are moved over with `migrate_verifier`."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
@@ -3,4 +3,5 @@ export * from "./types";
 export * from "./errors";
 export * from "./validation";
 export * from "./constants";
+export * from "./utils";
 export * from "./idl/prefix_system"; 
\ No newline at end of file
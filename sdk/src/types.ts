@@ -10,6 +10,7 @@ export interface FeeRegistry {
   updatedAt: BN;
 }
 
+// Legacy verifier list; superseded by one VerifierRecord per verifier
 export interface VerifiersList {
   admin: PublicKey;
   verifiers: PublicKey[];
@@ -18,6 +19,62 @@ export interface VerifiersList {
   updatedAt: BN;
 }
 
+export interface VerifierRecord {
+  verifier: PublicKey;
+  label: string;
+  active: boolean;
+  addedAt: BN;
+  removedAt: BN | null;
+  bump: number;
+  claimedPrefix: PublicKey | null;
+  claimExpiresAt: BN;
+  bond: BN; // lamports held in the verifier vault, excluding its rent
+  approvals: BN;
+  rejections: BN;
+  totalReviewSecs: BN;
+  lastActionAt: BN;
+  scope: {
+    categoryMask: number;
+    minLen: number;
+    maxLen: number;
+    patterns: string[];
+  };
+}
+
+export interface ReviewConfig {
+  defaultQuorum: number;
+  quorumTiers: { minLen: number; maxLen: number; threshold: number }[];
+  claimWindow: BN;
+  appealBond: BN;
+  minVerifierBond: BN;
+  unbondingPeriod: BN;
+  needsInfoExtension: BN;
+  maxExpiryExtension: BN;
+  verificationValidity: BN;
+  bump: number;
+  createdAt: BN;
+  updatedAt: BN;
+}
+
+export interface ProtocolConfig {
+  minPrefixLen: number;
+  maxPrefixLen: number;
+  allowHyphen: boolean;
+  allowUnderscore: boolean;
+  maxExpiryDuration: BN;
+  minReviewWindow: BN;
+  sweepReward: BN;
+  rejectedGracePeriod: BN;
+  royaltyBps: number;
+  commitRequired: boolean;
+  commitDeposit: BN;
+  minRevealDelay: BN;
+  commitmentTtl: BN;
+  bump: number;
+  createdAt: BN;
+  updatedAt: BN;
+}
+
 export type Treasury = AccountInfo<Buffer>;
 
 export class PrefixStatus {
@@ -25,6 +82,19 @@ export class PrefixStatus {
   static readonly Active = { active: {} };
   static readonly Rejected = { rejected: {} };
   static readonly Inactive = { inactive: {} };
+  static readonly UnderAppeal = { underAppeal: {} };
+  static readonly NeedsInfo = { needsInfo: {} };
+  static readonly OwnerPaused = { ownerPaused: {} };
+  static readonly Frozen = { frozen: {} };
+}
+
+export class RejectionReason {
+  static readonly SchemaInvalid = { schemaInvalid: {} };
+  static readonly HashMismatch = { hashMismatch: {} };
+  static readonly Impersonation = { impersonation: {} };
+  static readonly Trademark = { trademark: {} };
+  static readonly Offensive = { offensive: {} };
+  static readonly Other = { other: {} };
 }
 
 export interface PrefixAccount {
@@ -36,8 +106,51 @@ export interface PrefixAccount {
   status: PrefixStatus;
   authorityKeys: PublicKey[];
   feePaid: BN;
-  expiryAt?: BN;
+  expiryAt: BN;
   createdAt: BN;
   updatedAt: BN;
   bump: number;
+  reviewRound: number; // bumped every time the prefix (re)enters Pending
+  claimedBy: PublicKey | null; // verifier holding the review claim
+  claimExpiresAt: BN;
+  rejectionReason: RejectionReason | null;
+  rejectionEvidence: number[] | null;
+  resubmissionCount: number;
+  appealEvidence: number[] | null;
+  decidedBy: PublicKey | null;
+  reviewStartedAt: BN;
+  infoRequestHash: number[];
+  expiryExtensionUsed: BN;
+  verifiedUntil: BN; // approval stops being trusted after this timestamp
+  intentCategories: number;
+  parent: PublicKey | null; // parent PrefixAccount for sub-prefixes
+  depth: number;
+  subPrefixCount: number;
+  rejectedAt: BN;
+  suspendedUntil: BN; // untrusted until this timestamp
+  suspensionReason: object | null;
+  suspensionEvidence: number[] | null;
+  suspendedBy: PublicKey | null;
+  frozenBy: PublicKey | null;
+  deactivatedFrom: PrefixStatus | null;
+  listed: boolean; // an open marketplace listing locks the prefix
+}
+
+export interface AuditEntry {
+  actor: PublicKey;
+  action: object;
+  oldStatus: PrefixStatus | null;
+  newStatus: PrefixStatus;
+  oldOwner: PublicKey | null;
+  newOwner: PublicKey;
+  slot: BN;
+  timestamp: BN;
+}
+
+export interface AuditLog {
+  prefix: string;
+  entries: AuditEntry[]; // ring buffer of the last MAX_AUDIT_ENTRIES transitions
+  head: number;
+  total: number;
+  bump: number;
 }
@@ -0,0 +1,64 @@
+import { PublicKey } from "@solana/web3.js";
+import { SUB_PREFIX_SEPARATOR } from "./constants";
+
+// Confusable characters and the representative they collapse to
+const SKELETON_MAP: Record<string, string> = {
+  "0": "O",
+  "1": "I",
+  L: "I",
+  "2": "Z",
+  "3": "E",
+  "4": "A",
+  "5": "S",
+  "6": "G",
+  "7": "T",
+  "8": "B",
+};
+
+/**
+ * Canonical "skeleton" of a normalized prefix, matching the program's `skeleton_prefix`.
+ * Confusable names share a skeleton, e.g. `G00GLE` and `GOOGLE` both map to `GOOGIE`;
+ * separators are dropped. Skeleton guard, reserved-name and tombstone PDAs are keyed by it.
+ */
+export function skeletonPrefix(normalized: string): string {
+  return normalized
+    .split("")
+    .filter((c) => /[A-Z0-9]/.test(c))
+    .map((c) => SKELETON_MAP[c] ?? c)
+    .join("");
+}
+
+/**
+ * Full name of a sub-prefix, e.g. `ACME` + `PAY` -> `ACME-PAY`
+ */
+export function subPrefixName(parent: string, label: string): string {
+  return `${parent}${SUB_PREFIX_SEPARATOR}${label}`;
+}
+
+/**
+ * Part of a name before its first separator, e.g. `ACME-PAY` -> `ACME`
+ */
+export function prefixRoot(name: string): string {
+  return name.split(SUB_PREFIX_SEPARATOR)[0];
+}
+
+/**
+ * Commitment for commit-reveal registration: sha256(prefix || owner || salt)
+ * @param prefix the normalized prefix that will be revealed
+ * @param owner the wallet that will reveal it
+ * @param salt 32 random bytes kept secret until the reveal
+ * @returns the 32-byte commitment to pass to commitPrefix
+ */
+export async function prefixCommitment(
+  prefix: string,
+  owner: PublicKey,
+  salt: Uint8Array | number[]
+): Promise<number[]> {
+  const data = Buffer.concat([
+    Buffer.from(prefix),
+    owner.toBuffer(),
+    Buffer.from(salt),
+  ]);
+  const digest = await globalThis.crypto.subtle.digest("SHA-256", data);
+  return Array.from(new Uint8Array(digest));
+}
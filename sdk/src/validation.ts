@@ -34,7 +34,8 @@ export function validateMetadataHash(hash: number[] | Uint8Array): void {
 /**
  * Validate prefix
  * Must be between MIN_PREFIX_LEN and MAX_PREFIX_LEN characters
- * Must be alphanumeric
+ * Must be alphanumeric; `-` and `_` may separate alphanumerics where the
+ * protocol config allows them (sub-prefixes always use `-`)
  */
 export function validatePrefix(prefix: string): void {
   if (prefix.length < MIN_PREFIX_LEN || prefix.length > MAX_PREFIX_LEN) {
@@ -43,7 +44,7 @@ export function validatePrefix(prefix: string): void {
     );
   }
 
-  if (!/^[A-Z0-9]+$/.test(prefix)) {
+  if (!/^[A-Z0-9]+([-_][A-Z0-9]+)*$/.test(prefix)) {
     throw new PrefixSystemClientError(
       `Invalid prefix: must be alphanumeric and between ${MIN_PREFIX_LEN} and ${MAX_PREFIX_LEN} characters`
     );
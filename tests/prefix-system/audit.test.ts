@@ -0,0 +1,123 @@
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { PrefixSystemClient } from "../../sdk/src/client";
+import {
+  TestContext,
+  airdrop,
+  submitPrefixWithFee,
+  approvePrefix,
+  rejectPrefix,
+  refundPrefixFee,
+  recoverPrefixOwnerWithFee,
+  pausePrefix,
+  resumePrefix,
+  getAuditLog,
+  derivePrefixPDA,
+} from "./helpers/setup";
+import { getSharedTestContext } from "./helpers/shared-setup";
+
+const MAX_AUDIT_ENTRIES = 16;
+
+describe("Audit Log Tests", () => {
+  let ctx: TestContext;
+  let admin: Keypair;
+  let sdk: PrefixSystemClient;
+  let owner: Keypair;
+  let verifier: Keypair;
+
+  before(async () => {
+    const shared = await getSharedTestContext();
+    ctx = shared.ctx;
+    owner = shared.owner;
+    verifier = shared.verifier;
+    admin = shared.admin;
+    sdk = PrefixSystemClient.initForTesting(ctx.connection, admin);
+
+    await airdrop(ctx.provider, owner.publicKey, 10);
+  });
+
+  function freshPrefix(tag: string) {
+    return `${tag}${Date.now().toString().slice(-6)}`.substring(0, 12);
+  }
+
+  describe("1️⃣ Audit Log", () => {
+    it("Each transition records its actor, old and new values", async () => {
+      const prefix = freshPrefix("ALT");
+      const newOwner = Keypair.generate();
+      await airdrop(ctx.provider, newOwner.publicKey, 2);
+
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await approvePrefix(ctx, prefix, verifier);
+      await recoverPrefixOwnerWithFee(ctx, prefix, newOwner);
+
+      const log = await getAuditLog(ctx, prefix);
+      expect(log.prefix).to.equal(prefix);
+      expect(log.total).to.equal(3);
+      expect(log.entries).to.have.length(3);
+
+      const [submitted, approved, recovered] = log.entries;
+      expect(submitted.action).to.deep.equal({ submitted: {} });
+      expect(submitted.actor.toString()).to.equal(owner.publicKey.toString());
+      expect(submitted.oldStatus).to.be.null;
+      expect(submitted.newStatus).to.deep.equal({ pending: {} });
+      expect(submitted.oldOwner).to.be.null;
+      expect(submitted.newOwner.toString()).to.equal(owner.publicKey.toString());
+
+      expect(approved.action).to.deep.equal({ approved: {} });
+      expect(approved.actor.toString()).to.equal(verifier.publicKey.toString());
+      expect(approved.oldStatus).to.deep.equal({ pending: {} });
+      expect(approved.newStatus).to.deep.equal({ active: {} });
+      expect(approved.slot.toNumber()).to.be.at.least(submitted.slot.toNumber());
+      expect(approved.timestamp.toNumber()).to.be.at.least(submitted.timestamp.toNumber());
+
+      expect(recovered.action).to.deep.equal({ ownerRecovered: {} });
+      expect(recovered.actor.toString()).to.equal(admin.publicKey.toString());
+      expect(recovered.oldOwner.toString()).to.equal(owner.publicKey.toString());
+      expect(recovered.newOwner.toString()).to.equal(newOwner.publicKey.toString());
+
+      // Relayers read the same history through the SDK
+      const sdkLog = await sdk.getAuditLog(prefix);
+      expect(sdkLog.total).to.equal(3);
+    });
+
+    it("The log outlives a refunded prefix", async () => {
+      const prefix = freshPrefix("ALR");
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await rejectPrefix(ctx, prefix, verifier);
+      await refundPrefixFee(ctx, prefix, owner);
+
+      expect(
+        await ctx.connection.getAccountInfo(await derivePrefixPDA(ctx.program.programId, prefix))
+      ).to.be.null;
+
+      const log = await getAuditLog(ctx, prefix);
+      expect(log.total).to.equal(3);
+      const refunded = log.entries[2];
+      expect(refunded.action).to.deep.equal({ refunded: {} });
+      expect(refunded.oldStatus).to.deep.equal({ rejected: {} });
+      expect(refunded.newStatus).to.deep.equal({ inactive: {} });
+    });
+
+    it("Only the latest transitions are kept once the buffer is full", async () => {
+      const prefix = freshPrefix("ALB");
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await approvePrefix(ctx, prefix, verifier);
+      for (let i = 0; i < MAX_AUDIT_ENTRIES / 2; i++) {
+        await pausePrefix(ctx, prefix, owner);
+        await resumePrefix(ctx, prefix, owner);
+      }
+
+      const log = await getAuditLog(ctx, prefix);
+      expect(log.total).to.equal(MAX_AUDIT_ENTRIES + 2);
+      expect(log.entries).to.have.length(MAX_AUDIT_ENTRIES);
+      // The two oldest entries (submission and approval) were overwritten
+      expect(log.head).to.equal(2);
+      expect(log.entries[log.head].action).to.deep.equal({ ownerPaused: {} });
+      const newest = log.entries[(log.head + MAX_AUDIT_ENTRIES - 1) % MAX_AUDIT_ENTRIES];
+      expect(newest.action).to.deep.equal({ ownerResumed: {} });
+      expect(
+        log.entries.some((entry) => JSON.stringify(entry.action) === JSON.stringify({ submitted: {} }))
+      ).to.be.false;
+    });
+  });
+});
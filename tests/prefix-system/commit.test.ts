@@ -0,0 +1,183 @@
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import {
+  TestContext,
+  airdrop,
+  submitPrefixWithFee,
+  setCommitPolicy,
+  commitPrefix,
+  revealPrefix,
+  refundCommitment,
+  fetchPrefixAccount,
+  getProtocolConfig,
+  deriveCommitmentPDA,
+  DEFAULT_COMMIT_DEPOSIT,
+  DEFAULT_MIN_REVEAL_DELAY,
+  DEFAULT_COMMITMENT_TTL,
+} from "./helpers/setup";
+import { getSharedTestContext } from "./helpers/shared-setup";
+
+describe("Commit-Reveal Tests", () => {
+  let ctx: TestContext;
+  let owner: Keypair;
+  let stranger: Keypair;
+
+  before(async () => {
+    const shared = await getSharedTestContext();
+    ctx = shared.ctx;
+    owner = shared.owner;
+
+    await airdrop(ctx.provider, owner.publicKey, 10);
+
+    stranger = Keypair.generate();
+    await airdrop(ctx.provider, stranger.publicKey, 2);
+  });
+
+  afterEach(async () => {
+    // Reset commit policy after each test
+    try {
+      await setCommitPolicy(ctx, false);
+    } catch (error) {
+      // Ignore errors if already at correct state
+    }
+  });
+
+  function freshPrefix(tag: string) {
+    return `${tag}${Date.now().toString().slice(-6)}`.substring(0, 12);
+  }
+
+  function randomSalt() {
+    return Array.from(Keypair.generate().publicKey.toBytes());
+  }
+
+  describe("1️⃣ Commit Policy", () => {
+    it("Admin can require commit-reveal", async () => {
+      await setCommitPolicy(ctx, true, DEFAULT_COMMIT_DEPOSIT, 30, 600);
+
+      const config = await getProtocolConfig(ctx);
+      expect(config.commitRequired).to.be.true;
+      expect(config.commitDeposit.toNumber()).to.equal(DEFAULT_COMMIT_DEPOSIT);
+      expect(config.minRevealDelay.toNumber()).to.equal(30);
+      expect(config.commitmentTtl.toNumber()).to.equal(600);
+    });
+
+    it("Direct submission fails while commit-reveal is required", async () => {
+      await setCommitPolicy(ctx, true);
+
+      try {
+        await submitPrefixWithFee(ctx, freshPrefix("CRD"), owner);
+        expect.fail("Should have failed without a commitment");
+      } catch (error) {
+        expect(error.message).to.include("CommitRevealRequired");
+      }
+    });
+
+    it("Should fail with a TTL not above the reveal delay or a non-admin signer", async () => {
+      try {
+        await setCommitPolicy(ctx, true, DEFAULT_COMMIT_DEPOSIT, 600, 600);
+        expect.fail("Should have failed with an invalid TTL");
+      } catch (error) {
+        expect(error.message).to.include("InvalidCommitPolicy");
+      }
+
+      try {
+        await setCommitPolicy({ ...ctx, admin: stranger }, true);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("2️⃣ Commit and Reveal", () => {
+    it("Commit escrows the deposit and reveal registers the prefix", async () => {
+      await setCommitPolicy(ctx, true, DEFAULT_COMMIT_DEPOSIT, 0, DEFAULT_COMMITMENT_TTL);
+      const prefix = freshPrefix("CRR");
+      const salt = randomSalt();
+
+      const commitment = await commitPrefix(ctx, prefix, owner, salt);
+      const commitmentPDA = deriveCommitmentPDA(ctx.program.programId, owner.publicKey, commitment);
+      const record = await ctx.program.account.prefixCommitment.fetch(commitmentPDA);
+      expect(record.owner.toString()).to.equal(owner.publicKey.toString());
+      expect(record.deposit.toNumber()).to.equal(DEFAULT_COMMIT_DEPOSIT);
+      expect(record.expiresAt.toNumber() - record.createdAt.toNumber()).to.equal(
+        DEFAULT_COMMITMENT_TTL
+      );
+
+      await revealPrefix(ctx, prefix, owner, salt, commitment);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.owner.toString()).to.equal(owner.publicKey.toString());
+      expect(prefixAccount.status).to.deep.equal({ pending: {} });
+      // The deposit and rent go back to the owner
+      expect(await ctx.connection.getAccountInfo(commitmentPDA)).to.be.null;
+    });
+
+    it("Should fail to reveal before the minimum delay", async () => {
+      await setCommitPolicy(ctx, true, DEFAULT_COMMIT_DEPOSIT, DEFAULT_MIN_REVEAL_DELAY);
+      const prefix = freshPrefix("CRE");
+      const salt = randomSalt();
+      const commitment = await commitPrefix(ctx, prefix, owner, salt);
+
+      try {
+        await revealPrefix(ctx, prefix, owner, salt, commitment);
+        expect.fail("Should have failed before the reveal delay");
+      } catch (error) {
+        expect(error.message).to.include("RevealTooEarly");
+      }
+    });
+
+    it("Should fail to reveal a different prefix or salt", async () => {
+      await setCommitPolicy(ctx, true, DEFAULT_COMMIT_DEPOSIT, 0);
+      const prefix = freshPrefix("CRM");
+      const salt = randomSalt();
+      const commitment = await commitPrefix(ctx, prefix, owner, salt);
+
+      try {
+        await revealPrefix(ctx, freshPrefix("CRX"), owner, salt, commitment);
+        expect.fail("Should have failed with a different prefix");
+      } catch (error) {
+        expect(error.message).to.include("CommitmentMismatch");
+      }
+
+      try {
+        await revealPrefix(ctx, prefix, owner, randomSalt(), commitment);
+        expect.fail("Should have failed with a different salt");
+      } catch (error) {
+        expect(error.message).to.include("CommitmentMismatch");
+      }
+    });
+
+    it("Expired commitments cannot be revealed but can be refunded", async () => {
+      await setCommitPolicy(ctx, true, DEFAULT_COMMIT_DEPOSIT, 0, 2);
+      const prefix = freshPrefix("CRF");
+      const salt = randomSalt();
+      const commitment = await commitPrefix(ctx, prefix, owner, salt);
+      const commitmentPDA = deriveCommitmentPDA(ctx.program.programId, owner.publicKey, commitment);
+
+      try {
+        await refundCommitment(ctx, owner, commitment);
+        expect.fail("Should have failed before expiry");
+      } catch (error) {
+        expect(error.message).to.include("CommitmentNotExpired");
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      try {
+        await revealPrefix(ctx, prefix, owner, salt, commitment);
+        expect.fail("Should have failed after expiry");
+      } catch (error) {
+        expect(error.message).to.include("CommitmentExpired");
+      }
+
+      const escrowed = await ctx.connection.getBalance(commitmentPDA);
+      const ownerBefore = await ctx.connection.getBalance(owner.publicKey);
+      await refundCommitment(ctx, owner, commitment);
+      const ownerAfter = await ctx.connection.getBalance(owner.publicKey);
+
+      expect(ownerAfter - ownerBefore).to.equal(escrowed);
+      expect(await ctx.connection.getAccountInfo(commitmentPDA)).to.be.null;
+    });
+  });
+});
@@ -1,6 +1,6 @@
 import { expect } from "chai";
 import { Keypair, PublicKey } from "@solana/web3.js";
-import { TestContext, airdrop, submitPrefixWithFee, approvePrefix, rejectPrefix, refundPrefixFee, updateFee, setPause, addVerifier, removeVerifier, withdrawTreasury, getVerifiersList } from "./helpers/setup";
+import { TestContext, airdrop, submitPrefixWithFee, approvePrefix, rejectPrefix, refundPrefixFee, updateFee, setPause, addVerifier, removeVerifier, withdrawTreasury, getVerifierRecord } from "./helpers/setup";
 import { getSharedTestContext } from "./helpers/shared-setup";
 
 describe("Event System Tests", () => {
@@ -95,6 +95,7 @@ describe("Event System Tests", () => {
       expect(capturedEvent).to.have.property('feePaid');
       expect(capturedEvent).to.have.property('createdAt');
       expect(capturedEvent).to.have.property('pendingPda');
+      expect(capturedEvent).to.have.property('skeleton');
       
       expect(capturedEvent.prefix).to.equal(prefix);
       expect(capturedEvent.owner.toString()).to.equal(owner.publicKey.toString());
@@ -127,6 +128,7 @@ describe("Event System Tests", () => {
       expect(capturedEvent).to.have.property('prefix');
       expect(capturedEvent).to.have.property('verifier');
       expect(capturedEvent).to.have.property('refHash');
+      expect(capturedEvent).to.have.property('verifiedUntil');
       expect(capturedEvent).to.have.property('approvedAt');
       
       expect(capturedEvent.prefix).to.equal(prefix);
@@ -157,6 +159,8 @@ describe("Event System Tests", () => {
       // Check PrefixRejected event structure
       expect(capturedEvent).to.have.property('prefix');
       expect(capturedEvent).to.have.property('verifier');
+      expect(capturedEvent).to.have.property('reasonCode');
+      expect(capturedEvent).to.have.property('evidenceHash');
       expect(capturedEvent).to.have.property('reason');
       expect(capturedEvent).to.have.property('rejectedAt');
       
@@ -218,10 +222,12 @@ describe("Event System Tests", () => {
       // Check VerifierAdded event structure
       expect(capturedEvent).to.have.property('admin');
       expect(capturedEvent).to.have.property('verifier');
+      expect(capturedEvent).to.have.property('label');
       expect(capturedEvent).to.have.property('addedAt');
       
       expect(capturedEvent.admin.toString()).to.equal(ctx.admin.publicKey.toString());
       expect(capturedEvent.verifier.toString()).to.equal(newVerifier.publicKey.toString());
+      expect(capturedEvent.label).to.equal("test verifier");
     });
 
     it("VerifierRemoved event should contain correct data", async () => {
@@ -519,7 +525,7 @@ describe("Event System Tests", () => {
       }
     });
 
-    it("Verifier events should match verifiers list state", async () => {
+    it("Verifier events should match verifier record state", async () => {
       const testVerifier = Keypair.generate();
       let addEvent: any = null;
       let removeEvent: any = null;
@@ -533,9 +539,9 @@ describe("Event System Tests", () => {
       await new Promise(resolve => setTimeout(resolve, 1000));
       ctx.program.removeEventListener(addListener);
 
-      // Check verifier is in list
-      const verifiersAfterAdd = await getVerifiersList(ctx);
-      expect(verifiersAfterAdd.verifiers.map(v => v.toString())).to.include(testVerifier.publicKey.toString());
+      // Check verifier record is active
+      const recordAfterAdd = await getVerifierRecord(ctx, testVerifier.publicKey);
+      expect(recordAfterAdd.active).to.be.true;
 
       const removeListener = ctx.program.addEventListener('verifierRemoved', (event) => {
         removeEvent = event;
@@ -546,9 +552,9 @@ describe("Event System Tests", () => {
       await new Promise(resolve => setTimeout(resolve, 1000));
       ctx.program.removeEventListener(removeListener);
 
-      // Check verifier is not in list
-      const verifiersAfterRemove = await getVerifiersList(ctx);
-      expect(verifiersAfterRemove.verifiers.map(v => v.toString())).to.not.include(testVerifier.publicKey.toString());
+      // Check verifier record is inactive
+      const recordAfterRemove = await getVerifierRecord(ctx, testVerifier.publicKey);
+      expect(recordAfterRemove.active).to.be.false;
 
       expect(addEvent).to.not.be.null;
       expect(removeEvent).to.not.be.null;
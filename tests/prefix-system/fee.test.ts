@@ -13,6 +13,8 @@ import {
   getTreasuryBalance,
   getFeeRegistry,
   fetchPrefixAccount,
+  getReviewConfig,
+  getProtocolConfig,
   TestContext,
 } from "./helpers/setup";
 import { getSharedTestContext } from "./helpers/shared-setup";
@@ -40,7 +42,7 @@ describe("Fee System Tests", () => {
   });
 
   describe("1️⃣ initialize instruction", () => {
-    it("Should create FeeRegistry and VerifiersList with correct admin and initial fee", async () => {
+    it("Should create FeeRegistry, ReviewConfig and ProtocolConfig with correct admin and initial fee", async () => {
       // This test verifies the setup done in before() hook
       const feeRegistry = await getFeeRegistry(ctx);
       expect(feeRegistry.admin.toString()).to.equal(
@@ -48,6 +50,12 @@ describe("Fee System Tests", () => {
       );
       expect(feeRegistry.currentFee.toNumber()).to.equal(1000000);
       expect(feeRegistry.pause).to.be.false;
+
+      const reviewConfig = await getReviewConfig(ctx);
+      expect(reviewConfig.defaultQuorum).to.equal(1);
+
+      const protocolConfig = await getProtocolConfig(ctx);
+      expect(protocolConfig.allowHyphen).to.be.false;
     });
   });
 
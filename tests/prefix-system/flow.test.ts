@@ -16,7 +16,11 @@ import {
   addVerifier,
   removeVerifier,
   withdrawTreasury,
-  getVerifiersList,
+  deactivatePrefix,
+  reactivatePrefix,
+  getVerifierRecord,
+  deriveAuditLogPDA,
+  INTENT_PAYMENT,
 } from "./helpers/setup";
 import { getSharedTestContext } from "./helpers/shared-setup";
 
@@ -205,18 +209,7 @@ const OWNER = "${owner.publicKey.toString()}";
 
       // Step 2: Deactivate prefix (admin action)
       console.log("Step 2: Deactivating prefix...");
-      await ctx.program.methods
-        .deactivatePrefix(prefix)
-        .accountsStrict({
-          prefixAccount: PublicKey.findProgramAddressSync(
-            [Buffer.from("prefix"), Buffer.from(prefix)],
-            ctx.program.programId
-          )[0],
-          admin: admin.publicKey,
-          feeRegistry: ctx.feeRegistryPDA,
-        })
-        .signers([admin])
-        .rpc();
+      await deactivatePrefix(ctx, prefix);
 
       // Verify prefix is deactivated
       prefixAccount = await ctx.program.account.prefixAccount.fetch(
@@ -229,18 +222,7 @@ const OWNER = "${owner.publicKey.toString()}";
 
       // Step 3: Reactivate prefix (admin action)
       console.log("Step 3: Reactivating prefix...");
-      await ctx.program.methods
-        .reactivatePrefix(prefix)
-        .accountsStrict({
-          prefixAccount: PublicKey.findProgramAddressSync(
-            [Buffer.from("prefix"), Buffer.from(prefix)],
-            ctx.program.programId
-          )[0],
-          admin: admin.publicKey,
-          feeRegistry: ctx.feeRegistryPDA,
-        })
-        .signers([admin])
-        .rpc();
+      await reactivatePrefix(ctx, prefix);
 
       // Verify prefix is active again
       prefixAccount = await ctx.program.account.prefixAccount.fetch(
@@ -321,10 +303,8 @@ const OWNER = "${owner.publicKey.toString()}";
       await addVerifier(ctx, newVerifier.publicKey);
 
       // Verify verifier was added
-      const verifiersList = await getVerifiersList(ctx);
-      expect(verifiersList.verifiers.map((v) => v.toString())).to.include(
-        newVerifier.publicKey.toString()
-      );
+      const verifierRecord = await getVerifierRecord(ctx, newVerifier.publicKey);
+      expect(verifierRecord.active).to.be.true;
 
       // Step 5: Unpause program
       console.log("Step 5: Unpausing program...");
@@ -436,8 +416,9 @@ const OWNER = "${owner.publicKey.toString()}";
       await addVerifier(ctx, verifier3.publicKey);
 
       // Verify verifiers were added
-      let verifiersList = await getVerifiersList(ctx);
-      expect(verifiersList.verifiers).to.have.length.greaterThan(3); // Including existing verifier
+      for (const v of [verifier1, verifier2, verifier3]) {
+        expect((await getVerifierRecord(ctx, v.publicKey)).active).to.be.true;
+      }
 
       // Step 2: Submit prefixes with different authority configurations
       console.log(
@@ -526,10 +507,8 @@ const OWNER = "${owner.publicKey.toString()}";
       await removeVerifier(ctx, verifier3.publicKey);
 
       // Verify verifier was removed
-      verifiersList = await getVerifiersList(ctx);
-      expect(verifiersList.verifiers.map((v) => v.toString())).to.not.include(
-        verifier3.publicKey.toString()
-      );
+      const removedRecord = await getVerifierRecord(ctx, verifier3.publicKey);
+      expect(removedRecord.active).to.be.false;
 
       // Step 8: Remaining verifiers can still approve
       console.log("Step 8: Remaining verifiers can still approve...");
@@ -614,8 +593,9 @@ const OWNER = "${owner.publicKey.toString()}";
       }
 
       // Verify verifiers were added
-      const verifiersList = await getVerifiersList(ctx);
-      expect(verifiersList.verifiers).to.have.length.greaterThan(5);
+      for (const v of verifiers) {
+        expect((await getVerifierRecord(ctx, v.publicKey)).active).to.be.true;
+      }
 
       // Test that all verifiers can approve prefixes
       const prefix = `LIMIT${Date.now().toString().slice(-6)}`.substring(0, 12);
@@ -863,14 +843,17 @@ const OWNER = "${owner.publicKey.toString()}";
           .updatePrefixMetadata(
             prefix,
             "https://unauthorized-update.com",
-            Array(32).fill(99)
+            Array(32).fill(99),
+            INTENT_PAYMENT
           )
           .accountsStrict({
+            owner: owner.publicKey,
+            protocolConfig: ctx.protocolConfigPDA,
             prefixAccount: PublicKey.findProgramAddressSync(
               [Buffer.from("prefix"), Buffer.from(prefix)],
               ctx.program.programId
             )[0],
-            owner: owner.publicKey,
+            auditLog: deriveAuditLogPDA(ctx.program.programId, prefix),
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           })
           .signers([owner])
@@ -980,7 +963,7 @@ const OWNER = "${owner.publicKey.toString()}";
         await approvePrefix(ctx, prefix, nonVerifier);
         expect.fail("Should have failed with non-verifier");
       } catch (error) {
-        expect(error.message).to.include("UnauthorizedVerifier");
+        expect(error.message).to.include("AccountNotInitialized");
       }
 
       // Step 3: Verify prefix state is still consistent
@@ -2,6 +2,12 @@ import * as anchor from "@coral-xyz/anchor";
 import { AnchorProvider, Program } from "@coral-xyz/anchor";
 import { Keypair, PublicKey, Ed25519Program } from "@solana/web3.js";
 import { PrefixSystem } from "../../../target/types/prefix_system";
+import {
+  prefixCommitment,
+  prefixRoot,
+  skeletonPrefix,
+  subPrefixName,
+} from "../../../sdk/src/utils";
 import * as nacl from "tweetnacl";
 
 export interface TestContext {
@@ -12,13 +18,49 @@ export interface TestContext {
   admin: Keypair;
   feeRegistryPDA: PublicKey;
   treasuryPDA: PublicKey;
-  verifiersPDA: PublicKey;
+  reviewConfigPDA: PublicKey;
+  protocolConfigPDA: PublicKey;
 }
 
 export const FEE_REGISTRY_SEED = "fee_registry";
-export const VERIFIERS_SEED = "verifiers";
+export const VERIFIERS_SEED = "verifiers"; // legacy VerifiersList
+export const VERIFIER_SEED = "verifier";
+export const VERIFIER_VAULT_SEED = "verifier_vault";
 export const TREASURY_SEED = "treasury";
 export const PREFIX_SEED = "prefix";
+export const REVIEW_CONFIG_SEED = "review_config";
+export const PROTOCOL_CONFIG_SEED = "protocol_config";
+export const REVIEW_SEED = "review";
+export const APPEAL_SEED = "appeal";
+export const LISTING_SEED = "listing";
+export const AUCTION_SEED = "auction";
+export const RESERVED_SEED = "reserved";
+export const SKELETON_SEED = "skeleton";
+export const TOMBSTONE_SEED = "tombstone";
+export const AUDIT_LOG_SEED = "audit_log";
+export const COMMITMENT_SEED = "commitment";
+
+export const INTENT_PAYMENT = 1;
+export const INTENT_STAKE = 1 << 1;
+export const DEFAULT_APPEAL_BOND = 100_000_000;
+export const DEFAULT_NEEDS_INFO_EXTENSION = 3 * 24 * 60 * 60;
+export const DEFAULT_MAX_EXPIRY_EXTENSION = 7 * 24 * 60 * 60;
+export const DEFAULT_VERIFICATION_VALIDITY = 365 * 24 * 60 * 60;
+export const DEFAULT_PROTOCOL_CONFIG = {
+  minPrefixLen: 3,
+  maxPrefixLen: 12,
+  allowHyphen: false,
+  allowUnderscore: false,
+  maxExpiryDuration: 14 * 24 * 60 * 60,
+  minReviewWindow: 24 * 60 * 60,
+};
+export const DEFAULT_SWEEP_REWARD = 1_000_000;
+export const DEFAULT_REJECTED_GRACE_PERIOD = 30 * 24 * 60 * 60;
+export const DEFAULT_CLAIM_WINDOW = 24 * 60 * 60;
+export const DEFAULT_UNBONDING_PERIOD = 7 * 24 * 60 * 60;
+export const DEFAULT_COMMIT_DEPOSIT = 10_000_000;
+export const DEFAULT_MIN_REVEAL_DELAY = 60;
+export const DEFAULT_COMMITMENT_TTL = 24 * 60 * 60;
 
 export async function initProviderAndProgram(): Promise<TestContext> {
   const provider = anchor.AnchorProvider.local();
@@ -38,8 +80,13 @@ export async function initProviderAndProgram(): Promise<TestContext> {
     program.programId
   );
 
-  const [verifiersPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from(VERIFIERS_SEED)],
+  const [reviewConfigPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from(REVIEW_CONFIG_SEED)],
+    program.programId
+  );
+
+  const [protocolConfigPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PROTOCOL_CONFIG_SEED)],
     program.programId
   );
 
@@ -56,7 +103,8 @@ export async function initProviderAndProgram(): Promise<TestContext> {
     admin,
     feeRegistryPDA,
     treasuryPDA,
-    verifiersPDA,
+    reviewConfigPDA,
+    protocolConfigPDA,
   };
 }
 
@@ -81,6 +129,83 @@ export async function derivePrefixPDA(programId: PublicKey, prefix: string) {
   return pda;
 }
 
+// PDAs keyed by the normalized prefix
+function derivePrefixKeyedPDA(
+  programId: PublicKey,
+  seed: string,
+  prefix: string
+) {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(seed), Buffer.from(prefix.toUpperCase())],
+    programId
+  );
+  return pda;
+}
+
+export function deriveReviewStatePDA(programId: PublicKey, prefix: string) {
+  return derivePrefixKeyedPDA(programId, REVIEW_SEED, prefix);
+}
+
+export function deriveAuditLogPDA(programId: PublicKey, prefix: string) {
+  return derivePrefixKeyedPDA(programId, AUDIT_LOG_SEED, prefix);
+}
+
+export function deriveAppealPDA(programId: PublicKey, prefix: string) {
+  return derivePrefixKeyedPDA(programId, APPEAL_SEED, prefix);
+}
+
+export function deriveListingPDA(programId: PublicKey, prefix: string) {
+  return derivePrefixKeyedPDA(programId, LISTING_SEED, prefix);
+}
+
+export function deriveAuctionPDA(programId: PublicKey, prefix: string) {
+  return derivePrefixKeyedPDA(programId, AUCTION_SEED, prefix);
+}
+
+// Skeleton guard, reserved-name and tombstone PDAs are keyed by the prefix skeleton
+export function deriveSkeletonKeyedPDA(
+  programId: PublicKey,
+  seed: string,
+  prefix: string
+) {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(seed), Buffer.from(skeletonPrefix(prefix.toUpperCase()))],
+    programId
+  );
+  return pda;
+}
+
+export function deriveVerifierRecordPDA(
+  programId: PublicKey,
+  verifier: PublicKey
+) {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(VERIFIER_SEED), verifier.toBuffer()],
+    programId
+  );
+  return pda;
+}
+
+export function deriveVerifierVaultPDA(
+  programId: PublicKey,
+  verifier: PublicKey
+) {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(VERIFIER_VAULT_SEED), verifier.toBuffer()],
+    programId
+  );
+  return pda;
+}
+
+// Verifier records of earlier voters, passed so their votes count towards the quorum
+export function voterRecords(ctx: TestContext, voters: PublicKey[]) {
+  return voters.map((voter) => ({
+    pubkey: deriveVerifierRecordPDA(ctx.program.programId, voter),
+    isSigner: false,
+    isWritable: false,
+  }));
+}
+
 // Fetch prefix account
 export async function fetchPrefixAccount(ctx: TestContext, prefix: string) {
   const pda = await derivePrefixPDA(ctx.program.programId, prefix);
@@ -88,36 +213,166 @@ export async function fetchPrefixAccount(ctx: TestContext, prefix: string) {
 }
 
 // Helper: Initialize the program
+// Suites submit directly and review with unbonded verifiers, so the commit-reveal
+// requirement and the verifier bond minimum are switched off; the suites that cover
+// them turn them back on.
 export async function initializeProgram(
   ctx: TestContext,
   initialFee: number = 1000000
 ) {
-  return ctx.program.methods
+  const sig = await ctx.program.methods
     .initialize(ctx.admin.publicKey, new anchor.BN(initialFee))
     .accountsStrict({
       feeRegistry: ctx.feeRegistryPDA,
-      verifiers: ctx.verifiersPDA,
+      reviewConfig: ctx.reviewConfigPDA,
+      protocolConfig: ctx.protocolConfigPDA,
       treasury: ctx.treasuryPDA,
       payer: ctx.payer.publicKey,
       systemProgram: anchor.web3.SystemProgram.programId,
     })
     .signers([ctx.payer])
     .rpc();
+  await setCommitPolicy(ctx, false);
+  await setVerifierBondPolicy(ctx, 0);
+  return sig;
+}
+
+// Helper: Set commit-reveal policy
+export async function setCommitPolicy(
+  ctx: TestContext,
+  commitRequired: boolean,
+  commitDeposit: number = DEFAULT_COMMIT_DEPOSIT,
+  minRevealDelay: number = DEFAULT_MIN_REVEAL_DELAY,
+  commitmentTtl: number = DEFAULT_COMMITMENT_TTL
+) {
+  return ctx.program.methods
+    .setCommitPolicy(
+      commitRequired,
+      new anchor.BN(commitDeposit),
+      new anchor.BN(minRevealDelay),
+      new anchor.BN(commitmentTtl)
+    )
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      protocolConfig: ctx.protocolConfigPDA,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Set verifier bond policy
+export async function setVerifierBondPolicy(
+  ctx: TestContext,
+  minVerifierBond: number,
+  unbondingPeriod: number = DEFAULT_UNBONDING_PERIOD
+) {
+  return ctx.program.methods
+    .setVerifierBondPolicy(
+      new anchor.BN(minVerifierBond),
+      new anchor.BN(unbondingPeriod)
+    )
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      reviewConfig: ctx.reviewConfigPDA,
+    })
+    .signers([ctx.admin])
+    .rpc();
 }
 
 // Helper: Add verifier
-export async function addVerifier(ctx: TestContext, verifier: PublicKey) {
+export async function addVerifier(
+  ctx: TestContext,
+  verifier: PublicKey,
+  label: string = "test verifier"
+) {
   return ctx.program.methods
-    .addVerifier(verifier)
+    .addVerifier(verifier, label)
     .accountsStrict({
-      verifiers: ctx.verifiersPDA,
+      admin: ctx.admin.publicKey,
       feeRegistry: ctx.feeRegistryPDA,
+      verifierRecord: deriveVerifierRecordPDA(ctx.program.programId, verifier),
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Deposit verifier bond
+export async function depositVerifierBond(
+  ctx: TestContext,
+  verifier: Keypair,
+  amount: number
+) {
+  return ctx.program.methods
+    .depositVerifierBond(new anchor.BN(amount))
+    .accountsStrict({
+      verifier: verifier.publicKey,
+      verifierRecord: deriveVerifierRecordPDA(
+        ctx.program.programId,
+        verifier.publicKey
+      ),
+      vault: deriveVerifierVaultPDA(ctx.program.programId, verifier.publicKey),
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([verifier])
+    .rpc();
+}
+
+// Helper: Slash part of a verifier's bond into the treasury
+export async function slashVerifier(
+  ctx: TestContext,
+  verifier: PublicKey,
+  amount: number,
+  reason: string = "Approved an impersonating prefix"
+) {
+  return ctx.program.methods
+    .slashVerifier(verifier, new anchor.BN(amount), reason)
+    .accountsStrict({
       admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      verifierRecord: deriveVerifierRecordPDA(ctx.program.programId, verifier),
+      vault: deriveVerifierVaultPDA(ctx.program.programId, verifier),
+      treasury: ctx.treasuryPDA,
     })
     .signers([ctx.admin])
     .rpc();
 }
 
+// Helper: Resign as a verifier, starting the unbonding period
+export async function resignVerifier(ctx: TestContext, verifier: Keypair) {
+  return ctx.program.methods
+    .resignVerifier()
+    .accountsStrict({
+      verifier: verifier.publicKey,
+      reviewConfig: ctx.reviewConfigPDA,
+      verifierRecord: deriveVerifierRecordPDA(
+        ctx.program.programId,
+        verifier.publicKey
+      ),
+    })
+    .signers([verifier])
+    .rpc();
+}
+
+// Helper: Withdraw a departed verifier's bond
+export async function withdrawVerifierBond(ctx: TestContext, verifier: Keypair) {
+  return ctx.program.methods
+    .withdrawVerifierBond()
+    .accountsStrict({
+      verifier: verifier.publicKey,
+      reviewConfig: ctx.reviewConfigPDA,
+      verifierRecord: deriveVerifierRecordPDA(
+        ctx.program.programId,
+        verifier.publicKey
+      ),
+      vault: deriveVerifierVaultPDA(ctx.program.programId, verifier.publicKey),
+    })
+    .signers([verifier])
+    .rpc();
+}
+
 // Helper: Create Ed25519 signature instruction using proper Ed25519Program
 export function createEd25519SignatureInstruction(
   signer: Keypair,
@@ -133,6 +388,40 @@ export function createEd25519SignatureInstruction(
   });
 }
 
+// Accounts of a direct submission, shared with reveal_prefix
+export async function submitAccounts(
+  ctx: TestContext,
+  prefix: string,
+  owner: PublicKey
+) {
+  const programId = ctx.program.programId;
+  const normalized = prefix.toUpperCase();
+  const hyphenated = normalized.includes("-");
+  return {
+    owner,
+    feeRegistry: ctx.feeRegistryPDA,
+    protocolConfig: ctx.protocolConfigPDA,
+    treasury: ctx.treasuryPDA,
+    prefixAccount: await derivePrefixPDA(programId, normalized),
+    reviewState: deriveReviewStatePDA(programId, normalized),
+    skeletonGuard: deriveSkeletonKeyedPDA(programId, SKELETON_SEED, normalized),
+    auditLog: deriveAuditLogPDA(programId, normalized),
+    reservedPrefix: deriveSkeletonKeyedPDA(programId, RESERVED_SEED, normalized),
+    rootSkeletonGuard: hyphenated
+      ? deriveSkeletonKeyedPDA(programId, SKELETON_SEED, prefixRoot(normalized))
+      : null,
+    rootReservedPrefix: hyphenated
+      ? deriveSkeletonKeyedPDA(programId, RESERVED_SEED, prefixRoot(normalized))
+      : null,
+    rootTombstone: hyphenated
+      ? deriveSkeletonKeyedPDA(programId, TOMBSTONE_SEED, prefixRoot(normalized))
+      : null,
+    tombstone: deriveSkeletonKeyedPDA(programId, TOMBSTONE_SEED, normalized),
+    instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+    systemProgram: anchor.web3.SystemProgram.programId,
+  };
+}
+
 // Helper: Submit prefix with fee
 export async function submitPrefixWithFee(
   ctx: TestContext,
@@ -140,7 +429,9 @@ export async function submitPrefixWithFee(
   owner: Keypair,
   metadataUri: string = "https://example.com/metadata",
   metadataHash: number[] = Array(32).fill(1),
-  authorityKeys: PublicKey[] = []
+  authorityKeys: PublicKey[] = [],
+  intentCategories: number = INTENT_PAYMENT,
+  reviewWindow: number | null = null
 ) {
   // Create Ed25519 signature over metadata_hash
   const ed25519Ix = createEd25519SignatureInstruction(
@@ -149,162 +440,506 @@ export async function submitPrefixWithFee(
   );
 
   return ctx.program.methods
-    .submitPrefixWithFee(prefix, metadataUri, metadataHash, authorityKeys)
+    .submitPrefixWithFee(
+      prefix,
+      metadataUri,
+      metadataHash,
+      authorityKeys,
+      intentCategories,
+      reviewWindow === null ? null : new anchor.BN(reviewWindow)
+    )
+    .accountsStrict(await submitAccounts(ctx, prefix, owner.publicKey))
+    .preInstructions([ed25519Ix])
+    .signers([owner])
+    .rpc();
+}
+
+// Commitment PDA, keyed by the committing owner and the commitment hash
+export function deriveCommitmentPDA(
+  programId: PublicKey,
+  owner: PublicKey,
+  commitment: number[]
+) {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(COMMITMENT_SEED), owner.toBuffer(), Buffer.from(commitment)],
+    programId
+  );
+  return pda;
+}
+
+// Helper: Commit to a prefix without revealing it; returns the commitment hash
+export async function commitPrefix(
+  ctx: TestContext,
+  prefix: string,
+  owner: Keypair,
+  salt: number[]
+) {
+  const commitment = await prefixCommitment(prefix, owner.publicKey, salt);
+  await ctx.program.methods
+    .commitPrefix(commitment)
     .accountsStrict({
       owner: owner.publicKey,
       feeRegistry: ctx.feeRegistryPDA,
-      treasury: ctx.treasuryPDA,
-      prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
-      instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      protocolConfig: ctx.protocolConfigPDA,
+      prefixCommitment: deriveCommitmentPDA(ctx.program.programId, owner.publicKey, commitment),
       systemProgram: anchor.web3.SystemProgram.programId,
     })
-    .preInstructions([ed25519Ix])
     .signers([owner])
     .rpc();
+  return commitment;
 }
 
-// Helper: Approve prefix
-export async function approvePrefix(
+// Helper: Reveal a committed prefix, registering it like a direct submission
+export async function revealPrefix(
   ctx: TestContext,
   prefix: string,
-  verifier: Keypair,
-  refHash: number[] = Array(32).fill(2)
+  owner: Keypair,
+  salt: number[],
+  commitment: number[],
+  metadataUri: string = "https://example.com/metadata",
+  metadataHash: number[] = Array(32).fill(1),
+  authorityKeys: PublicKey[] = [],
+  intentCategories: number = INTENT_PAYMENT,
+  reviewWindow: number | null = null
 ) {
+  const ed25519Ix = createEd25519SignatureInstruction(
+    owner,
+    new Uint8Array(metadataHash)
+  );
+
   return ctx.program.methods
-    .approvePrefix(prefix, refHash)
+    .revealPrefix(
+      prefix,
+      salt,
+      metadataUri,
+      metadataHash,
+      authorityKeys,
+      intentCategories,
+      reviewWindow === null ? null : new anchor.BN(reviewWindow)
+    )
     .accountsStrict({
-      prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
-      verifiers: ctx.verifiersPDA,
-      feeRegistry: ctx.feeRegistryPDA,
-      treasury: ctx.treasuryPDA,
-      verifier: verifier.publicKey,
+      submit: await submitAccounts(ctx, prefix, owner.publicKey),
+      prefixCommitment: deriveCommitmentPDA(ctx.program.programId, owner.publicKey, commitment),
     })
-    .signers([verifier])
+    .preInstructions([ed25519Ix])
+    .signers([owner])
     .rpc();
 }
 
-// Helper: Reject prefix
-export async function rejectPrefix(
+// Helper: Reclaim the deposit of an expired commitment
+export async function refundCommitment(
   ctx: TestContext,
-  prefix: string,
-  verifier: Keypair,
-  reason: string = "Invalid metadata"
+  owner: Keypair,
+  commitment: number[]
 ) {
   return ctx.program.methods
-    .rejectPrefix(prefix, reason)
+    .refundCommitment()
     .accountsStrict({
-      prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
-      verifiers: ctx.verifiersPDA,
-      feeRegistry: ctx.feeRegistryPDA,
-      verifier: verifier.publicKey,
+      owner: owner.publicKey,
+      prefixCommitment: deriveCommitmentPDA(ctx.program.programId, owner.publicKey, commitment),
     })
-    .signers([verifier])
+    .signers([owner])
     .rpc();
 }
 
-// Helper: Refund prefix fee
-export async function refundPrefixFee(
+// Helper: Resubmit a rejected prefix with corrected metadata
+export async function resubmitPrefix(
   ctx: TestContext,
   prefix: string,
-  owner: Keypair
+  owner: Keypair,
+  metadataUri: string = "https://example.com/metadata-v2",
+  metadataHash: number[] = Array(32).fill(3),
+  intentCategories: number = INTENT_PAYMENT,
+  reviewWindow: number | null = null
 ) {
+  const programId = ctx.program.programId;
+  const ed25519Ix = createEd25519SignatureInstruction(
+    owner,
+    new Uint8Array(metadataHash)
+  );
+
   return ctx.program.methods
-    .refundPrefixFee(prefix)
+    .resubmitPrefix(
+      prefix,
+      metadataUri,
+      metadataHash,
+      intentCategories,
+      reviewWindow === null ? null : new anchor.BN(reviewWindow)
+    )
     .accountsStrict({
       owner: owner.publicKey,
       feeRegistry: ctx.feeRegistryPDA,
+      protocolConfig: ctx.protocolConfigPDA,
       treasury: ctx.treasuryPDA,
-      prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+      instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
       systemProgram: anchor.web3.SystemProgram.programId,
     })
+    .preInstructions([ed25519Ix])
     .signers([owner])
     .rpc();
 }
 
-// Helper: Update fee
-export async function updateFee(ctx: TestContext, newFee: number) {
+// Helper: Appeal a rejection, escrowing the appeal bond
+export async function appealRejection(
+  ctx: TestContext,
+  prefix: string,
+  owner: Keypair,
+  evidenceHash: number[] = Array(32).fill(4)
+) {
+  const programId = ctx.program.programId;
   return ctx.program.methods
-    .updateFee(new anchor.BN(newFee))
+    .appealRejection(prefix, evidenceHash)
     .accountsStrict({
+      owner: owner.publicKey,
       feeRegistry: ctx.feeRegistryPDA,
-      admin: ctx.admin.publicKey,
+      reviewConfig: ctx.reviewConfigPDA,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+      appeal: deriveAppealPDA(programId, prefix),
+      systemProgram: anchor.web3.SystemProgram.programId,
     })
-    .signers([ctx.admin])
+    .signers([owner])
     .rpc();
 }
 
-// Helper: Set pause
-export async function setPause(ctx: TestContext, pause: boolean) {
+// Helper: Resolve an appeal; the resolver is the admin or a verifier
+export async function resolveAppeal(
+  ctx: TestContext,
+  prefix: string,
+  resolver: Keypair,
+  owner: PublicKey,
+  uphold: boolean
+) {
+  const programId = ctx.program.programId;
+  const isAdmin = resolver.publicKey.equals(ctx.admin.publicKey);
   return ctx.program.methods
-    .setPause(pause)
+    .resolveAppeal(prefix, uphold)
     .accountsStrict({
+      resolver: resolver.publicKey,
       feeRegistry: ctx.feeRegistryPDA,
-      admin: ctx.admin.publicKey,
+      reviewConfig: ctx.reviewConfigPDA,
+      verifierRecord: isAdmin
+        ? null
+        : deriveVerifierRecordPDA(programId, resolver.publicKey),
+      treasury: ctx.treasuryPDA,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+      reviewState: deriveReviewStatePDA(programId, prefix),
+      appeal: deriveAppealPDA(programId, prefix),
+      owner,
     })
-    .signers([ctx.admin])
+    .signers([resolver])
     .rpc();
 }
 
-// Helper: Withdraw treasury
-export async function withdrawTreasury(
+// Helper: Create a sub-prefix under an active parent; ancestors above the parent are
+// passed so their trust can be checked
+export async function createSubPrefix(
   ctx: TestContext,
-  amount: number,
-  to: PublicKey
+  parentPrefix: string,
+  label: string,
+  parentOwner: Keypair,
+  owner: PublicKey = parentOwner.publicKey,
+  ancestors: string[] = []
 ) {
+  const programId = ctx.program.programId;
+  const name = subPrefixName(parentPrefix, label);
+  const ancestorAccounts = await Promise.all(
+    ancestors.map(async (ancestor) => ({
+      pubkey: await derivePrefixPDA(programId, ancestor),
+      isSigner: false,
+      isWritable: false,
+    }))
+  );
+
   return ctx.program.methods
-    .withdrawTreasury(new anchor.BN(amount), to)
+    .createSubPrefix(
+      parentPrefix,
+      label,
+      owner,
+      "https://example.com/sub-metadata",
+      Array(32).fill(1),
+      [],
+      INTENT_PAYMENT
+    )
     .accountsStrict({
+      parentOwner: parentOwner.publicKey,
       feeRegistry: ctx.feeRegistryPDA,
-      treasury: ctx.treasuryPDA,
-      to: to,
+      protocolConfig: ctx.protocolConfigPDA,
+      parentAccount: await derivePrefixPDA(programId, parentPrefix),
+      prefixAccount: await derivePrefixPDA(programId, name),
+      reviewState: deriveReviewStatePDA(programId, name),
+      skeletonGuard: deriveSkeletonKeyedPDA(programId, SKELETON_SEED, name),
+      auditLog: deriveAuditLogPDA(programId, name),
+      reservedPrefix: deriveSkeletonKeyedPDA(programId, RESERVED_SEED, name),
+      tombstone: deriveSkeletonKeyedPDA(programId, TOMBSTONE_SEED, name),
       systemProgram: anchor.web3.SystemProgram.programId,
-      admin: ctx.admin.publicKey,
     })
-    .signers([ctx.admin])
+    .remainingAccounts(ancestorAccounts)
+    .signers([parentOwner])
     .rpc();
 }
 
-// Helper: Get treasury balance
-export async function getTreasuryBalance(ctx: TestContext) {
-  const account = await ctx.connection.getAccountInfo(ctx.treasuryPDA);
-  return account ? account.lamports : 0;
-}
-
-// Helper: Get fee registry
-export async function getFeeRegistry(ctx: TestContext) {
-  return ctx.program.account.feeRegistry.fetch(ctx.feeRegistryPDA);
-}
-
-// Helper: Update prefix metadata
-export async function updatePrefixMetadata(
+// Helper: Approve prefix
+export async function approvePrefix(
   ctx: TestContext,
   prefix: string,
-  owner: Keypair,
-  newUri: string,
-  newHash: number[]
+  verifier: Keypair,
+  refHash: number[] = Array(32).fill(2),
+  otherVoters: PublicKey[] = []
 ) {
-  const ed25519Ix = createEd25519SignatureInstruction(
-    owner,
-    new Uint8Array(newHash)
-  );
-
+  const programId = ctx.program.programId;
   return ctx.program.methods
-    .updatePrefixMetadata(prefix, newUri, newHash)
+    .approvePrefix(prefix, refHash)
     .accountsStrict({
-      owner: owner.publicKey,
-      prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
-      instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      verifier: verifier.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      verifierRecord: deriveVerifierRecordPDA(programId, verifier.publicKey),
+      reviewConfig: ctx.reviewConfigPDA,
+      treasury: ctx.treasuryPDA,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+      reviewState: deriveReviewStatePDA(programId, prefix),
     })
-    .preInstructions([ed25519Ix])
-    .signers([owner])
+    .remainingAccounts(voterRecords(ctx, otherVoters))
+    .signers([verifier])
     .rpc();
 }
 
-// Helper: Update prefix authority
-export async function updatePrefixAuthority(
+// Helper: Reject prefix
+export async function rejectPrefix(
   ctx: TestContext,
   prefix: string,
-  owner: Keypair,
+  verifier: Keypair,
+  reason: string = "Invalid metadata",
+  reasonCode: any = { other: {} },
+  evidenceHash: number[] | null = null,
+  otherVoters: PublicKey[] = []
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .rejectPrefix(prefix, reasonCode, evidenceHash, reason)
+    .accountsStrict({
+      verifier: verifier.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      verifierRecord: deriveVerifierRecordPDA(programId, verifier.publicKey),
+      reviewConfig: ctx.reviewConfigPDA,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+      reviewState: deriveReviewStatePDA(programId, prefix),
+    })
+    .remainingAccounts(voterRecords(ctx, otherVoters))
+    .signers([verifier])
+    .rpc();
+}
+
+// Helper: Ask the owner for more information, moving the prefix to NeedsInfo
+export async function requestPrefixInfo(
+  ctx: TestContext,
+  prefix: string,
+  verifier: Keypair,
+  requestHash: number[] = Array(32).fill(6)
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .requestPrefixInfo(prefix, requestHash)
+    .accountsStrict({
+      verifier: verifier.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      reviewConfig: ctx.reviewConfigPDA,
+      verifierRecord: deriveVerifierRecordPDA(programId, verifier.publicKey),
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+    })
+    .signers([verifier])
+    .rpc();
+}
+
+// Helper: Extend the review window of a pending prefix
+export async function extendReviewWindow(
+  ctx: TestContext,
+  prefix: string,
+  verifier: Keypair,
+  extension: number
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .extendReviewWindow(prefix, new anchor.BN(extension))
+    .accountsStrict({
+      verifier: verifier.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      reviewConfig: ctx.reviewConfigPDA,
+      verifierRecord: deriveVerifierRecordPDA(programId, verifier.publicKey),
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+    })
+    .signers([verifier])
+    .rpc();
+}
+
+// Helper: Renew the verification of an active prefix
+export async function reverifyPrefix(
+  ctx: TestContext,
+  prefix: string,
+  verifier: Keypair
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .reverifyPrefix(prefix)
+    .accountsStrict({
+      verifier: verifier.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      reviewConfig: ctx.reviewConfigPDA,
+      verifierRecord: deriveVerifierRecordPDA(programId, verifier.publicKey),
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+    })
+    .signers([verifier])
+    .rpc();
+}
+
+// Helper: Refund prefix fee
+export async function refundPrefixFee(
+  ctx: TestContext,
+  prefix: string,
+  owner: Keypair
+) {
+  return ctx.program.methods
+    .refundPrefixFee(prefix)
+    .accountsStrict({
+      owner: owner.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      treasury: ctx.treasuryPDA,
+      prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
+      reviewState: deriveReviewStatePDA(ctx.program.programId, prefix),
+      skeletonGuard: deriveSkeletonKeyedPDA(
+        ctx.program.programId,
+        SKELETON_SEED,
+        prefix
+      ),
+      auditLog: deriveAuditLogPDA(ctx.program.programId, prefix),
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([owner])
+    .rpc();
+}
+
+// Helper: Sweep an abandoned prefix, refunding its owner
+export async function sweepExpiredPrefix(
+  ctx: TestContext,
+  prefix: string,
+  caller: Keypair,
+  owner: PublicKey,
+  parentPrefix: string | null = null
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .sweepExpiredPrefix(prefix)
+    .accountsStrict({
+      caller: caller.publicKey,
+      owner,
+      feeRegistry: ctx.feeRegistryPDA,
+      protocolConfig: ctx.protocolConfigPDA,
+      treasury: ctx.treasuryPDA,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      reviewState: deriveReviewStatePDA(programId, prefix),
+      skeletonGuard: deriveSkeletonKeyedPDA(programId, SKELETON_SEED, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+      parentAccount:
+        parentPrefix === null ? null : await derivePrefixPDA(programId, parentPrefix),
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([caller])
+    .rpc();
+}
+
+// Helper: Update fee
+export async function updateFee(ctx: TestContext, newFee: number) {
+  return ctx.program.methods
+    .updateFee(new anchor.BN(newFee))
+    .accountsStrict({
+      feeRegistry: ctx.feeRegistryPDA,
+      admin: ctx.admin.publicKey,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Set pause
+export async function setPause(ctx: TestContext, pause: boolean) {
+  return ctx.program.methods
+    .setPause(pause)
+    .accountsStrict({
+      feeRegistry: ctx.feeRegistryPDA,
+      admin: ctx.admin.publicKey,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Withdraw treasury
+export async function withdrawTreasury(
+  ctx: TestContext,
+  amount: number,
+  to: PublicKey
+) {
+  return ctx.program.methods
+    .withdrawTreasury(new anchor.BN(amount), to)
+    .accountsStrict({
+      feeRegistry: ctx.feeRegistryPDA,
+      treasury: ctx.treasuryPDA,
+      to: to,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      admin: ctx.admin.publicKey,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Get treasury balance
+export async function getTreasuryBalance(ctx: TestContext) {
+  const account = await ctx.connection.getAccountInfo(ctx.treasuryPDA);
+  return account ? account.lamports : 0;
+}
+
+// Helper: Get fee registry
+export async function getFeeRegistry(ctx: TestContext) {
+  return ctx.program.account.feeRegistry.fetch(ctx.feeRegistryPDA);
+}
+
+// Helper: Update prefix metadata
+export async function updatePrefixMetadata(
+  ctx: TestContext,
+  prefix: string,
+  owner: Keypair,
+  newUri: string,
+  newHash: number[],
+  intentCategories: number = INTENT_PAYMENT
+) {
+  const ed25519Ix = createEd25519SignatureInstruction(
+    owner,
+    new Uint8Array(newHash)
+  );
+
+  return ctx.program.methods
+    .updatePrefixMetadata(prefix, newUri, newHash, intentCategories)
+    .accountsStrict({
+      owner: owner.publicKey,
+      protocolConfig: ctx.protocolConfigPDA,
+      prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
+      auditLog: deriveAuditLogPDA(ctx.program.programId, prefix),
+      instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+    })
+    .preInstructions([ed25519Ix])
+    .signers([owner])
+    .rpc();
+}
+
+// Helper: Update prefix authority
+export async function updatePrefixAuthority(
+  ctx: TestContext,
+  prefix: string,
+  owner: Keypair,
   authorityKeys: PublicKey[]
 ) {
   return ctx.program.methods
@@ -325,6 +960,7 @@ export async function deactivatePrefix(ctx: TestContext, prefix: string) {
       admin: ctx.admin.publicKey,
       feeRegistry: ctx.feeRegistryPDA,
       prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
+      auditLog: deriveAuditLogPDA(ctx.program.programId, prefix),
     })
     .signers([ctx.admin])
     .rpc();
@@ -338,6 +974,331 @@ export async function reactivatePrefix(ctx: TestContext, prefix: string) {
       admin: ctx.admin.publicKey,
       feeRegistry: ctx.feeRegistryPDA,
       prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
+      auditLog: deriveAuditLogPDA(ctx.program.programId, prefix),
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Owner pauses their own prefix
+export async function pausePrefix(ctx: TestContext, prefix: string, owner: Keypair) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .pausePrefix(prefix)
+    .accountsStrict({
+      owner: owner.publicKey,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+    })
+    .signers([owner])
+    .rpc();
+}
+
+// Helper: Owner resumes their paused prefix
+export async function resumePrefix(ctx: TestContext, prefix: string, owner: Keypair) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .resumePrefix(prefix)
+    .accountsStrict({
+      owner: owner.publicKey,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+    })
+    .signers([owner])
+    .rpc();
+}
+
+// Helper: Any authority key freezes the prefix
+export async function emergencyFreeze(
+  ctx: TestContext,
+  prefix: string,
+  authority: Keypair
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .emergencyFreeze(prefix)
+    .accountsStrict({
+      authority: authority.publicKey,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+    })
+    .signers([authority])
+    .rpc();
+}
+
+// Helper: Owner unfreezes once the freezing key is rotated out
+export async function unfreezePrefix(ctx: TestContext, prefix: string, owner: Keypair) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .unfreezePrefix(prefix)
+    .accountsStrict({
+      owner: owner.publicKey,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+    })
+    .signers([owner])
+    .rpc();
+}
+
+// Helper: List an active prefix for sale
+export async function listPrefix(
+  ctx: TestContext,
+  prefix: string,
+  owner: Keypair,
+  price: number
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .listPrefix(prefix, new anchor.BN(price))
+    .accountsStrict({
+      owner: owner.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      listing: deriveListingPDA(programId, prefix),
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([owner])
+    .rpc();
+}
+
+// Helper: Buy a listed prefix at no more than maxPrice
+export async function buyPrefix(
+  ctx: TestContext,
+  prefix: string,
+  buyer: Keypair,
+  seller: PublicKey,
+  maxPrice: number
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .buyPrefix(prefix, new anchor.BN(maxPrice))
+    .accountsStrict({
+      buyer: buyer.publicKey,
+      seller,
+      feeRegistry: ctx.feeRegistryPDA,
+      protocolConfig: ctx.protocolConfigPDA,
+      treasury: ctx.treasuryPDA,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+      listing: deriveListingPDA(programId, prefix),
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([buyer])
+    .rpc();
+}
+
+// Helper: Remove a listing; the seller or the current owner may sign
+export async function delistPrefix(
+  ctx: TestContext,
+  prefix: string,
+  signer: Keypair,
+  seller: PublicKey
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .delistPrefix(prefix)
+    .accountsStrict({
+      signer: signer.publicKey,
+      seller,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      listing: deriveListingPDA(programId, prefix),
+    })
+    .signers([signer])
+    .rpc();
+}
+
+// Helper: Open an auction for a reserved name
+export async function openAuction(
+  ctx: TestContext,
+  prefix: string,
+  reservePrice: number,
+  minIncrement: number,
+  duration: number,
+  activateOnSettle: boolean = false
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .openAuction(
+      prefix,
+      new anchor.BN(reservePrice),
+      new anchor.BN(minIncrement),
+      new anchor.BN(duration),
+      activateOnSettle
+    )
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      reservedPrefix: deriveSkeletonKeyedPDA(programId, RESERVED_SEED, prefix),
+      auction: deriveAuctionPDA(programId, prefix),
+      skeletonGuard: deriveSkeletonKeyedPDA(programId, SKELETON_SEED, prefix),
+      tombstone: deriveSkeletonKeyedPDA(programId, TOMBSTONE_SEED, prefix),
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Bid on an open auction, refunding the bidder it outbids
+export async function placeBid(
+  ctx: TestContext,
+  prefix: string,
+  bidder: Keypair,
+  amount: number,
+  previousBidder: PublicKey | null = null
+) {
+  return ctx.program.methods
+    .placeBid(prefix, new anchor.BN(amount))
+    .accountsStrict({
+      bidder: bidder.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      auction: deriveAuctionPDA(ctx.program.programId, prefix),
+      previousBidder,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([bidder])
+    .rpc();
+}
+
+// Helper: Settle an ended auction, registering the prefix to the winner
+export async function settleAuction(
+  ctx: TestContext,
+  prefix: string,
+  winner: Keypair,
+  metadataUri: string = "https://example.com/metadata",
+  metadataHash: number[] = Array(32).fill(1),
+  authorityKeys: PublicKey[] = [],
+  intentCategories: number = INTENT_PAYMENT
+) {
+  const programId = ctx.program.programId;
+  const ed25519Ix = createEd25519SignatureInstruction(
+    winner,
+    new Uint8Array(metadataHash)
+  );
+
+  return ctx.program.methods
+    .settleAuction(prefix, metadataUri, metadataHash, authorityKeys, intentCategories)
+    .accountsStrict({
+      winner: winner.publicKey,
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      protocolConfig: ctx.protocolConfigPDA,
+      reviewConfig: ctx.reviewConfigPDA,
+      treasury: ctx.treasuryPDA,
+      auction: deriveAuctionPDA(programId, prefix),
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      reviewState: deriveReviewStatePDA(programId, prefix),
+      skeletonGuard: deriveSkeletonKeyedPDA(programId, SKELETON_SEED, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+      tombstone: deriveSkeletonKeyedPDA(programId, TOMBSTONE_SEED, prefix),
+      instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .preInstructions([ed25519Ix])
+    .signers([winner])
+    .rpc();
+}
+
+// Helper: Cancel an auction; the highest bidder, if any, is refunded
+export async function cancelAuction(
+  ctx: TestContext,
+  prefix: string,
+  bidder: PublicKey | null = null
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .cancelAuction(prefix)
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      auction: deriveAuctionPDA(programId, prefix),
+      skeletonGuard: deriveSkeletonKeyedPDA(programId, SKELETON_SEED, prefix),
+      bidder,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Suspend an active prefix for a limited time
+export async function suspendPrefix(
+  ctx: TestContext,
+  prefix: string,
+  duration: number,
+  reason: any = { phishing: {} },
+  evidenceHash: number[] | null = null
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .suspendPrefix(prefix, new anchor.BN(duration), reason, evidenceHash)
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Lengthen a running suspension
+export async function extendSuspension(
+  ctx: TestContext,
+  prefix: string,
+  additional: number
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .extendSuspension(prefix, new anchor.BN(additional))
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Lift a running suspension early
+export async function endSuspension(ctx: TestContext, prefix: string) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .endSuspension(prefix)
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Revoke a prefix, leaving a tombstone; a null cooldown blocks the name for good
+export async function revokePrefix(
+  ctx: TestContext,
+  prefix: string,
+  owner: PublicKey,
+  reason: string = "Impersonation",
+  cooldown: number | null = null,
+  seller: PublicKey | null = null
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .revokePrefix(prefix, reason, cooldown === null ? null : new anchor.BN(cooldown))
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      owner,
+      feeRegistry: ctx.feeRegistryPDA,
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      reviewState: deriveReviewStatePDA(programId, prefix),
+      skeletonGuard: deriveSkeletonKeyedPDA(programId, SKELETON_SEED, prefix),
+      auditLog: deriveAuditLogPDA(programId, prefix),
+      tombstone: deriveSkeletonKeyedPDA(programId, TOMBSTONE_SEED, prefix),
+      parentAccount: null,
+      claimHolder: null,
+      listing: seller === null ? null : deriveListingPDA(programId, prefix),
+      seller,
+      systemProgram: anchor.web3.SystemProgram.programId,
     })
     .signers([ctx.admin])
     .rpc();
@@ -357,6 +1318,7 @@ export async function recoverPrefixOwnerWithFee(
       feeRegistry: ctx.feeRegistryPDA,
       treasury: ctx.treasuryPDA,
       prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
+      auditLog: deriveAuditLogPDA(ctx.program.programId, prefix),
       systemProgram: anchor.web3.SystemProgram.programId,
     })
     .signers([newOwner, ctx.admin])
@@ -364,21 +1326,309 @@ export async function recoverPrefixOwnerWithFee(
 }
 
 // Helper: Remove verifier
-export async function removeVerifier(ctx: TestContext, verifier: PublicKey) {
+export async function removeVerifier(
+  ctx: TestContext,
+  verifier: PublicKey,
+  claimedPrefix: string | null = null
+) {
   return ctx.program.methods
     .removeVerifier(verifier)
     .accountsStrict({
-      verifiers: ctx.verifiersPDA,
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      verifierRecord: deriveVerifierRecordPDA(ctx.program.programId, verifier),
+      claimedPrefix: claimedPrefix
+        ? await derivePrefixPDA(ctx.program.programId, claimedPrefix)
+        : null,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Migrate a legacy VerifiersList entry to its own record
+export async function migrateVerifier(ctx: TestContext, verifier: PublicKey) {
+  const [verifiersPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from(VERIFIERS_SEED)],
+    ctx.program.programId
+  );
+  return ctx.program.methods
+    .migrateVerifier(verifier)
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
       feeRegistry: ctx.feeRegistryPDA,
+      verifiers: verifiersPDA,
+      verifierRecord: deriveVerifierRecordPDA(ctx.program.programId, verifier),
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Get verifier record
+export async function getVerifierRecord(ctx: TestContext, verifier: PublicKey) {
+  return ctx.program.account.verifierRecord.fetch(
+    deriveVerifierRecordPDA(ctx.program.programId, verifier)
+  );
+}
+
+// Helper: Set review quorum
+export async function setReviewQuorum(
+  ctx: TestContext,
+  defaultQuorum: number,
+  quorumTiers: { minLen: number; maxLen: number; threshold: number }[] = []
+) {
+  return ctx.program.methods
+    .setReviewQuorum(defaultQuorum, quorumTiers)
+    .accountsStrict({
       admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      reviewConfig: ctx.reviewConfigPDA,
     })
     .signers([ctx.admin])
     .rpc();
 }
 
-// Helper: Get verifiers list
-export async function getVerifiersList(ctx: TestContext) {
-  return ctx.program.account.verifiersList.fetch(ctx.verifiersPDA);
+// Helper: Set claim window
+export async function setClaimWindow(ctx: TestContext, claimWindow: number) {
+  return ctx.program.methods
+    .setClaimWindow(new anchor.BN(claimWindow))
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      reviewConfig: ctx.reviewConfigPDA,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Set the appeal bond
+export async function setAppealBond(ctx: TestContext, appealBond: number) {
+  return ctx.program.methods
+    .setAppealBond(new anchor.BN(appealBond))
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      reviewConfig: ctx.reviewConfigPDA,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Set the review window extension policy
+export async function setExpiryExtensionPolicy(
+  ctx: TestContext,
+  needsInfoExtension: number,
+  maxExpiryExtension: number
+) {
+  return ctx.program.methods
+    .setExpiryExtensionPolicy(
+      new anchor.BN(needsInfoExtension),
+      new anchor.BN(maxExpiryExtension)
+    )
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      reviewConfig: ctx.reviewConfigPDA,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Set how long an approval stays trusted
+export async function setVerificationValidity(
+  ctx: TestContext,
+  verificationValidity: number
+) {
+  return ctx.program.methods
+    .setVerificationValidity(new anchor.BN(verificationValidity))
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      reviewConfig: ctx.reviewConfigPDA,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Restrict which prefixes a verifier may review
+export async function setVerifierScope(
+  ctx: TestContext,
+  verifier: PublicKey,
+  scope: {
+    categoryMask: number;
+    minLen: number;
+    maxLen: number;
+    patterns: string[];
+  }
+) {
+  return ctx.program.methods
+    .setVerifierScope(verifier, scope)
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      verifierRecord: deriveVerifierRecordPDA(ctx.program.programId, verifier),
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Reserve or block a prefix
+export async function reservePrefix(
+  ctx: TestContext,
+  prefix: string,
+  kind: any = { blocked: {} },
+  reason: string = "Protected name",
+  claimant: PublicKey | null = null
+) {
+  return ctx.program.methods
+    .reservePrefix(prefix, kind, reason, claimant)
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      protocolConfig: ctx.protocolConfigPDA,
+      reservedPrefix: deriveSkeletonKeyedPDA(
+        ctx.program.programId,
+        RESERVED_SEED,
+        prefix
+      ),
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Remove a prefix from the reserved registry
+export async function releaseReservedPrefix(ctx: TestContext, prefix: string) {
+  return ctx.program.methods
+    .releaseReservedPrefix(prefix)
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      reservedPrefix: deriveSkeletonKeyedPDA(
+        ctx.program.programId,
+        RESERVED_SEED,
+        prefix
+      ),
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Update the prefix rules; omitted fields keep their defaults
+export async function updateProtocolConfig(
+  ctx: TestContext,
+  overrides: Partial<typeof DEFAULT_PROTOCOL_CONFIG> = {}
+) {
+  const config = { ...DEFAULT_PROTOCOL_CONFIG, ...overrides };
+  return ctx.program.methods
+    .updateProtocolConfig(
+      config.minPrefixLen,
+      config.maxPrefixLen,
+      config.allowHyphen,
+      config.allowUnderscore,
+      new anchor.BN(config.maxExpiryDuration),
+      new anchor.BN(config.minReviewWindow)
+    )
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      protocolConfig: ctx.protocolConfigPDA,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Set the sweep reward and rejected grace period
+export async function setSweepPolicy(
+  ctx: TestContext,
+  sweepReward: number,
+  rejectedGracePeriod: number
+) {
+  return ctx.program.methods
+    .setSweepPolicy(new anchor.BN(sweepReward), new anchor.BN(rejectedGracePeriod))
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      protocolConfig: ctx.protocolConfigPDA,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Set the marketplace royalty in basis points
+export async function setRoyalty(ctx: TestContext, royaltyBps: number) {
+  return ctx.program.methods
+    .setRoyalty(royaltyBps)
+    .accountsStrict({
+      admin: ctx.admin.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      protocolConfig: ctx.protocolConfigPDA,
+    })
+    .signers([ctx.admin])
+    .rpc();
+}
+
+// Helper: Claim a pending prefix for review
+export async function claimReview(
+  ctx: TestContext,
+  prefix: string,
+  verifier: Keypair
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .claimReview(prefix)
+    .accountsStrict({
+      verifier: verifier.publicKey,
+      feeRegistry: ctx.feeRegistryPDA,
+      reviewConfig: ctx.reviewConfigPDA,
+      verifierRecord: deriveVerifierRecordPDA(programId, verifier.publicKey),
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+      reviewState: deriveReviewStatePDA(programId, prefix),
+    })
+    .signers([verifier])
+    .rpc();
+}
+
+// Helper: Release a review claim
+export async function releaseReview(
+  ctx: TestContext,
+  prefix: string,
+  verifier: Keypair
+) {
+  const programId = ctx.program.programId;
+  return ctx.program.methods
+    .releaseReview(prefix)
+    .accountsStrict({
+      verifier: verifier.publicKey,
+      verifierRecord: deriveVerifierRecordPDA(programId, verifier.publicKey),
+      prefixAccount: await derivePrefixPDA(programId, prefix),
+    })
+    .signers([verifier])
+    .rpc();
+}
+
+// Helper: Get review state
+export async function getReviewState(ctx: TestContext, prefix: string) {
+  return ctx.program.account.reviewState.fetch(
+    deriveReviewStatePDA(ctx.program.programId, prefix)
+  );
+}
+
+// Helper: Get audit log
+export async function getAuditLog(ctx: TestContext, prefix: string) {
+  return ctx.program.account.auditLog.fetch(
+    deriveAuditLogPDA(ctx.program.programId, prefix)
+  );
+}
+
+// Helper: Get review config
+export async function getReviewConfig(ctx: TestContext) {
+  return ctx.program.account.reviewConfig.fetch(ctx.reviewConfigPDA);
+}
+
+// Helper: Get protocol config
+export async function getProtocolConfig(ctx: TestContext) {
+  return ctx.program.account.protocolConfig.fetch(ctx.protocolConfigPDA);
 }
 
 // Export everything for tests
@@ -390,11 +1640,46 @@ export default {
   initializeProgram,
   addVerifier,
   removeVerifier,
-  getVerifiersList,
+  migrateVerifier,
+  getVerifierRecord,
+  depositVerifierBond,
+  slashVerifier,
+  resignVerifier,
+  withdrawVerifierBond,
+  setCommitPolicy,
+  setVerifierBondPolicy,
+  setReviewQuorum,
+  setClaimWindow,
+  setAppealBond,
+  setExpiryExtensionPolicy,
+  setVerificationValidity,
+  setVerifierScope,
+  reservePrefix,
+  releaseReservedPrefix,
+  updateProtocolConfig,
+  setSweepPolicy,
+  setRoyalty,
+  claimReview,
+  releaseReview,
+  getReviewState,
+  getAuditLog,
+  getReviewConfig,
+  getProtocolConfig,
   submitPrefixWithFee,
+  commitPrefix,
+  revealPrefix,
+  refundCommitment,
+  resubmitPrefix,
+  appealRejection,
+  resolveAppeal,
+  createSubPrefix,
   approvePrefix,
   rejectPrefix,
+  requestPrefixInfo,
+  extendReviewWindow,
+  reverifyPrefix,
   refundPrefixFee,
+  sweepExpiredPrefix,
   updateFee,
   setPause,
   withdrawTreasury,
@@ -404,5 +1689,20 @@ export default {
   updatePrefixAuthority,
   deactivatePrefix,
   reactivatePrefix,
+  pausePrefix,
+  resumePrefix,
+  emergencyFreeze,
+  unfreezePrefix,
+  listPrefix,
+  buyPrefix,
+  delistPrefix,
+  openAuction,
+  placeBid,
+  settleAuction,
+  cancelAuction,
+  suspendPrefix,
+  extendSuspension,
+  endSuspension,
+  revokePrefix,
   recoverPrefixOwnerWithFee,
 };
@@ -0,0 +1,367 @@
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { PrefixSystemClient } from "../../sdk/src/client";
+import {
+  TestContext,
+  airdrop,
+  submitPrefixWithFee,
+  approvePrefix,
+  reverifyPrefix,
+  updatePrefixMetadata,
+  recoverPrefixOwnerWithFee,
+  listPrefix,
+  buyPrefix,
+  delistPrefix,
+  setRoyalty,
+  fetchPrefixAccount,
+  getTreasuryBalance,
+  deriveListingPDA,
+  reservePrefix,
+  openAuction,
+  placeBid,
+  settleAuction,
+  cancelAuction,
+  deriveAuctionPDA,
+  deriveSkeletonKeyedPDA,
+  SKELETON_SEED,
+} from "./helpers/setup";
+import { getSharedTestContext } from "./helpers/shared-setup";
+
+describe("Market Tests", () => {
+  const SOL = 1_000_000_000;
+  let ctx: TestContext;
+  let sdk: PrefixSystemClient;
+  let owner: Keypair;
+  let verifier: Keypair;
+  let buyer: Keypair;
+  let bidder: Keypair;
+
+  before(async () => {
+    const shared = await getSharedTestContext();
+    ctx = shared.ctx;
+    owner = shared.owner;
+    verifier = shared.verifier;
+    sdk = PrefixSystemClient.initForTesting(ctx.connection, shared.admin);
+
+    await airdrop(ctx.provider, owner.publicKey, 10);
+
+    buyer = Keypair.generate();
+    await airdrop(ctx.provider, buyer.publicKey, 10);
+
+    bidder = Keypair.generate();
+    await airdrop(ctx.provider, bidder.publicKey, 10);
+  });
+
+  afterEach(async () => {
+    // Reset market settings after each test
+    try {
+      await setRoyalty(ctx, 0);
+    } catch (error) {
+      // Ignore errors if already at correct state
+    }
+  });
+
+  // Submits and approves a fresh prefix
+  async function activePrefix(tag: string) {
+    const prefix = `${tag}${Date.now().toString().slice(-6)}`.substring(0, 12);
+    await submitPrefixWithFee(ctx, prefix, owner, undefined, undefined, [Keypair.generate().publicKey]);
+    await approvePrefix(ctx, prefix, verifier);
+    return prefix;
+  }
+
+  describe("1️⃣ Marketplace", () => {
+    it("Listing records the price and locks the prefix", async () => {
+      const prefix = await activePrefix("MKL");
+
+      await listPrefix(ctx, prefix, owner, SOL);
+
+      const listing = await ctx.program.account.listing.fetch(
+        deriveListingPDA(ctx.program.programId, prefix)
+      );
+      expect(listing.prefix).to.equal(prefix);
+      expect(listing.seller.toString()).to.equal(owner.publicKey.toString());
+      expect(listing.price.toNumber()).to.equal(SOL);
+      expect((await fetchPrefixAccount(ctx, prefix)).listed).to.be.true;
+
+      try {
+        await updatePrefixMetadata(ctx, prefix, owner, "https://example.com/listed", Array(32).fill(9));
+        expect.fail("Should have failed while listed");
+      } catch (error) {
+        expect(error.message).to.include("PrefixListed");
+      }
+    });
+
+    it("Buying transfers ownership and pays seller and treasury", async () => {
+      const prefix = await activePrefix("MKB");
+      await setRoyalty(ctx, 500);
+      await listPrefix(ctx, prefix, owner, SOL);
+
+      const sellerBefore = await ctx.connection.getBalance(owner.publicKey);
+      const treasuryBefore = await getTreasuryBalance(ctx);
+      await buyPrefix(ctx, prefix, buyer, owner.publicKey, SOL);
+      const sellerAfter = await ctx.connection.getBalance(owner.publicKey);
+      const treasuryAfter = await getTreasuryBalance(ctx);
+
+      expect(treasuryAfter - treasuryBefore).to.equal(SOL * 0.05);
+      // The seller also gets the listing rent back
+      expect(sellerAfter - sellerBefore).to.be.at.least(SOL * 0.95);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.owner.toString()).to.equal(buyer.publicKey.toString());
+      expect(prefixAccount.authorityKeys).to.have.length(0);
+      expect(prefixAccount.listed).to.be.false;
+      expect(prefixAccount.verifiedUntil.toNumber()).to.equal(0);
+      expect(prefixAccount.status).to.deep.equal({ pending: {} });
+      expect(
+        await ctx.connection.getAccountInfo(deriveListingPDA(ctx.program.programId, prefix))
+      ).to.be.null;
+
+      // A single verifier cannot vouch for the new owner outside the review
+      expect(await sdk.isPrefixTrusted(prefix)).to.be.false;
+      try {
+        await reverifyPrefix(ctx, prefix, verifier);
+        expect.fail("Should have failed for a sold prefix under review");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixStatus");
+      }
+
+      await approvePrefix(ctx, prefix, verifier);
+      expect(await sdk.isPrefixTrusted(prefix)).to.be.true;
+    });
+
+    it("Should fail above the buyer's maximum price or for the seller", async () => {
+      const prefix = await activePrefix("MKP");
+      await listPrefix(ctx, prefix, owner, SOL);
+
+      try {
+        await buyPrefix(ctx, prefix, buyer, owner.publicKey, SOL - 1);
+        expect.fail("Should have failed above the maximum price");
+      } catch (error) {
+        expect(error.message).to.include("InvalidListingPrice");
+      }
+
+      try {
+        await buyPrefix(ctx, prefix, owner, owner.publicKey, SOL);
+        expect.fail("Should have failed for the seller");
+      } catch (error) {
+        expect(error.message).to.include("BuyerIsSeller");
+      }
+    });
+
+    it("Seller can delist; strangers cannot", async () => {
+      const prefix = await activePrefix("MKD");
+      await listPrefix(ctx, prefix, owner, SOL);
+
+      try {
+        await delistPrefix(ctx, prefix, buyer, owner.publicKey);
+        expect.fail("Should have failed for a stranger");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedOwnerAction");
+      }
+
+      await delistPrefix(ctx, prefix, owner, owner.publicKey);
+      expect((await fetchPrefixAccount(ctx, prefix)).listed).to.be.false;
+      expect(
+        await ctx.connection.getAccountInfo(deriveListingPDA(ctx.program.programId, prefix))
+      ).to.be.null;
+    });
+
+    it("A listing goes stale when ownership moves", async () => {
+      const prefix = await activePrefix("MKS");
+      const newOwner = Keypair.generate();
+      await airdrop(ctx.provider, newOwner.publicKey, 2);
+      await listPrefix(ctx, prefix, owner, SOL);
+      await recoverPrefixOwnerWithFee(ctx, prefix, newOwner);
+
+      try {
+        await buyPrefix(ctx, prefix, buyer, owner.publicKey, SOL);
+        expect.fail("Should have failed with a stale listing");
+      } catch (error) {
+        expect(error.message).to.include("StaleListing");
+      }
+
+      // The current owner may clear the listing left behind
+      await delistPrefix(ctx, prefix, newOwner, owner.publicKey);
+      expect((await fetchPrefixAccount(ctx, prefix)).listed).to.be.false;
+    });
+
+    it("Should fail to list with a zero price or a non-active prefix", async () => {
+      const prefix = await activePrefix("MKZ");
+      try {
+        await listPrefix(ctx, prefix, owner, 0);
+        expect.fail("Should have failed with a zero price");
+      } catch (error) {
+        expect(error.message).to.include("InvalidListingPrice");
+      }
+
+      const pending = `MKQ${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, pending, owner);
+      try {
+        await listPrefix(ctx, pending, owner, SOL);
+        expect.fail("Should have failed on a pending prefix");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixStatus");
+      }
+    });
+
+    it("Should fail with an oversized royalty or a non-admin signer", async () => {
+      try {
+        await setRoyalty(ctx, 1001);
+        expect.fail("Should have failed with an oversized royalty");
+      } catch (error) {
+        expect(error.message).to.include("InvalidRoyalty");
+      }
+
+      try {
+        await setRoyalty({ ...ctx, admin: buyer }, 100);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("2️⃣ Auctions", () => {
+    const HOUR = 60 * 60;
+
+    // Reserves a fresh name and opens an hour-long auction for it
+    async function auctionedPrefix(tag: string) {
+      const prefix = `${tag}${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await reservePrefix(ctx, prefix, { reserved: {} });
+      await openAuction(ctx, prefix, SOL, SOL / 10, HOUR);
+      return prefix;
+    }
+
+    it("Opening records the terms and holds the name", async () => {
+      const prefix = await auctionedPrefix("AUO");
+
+      const auction = await ctx.program.account.auction.fetch(
+        deriveAuctionPDA(ctx.program.programId, prefix)
+      );
+      expect(auction.prefix).to.equal(prefix);
+      expect(auction.reservePrice.toNumber()).to.equal(SOL);
+      expect(auction.minIncrement.toNumber()).to.equal(SOL / 10);
+      expect(auction.highestBidder).to.be.null;
+      expect(auction.endAt.toNumber() - auction.startAt.toNumber()).to.equal(HOUR);
+      expect(auction.activateOnSettle).to.be.false;
+
+      // The skeleton guard is taken, so nobody can register the name meanwhile
+      try {
+        await submitPrefixWithFee(ctx, prefix, owner);
+        expect.fail("Should have failed while auctioned");
+      } catch (error) {
+        expect(error.message).to.include("already in use");
+      }
+    });
+
+    it("Bids escrow the amount and refund the outbid bidder", async () => {
+      const prefix = await auctionedPrefix("AUB");
+
+      try {
+        await placeBid(ctx, prefix, bidder, SOL - 1);
+        expect.fail("Should have failed below the reserve price");
+      } catch (error) {
+        expect(error.message).to.include("BidTooLow");
+      }
+
+      const bidderBefore = await ctx.connection.getBalance(bidder.publicKey);
+      await placeBid(ctx, prefix, bidder, SOL);
+      expect(bidderBefore - (await ctx.connection.getBalance(bidder.publicKey))).to.equal(SOL);
+
+      try {
+        await placeBid(ctx, prefix, buyer, SOL + SOL / 20, bidder.publicKey);
+        expect.fail("Should have failed below the minimum increment");
+      } catch (error) {
+        expect(error.message).to.include("BidTooLow");
+      }
+
+      try {
+        await placeBid(ctx, prefix, buyer, 2 * SOL);
+        expect.fail("Should have failed without the previous bidder");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPreviousBidder");
+      }
+
+      await placeBid(ctx, prefix, buyer, 2 * SOL, bidder.publicKey);
+      expect(await ctx.connection.getBalance(bidder.publicKey)).to.equal(bidderBefore);
+
+      const auction = await ctx.program.account.auction.fetch(
+        deriveAuctionPDA(ctx.program.programId, prefix)
+      );
+      expect(auction.highestBidder.toString()).to.equal(buyer.publicKey.toString());
+      expect(auction.highestBid.toNumber()).to.equal(2 * SOL);
+    });
+
+    it("Should fail to settle before the auction ends", async () => {
+      const prefix = await auctionedPrefix("AUS");
+      await placeBid(ctx, prefix, bidder, SOL);
+
+      try {
+        await settleAuction(ctx, prefix, bidder);
+        expect.fail("Should have failed before the end");
+      } catch (error) {
+        expect(error.message).to.include("AuctionNotEnded");
+      }
+    });
+
+    it("Admin can cancel an auction without bids", async () => {
+      const prefix = await auctionedPrefix("AUC");
+      await cancelAuction(ctx, prefix);
+
+      const programId = ctx.program.programId;
+      expect(await ctx.connection.getAccountInfo(deriveAuctionPDA(programId, prefix))).to.be.null;
+      expect(
+        await ctx.connection.getAccountInfo(deriveSkeletonKeyedPDA(programId, SKELETON_SEED, prefix))
+      ).to.be.null;
+    });
+
+    it("Should fail to cancel while the winner may still settle", async () => {
+      const prefix = await auctionedPrefix("AUH");
+      await placeBid(ctx, prefix, bidder, SOL);
+
+      try {
+        await cancelAuction(ctx, prefix, bidder.publicKey);
+        expect.fail("Should have failed with bids");
+      } catch (error) {
+        expect(error.message).to.include("AuctionHasBids");
+      }
+    });
+
+    it("Should fail to open for a name not reserved for auction", async () => {
+      const blocked = `AUN${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await reservePrefix(ctx, blocked);
+      try {
+        await openAuction(ctx, blocked, SOL, SOL / 10, HOUR);
+        expect.fail("Should have failed for a blocked name");
+      } catch (error) {
+        expect(error.message).to.include("PrefixNotReservedForAuction");
+      }
+    });
+
+    it("Should fail with invalid terms or a non-admin signer", async () => {
+      const prefix = `AUV${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await reservePrefix(ctx, prefix, { reserved: {} });
+
+      try {
+        await openAuction(ctx, prefix, SOL, SOL / 10, HOUR - 1);
+        expect.fail("Should have failed with a short duration");
+      } catch (error) {
+        expect(error.message).to.include("InvalidAuctionParams");
+      }
+
+      try {
+        await openAuction(ctx, prefix, 0, SOL / 10, HOUR);
+        expect.fail("Should have failed with a zero reserve price");
+      } catch (error) {
+        expect(error.message).to.include("InvalidAuctionParams");
+      }
+
+      try {
+        await openAuction({ ...ctx, admin: buyer }, prefix, SOL, SOL / 10, HOUR);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+});
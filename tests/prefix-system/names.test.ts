@@ -0,0 +1,449 @@
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { PrefixSystemClient } from "../../sdk/src/client";
+import { skeletonPrefix } from "../../sdk/src/utils";
+import {
+  TestContext,
+  airdrop,
+  submitPrefixWithFee,
+  approvePrefix,
+  deactivatePrefix,
+  createSubPrefix,
+  updateProtocolConfig,
+  getProtocolConfig,
+  DEFAULT_PROTOCOL_CONFIG,
+  rejectPrefix,
+  refundPrefixFee,
+  fetchPrefixAccount,
+  reservePrefix,
+  releaseReservedPrefix,
+  updatePrefixMetadata,
+  sweepExpiredPrefix,
+  derivePrefixPDA,
+  deriveSkeletonKeyedPDA,
+  RESERVED_SEED,
+  SKELETON_SEED,
+} from "./helpers/setup";
+import { getSharedTestContext } from "./helpers/shared-setup";
+
+describe("Name Tests", () => {
+  let ctx: TestContext;
+  let owner: Keypair;
+  let claimant: Keypair;
+  let verifier: Keypair;
+  let sdk: PrefixSystemClient;
+
+  before(async () => {
+    const shared = await getSharedTestContext();
+    ctx = shared.ctx;
+    owner = shared.owner;
+    verifier = shared.verifier;
+    sdk = PrefixSystemClient.initForTesting(ctx.connection, shared.admin);
+
+    await airdrop(ctx.provider, owner.publicKey, 10);
+
+    claimant = Keypair.generate();
+    await airdrop(ctx.provider, claimant.publicKey, 10);
+  });
+
+  describe("1️⃣ Reserved Names", () => {
+    it("Blocked names cannot be submitted by anyone", async () => {
+      const prefix = `RBK${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await reservePrefix(ctx, prefix, { blocked: {} }, "Scam reports", claimant.publicKey);
+
+      const reserved = await ctx.program.account.reservedPrefix.fetch(
+        deriveSkeletonKeyedPDA(ctx.program.programId, RESERVED_SEED, prefix)
+      );
+      expect(reserved.prefix).to.equal(prefix);
+      expect(reserved.kind).to.have.property("blocked");
+      expect(reserved.reason).to.equal("Scam reports");
+      // A blocked name has nobody to hand it to
+      expect(reserved.claimant).to.be.null;
+
+      for (const submitter of [owner, claimant]) {
+        try {
+          await submitPrefixWithFee(ctx, prefix, submitter);
+          expect.fail("Should have failed on a blocked name");
+        } catch (error) {
+          expect(error.message).to.include("PrefixBlocked");
+        }
+      }
+    });
+
+    it("Reserved names only accept their claimant", async () => {
+      const prefix = `RCL${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await reservePrefix(ctx, prefix, { reserved: {} }, "Brand holder", claimant.publicKey);
+
+      try {
+        await submitPrefixWithFee(ctx, prefix, owner);
+        expect.fail("Should have failed for a non-claimant");
+      } catch (error) {
+        expect(error.message).to.include("PrefixReserved");
+      }
+
+      await submitPrefixWithFee(ctx, prefix, claimant);
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.owner.toString()).to.equal(claimant.publicKey.toString());
+    });
+
+    it("Released names can be submitted again", async () => {
+      const prefix = `RRL${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await reservePrefix(ctx, prefix);
+      await releaseReservedPrefix(ctx, prefix);
+
+      const reservedInfo = await ctx.connection.getAccountInfo(
+        deriveSkeletonKeyedPDA(ctx.program.programId, RESERVED_SEED, prefix)
+      );
+      expect(reservedInfo).to.be.null;
+
+      await submitPrefixWithFee(ctx, prefix, owner);
+      expect((await fetchPrefixAccount(ctx, prefix)).status).to.have.property("pending");
+    });
+
+    it("Should fail with an invalid name or a long reason", async () => {
+      const prefix = `RL#${Date.now().toString().slice(-6)}`.substring(0, 12);
+      try {
+        await reservePrefix(ctx, prefix);
+        expect.fail("Should have failed with an invalid character");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixFormat");
+      }
+
+      try {
+        await reservePrefix(ctx, prefix.replace("#", "C"), { blocked: {} }, "R".repeat(101));
+        expect.fail("Should have failed with a long reason");
+      } catch (error) {
+        expect(error.message).to.include("ReservationReasonTooLong");
+      }
+    });
+
+    it("Should fail when non-admin reserves or releases", async () => {
+      const prefix = `RNA${Date.now().toString().slice(-6)}`.substring(0, 12);
+      try {
+        await reservePrefix({ ...ctx, admin: claimant }, prefix);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+
+      await reservePrefix(ctx, prefix);
+      try {
+        await releaseReservedPrefix({ ...ctx, admin: claimant }, prefix);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("2️⃣ Confusable Names", () => {
+    it("Skeletons collapse confusable characters and drop separators", () => {
+      expect(skeletonPrefix("G00GLE")).to.equal("GOOGIE");
+      expect(skeletonPrefix("PAY1")).to.equal(skeletonPrefix("PAYL"));
+      expect(skeletonPrefix("ACME-PAY")).to.equal(skeletonPrefix("ACMEPAY"));
+      expect(skeletonPrefix("B8S5")).to.equal("BBSS");
+    });
+
+    it("Registration claims the skeleton for the prefix", async () => {
+      const prefix = `CFG${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      const guard = await ctx.program.account.skeletonGuard.fetch(
+        deriveSkeletonKeyedPDA(ctx.program.programId, SKELETON_SEED, prefix)
+      );
+      expect(guard.prefix).to.equal(prefix);
+    });
+
+    it("Should fail to register a confusable variant of a taken prefix", async () => {
+      const suffix = Date.now().toString().slice(-6);
+      await submitPrefixWithFee(ctx, `GOOG${suffix}`, claimant);
+
+      for (const variant of [`G00G${suffix}`, `GO0G${suffix}`]) {
+        try {
+          await submitPrefixWithFee(ctx, variant, owner);
+          expect.fail("Should have failed with a confusable prefix");
+        } catch (error) {
+          expect(error.message).to.include("already in use");
+        }
+      }
+    });
+
+    it("Reservations cover confusable variants", async () => {
+      const suffix = Date.now().toString().slice(-6);
+      await reservePrefix(ctx, `BLOK${suffix}`);
+
+      try {
+        await submitPrefixWithFee(ctx, `BL0K${suffix}`, owner);
+        expect.fail("Should have failed with a blocked skeleton");
+      } catch (error) {
+        expect(error.message).to.include("PrefixBlocked");
+      }
+    });
+
+    it("A refunded prefix frees its skeleton", async () => {
+      const suffix = Date.now().toString().slice(-6);
+      const original = `FREE${suffix}`;
+      const variant = `FR3E${suffix}`;
+      await submitPrefixWithFee(ctx, original, owner);
+      await rejectPrefix(ctx, original, verifier);
+      await refundPrefixFee(ctx, original, owner);
+
+      await submitPrefixWithFee(ctx, variant, claimant);
+      const guard = await ctx.program.account.skeletonGuard.fetch(
+        deriveSkeletonKeyedPDA(ctx.program.programId, SKELETON_SEED, variant)
+      );
+      expect(guard.prefix).to.equal(variant);
+    });
+  });
+
+  describe("3️⃣ Sub-Prefixes", () => {
+    // Short parents leave room for labels within the 12 character limit
+    async function activeParent(letter: string) {
+      const parent = `${letter}${Date.now().toString().slice(-3)}`;
+      await submitPrefixWithFee(ctx, parent, owner);
+      await approvePrefix(ctx, parent, verifier);
+      return parent;
+    }
+
+    it("Parent owner can create an active child for another owner", async () => {
+      const parent = await activeParent("H");
+      const child = `${parent}-PAY`;
+
+      await createSubPrefix(ctx, parent, "PAY", owner, claimant.publicKey);
+
+      const childAccount = await fetchPrefixAccount(ctx, child);
+      const parentAccount = await fetchPrefixAccount(ctx, parent);
+      expect(childAccount.status).to.have.property("active");
+      expect(childAccount.prefix).to.equal(child);
+      expect(childAccount.owner.toString()).to.equal(claimant.publicKey.toString());
+      expect(childAccount.depth).to.equal(1);
+      expect(childAccount.feePaid.toNumber()).to.equal(0);
+      expect(childAccount.verifiedUntil.toString()).to.equal(
+        parentAccount.verifiedUntil.toString()
+      );
+      expect(parentAccount.subPrefixCount).to.equal(1);
+      expect(await sdk.isPrefixTrusted(child)).to.be.true;
+    });
+
+    it("Children lose trust with their parent", async () => {
+      const parent = await activeParent("J");
+      const child = `${parent}-OPS`;
+      await createSubPrefix(ctx, parent, "OPS", owner);
+
+      await deactivatePrefix(ctx, parent);
+
+      expect((await fetchPrefixAccount(ctx, child)).status).to.have.property("active");
+      expect(await sdk.isPrefixTrusted(child)).to.be.false;
+    });
+
+    it("Nesting stops at the maximum depth", async () => {
+      const parent = await activeParent("K");
+      const child = `${parent}-A`;
+      const grandchild = `${child}-B`;
+      await createSubPrefix(ctx, parent, "A", owner);
+      await createSubPrefix(ctx, child, "B", owner, owner.publicKey, [parent]);
+      expect((await fetchPrefixAccount(ctx, grandchild)).depth).to.equal(2);
+
+      try {
+        await createSubPrefix(ctx, grandchild, "C", owner, owner.publicKey, [parent, child]);
+        expect.fail("Should have failed past the maximum depth");
+      } catch (error) {
+        expect(error.message).to.include("SubPrefixDepthExceeded");
+      }
+    });
+
+    it("Should fail without the grandparent account", async () => {
+      const parent = await activeParent("M");
+      const child = `${parent}-A`;
+      await createSubPrefix(ctx, parent, "A", owner);
+
+      try {
+        await createSubPrefix(ctx, child, "B", owner);
+        expect.fail("Should have failed without the ancestor chain");
+      } catch (error) {
+        expect(error.message).to.include("MissingAncestorAccount");
+      }
+    });
+
+    it("Should fail under an untrusted parent or for a non-owner", async () => {
+      const pendingParent = `N${Date.now().toString().slice(-3)}`;
+      await submitPrefixWithFee(ctx, pendingParent, owner);
+      try {
+        await createSubPrefix(ctx, pendingParent, "PAY", owner);
+        expect.fail("Should have failed under a pending parent");
+      } catch (error) {
+        expect(error.message).to.include("ParentPrefixNotTrusted");
+      }
+
+      const parent = await activeParent("P");
+      try {
+        await createSubPrefix(ctx, parent, "PAY", claimant);
+        expect.fail("Should have failed for a non-owner");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedOwnerAction");
+      }
+    });
+
+    it("An expired child can be swept and frees its slot in the parent", async () => {
+      const parent = await activeParent("W");
+      const child = `${parent}-A`;
+      await createSubPrefix(ctx, parent, "A", owner);
+
+      // A metadata change sends the child back to review, which it then lets expire
+      await updateProtocolConfig(ctx, { minReviewWindow: 1, maxExpiryDuration: 2 });
+      await updatePrefixMetadata(ctx, child, owner, "https://example.com/v2", Array(32).fill(2));
+      await updateProtocolConfig(ctx);
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      try {
+        await sweepExpiredPrefix(ctx, child, claimant, owner.publicKey);
+        expect.fail("Should have failed without the parent account");
+      } catch (error) {
+        expect(error.message).to.include("MissingAncestorAccount");
+      }
+
+      await sweepExpiredPrefix(ctx, child, claimant, owner.publicKey, parent);
+      expect(
+        await ctx.connection.getAccountInfo(await derivePrefixPDA(ctx.program.programId, child))
+      ).to.be.null;
+      expect((await fetchPrefixAccount(ctx, parent)).subPrefixCount).to.equal(0);
+    });
+
+    it("Should fail with an invalid label", async () => {
+      const parent = await activeParent("U");
+
+      for (const label of ["P_Y", "TOOLONGLABEL"]) {
+        try {
+          await createSubPrefix(ctx, parent, label, owner);
+          expect.fail("Should have failed with an invalid label");
+        } catch (error) {
+          expect(error.message).to.include("InvalidSubPrefixLabel");
+        }
+      }
+    });
+  });
+
+  describe("4️⃣ Prefix Rules", () => {
+    afterEach(async () => {
+      // Reset prefix rules after each test
+      try {
+        await updateProtocolConfig(ctx);
+      } catch (error) {
+        // Ignore errors if already at correct state
+      }
+    });
+
+    it("Protocol config starts from the built-in rules", async () => {
+      const config = await getProtocolConfig(ctx);
+      expect(config.minPrefixLen).to.equal(DEFAULT_PROTOCOL_CONFIG.minPrefixLen);
+      expect(config.maxPrefixLen).to.equal(DEFAULT_PROTOCOL_CONFIG.maxPrefixLen);
+      expect(config.allowHyphen).to.be.false;
+      expect(config.allowUnderscore).to.be.false;
+      expect(config.maxExpiryDuration.toNumber()).to.equal(DEFAULT_PROTOCOL_CONFIG.maxExpiryDuration);
+      expect(config.minReviewWindow.toNumber()).to.equal(DEFAULT_PROTOCOL_CONFIG.minReviewWindow);
+    });
+
+    it("Length bounds are enforced on submission", async () => {
+      await updateProtocolConfig(ctx, { minPrefixLen: 5, maxPrefixLen: 8 });
+
+      for (const prefix of [
+        `W${Date.now().toString().slice(-3)}`,
+        `WL${Date.now().toString().slice(-7)}`,
+      ]) {
+        try {
+          await submitPrefixWithFee(ctx, prefix, owner);
+          expect.fail("Should have failed outside the length bounds");
+        } catch (error) {
+          expect(error.message).to.include("InvalidPrefixFormat");
+        }
+      }
+
+      const prefix = `WOK${Date.now().toString().slice(-5)}`;
+      await submitPrefixWithFee(ctx, prefix, owner);
+      expect((await fetchPrefixAccount(ctx, prefix)).status).to.have.property("pending");
+    });
+
+    it("Separators are only accepted once enabled", async () => {
+      const hyphenated = `HY${Date.now().toString().slice(-4)}-CD`;
+      const underscored = `US${Date.now().toString().slice(-4)}_CD`;
+
+      for (const prefix of [hyphenated, underscored]) {
+        try {
+          await submitPrefixWithFee(ctx, prefix, owner);
+          expect.fail("Should have failed with a disabled separator");
+        } catch (error) {
+          expect(error.message).to.include("InvalidPrefixFormat");
+        }
+      }
+
+      await updateProtocolConfig(ctx, { allowHyphen: true, allowUnderscore: true });
+      await submitPrefixWithFee(ctx, hyphenated, owner);
+      await submitPrefixWithFee(ctx, underscored, owner);
+      expect((await fetchPrefixAccount(ctx, hyphenated)).status).to.have.property("pending");
+      expect((await fetchPrefixAccount(ctx, underscored)).status).to.have.property("pending");
+    });
+
+    it("Should fail when a hyphenated name poses as a sub-prefix", async () => {
+      const root = `RT${Date.now().toString().slice(-4)}`;
+      await submitPrefixWithFee(ctx, root, owner);
+      await updateProtocolConfig(ctx, { allowHyphen: true });
+
+      try {
+        await submitPrefixWithFee(ctx, `${root}-PAY`, claimant);
+        expect.fail("Should have failed with a registered root");
+      } catch (error) {
+        expect(error.message).to.include("PrefixRootRegistered");
+      }
+    });
+
+    it("Should fail when the root is a confusable of a registered prefix or reserved", async () => {
+      const suffix = Date.now().toString().slice(-4);
+      await submitPrefixWithFee(ctx, `RO${suffix}`, owner);
+      await updateProtocolConfig(ctx, { allowHyphen: true });
+
+      try {
+        await submitPrefixWithFee(ctx, `R0${suffix}-PAY`, claimant);
+        expect.fail("Should have failed with a confusable root");
+      } catch (error) {
+        expect(error.message).to.include("PrefixRootRegistered");
+      }
+
+      // A reserved root is only open to its claimant
+      const reserved = `RV${suffix}`;
+      await reservePrefix(ctx, reserved, { reserved: {} }, "Brand", owner.publicKey);
+      try {
+        await submitPrefixWithFee(ctx, `${reserved}-PAY`, claimant);
+        expect.fail("Should have failed with a reserved root");
+      } catch (error) {
+        expect(error.message).to.include("PrefixReserved");
+      }
+      await submitPrefixWithFee(ctx, `${reserved}-PAY`, owner);
+    });
+
+    it("Should fail with invalid rules or a non-admin signer", async () => {
+      const invalidConfigs = [
+        { minPrefixLen: 0 },
+        { minPrefixLen: 8, maxPrefixLen: 6 },
+        { maxPrefixLen: 13 },
+        { minReviewWindow: 0 },
+        { maxExpiryDuration: 60, minReviewWindow: 120 },
+      ];
+
+      for (const overrides of invalidConfigs) {
+        try {
+          await updateProtocolConfig(ctx, overrides);
+          expect.fail("Should have failed with invalid rules");
+        } catch (error) {
+          expect(error.message).to.include("InvalidProtocolConfig");
+        }
+      }
+
+      try {
+        await updateProtocolConfig({ ...ctx, admin: claimant });
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+});
@@ -1,9 +1,5 @@
 import { expect } from "chai";
-import {
-  Keypair,
-  SystemProgram,
-  SYSVAR_INSTRUCTIONS_PUBKEY,
-} from "@solana/web3.js";
+import { Keypair } from "@solana/web3.js";
 import {
   airdrop,
   submitPrefixWithFee,
@@ -20,7 +16,8 @@ import {
   reactivatePrefix,
   recoverPrefixOwnerWithFee,
   createEd25519SignatureInstruction,
-  derivePrefixPDA,
+  submitAccounts,
+  INTENT_PAYMENT,
   TestContext,
 } from "./helpers/setup";
 import { getSharedTestContext } from "./helpers/shared-setup";
@@ -249,7 +246,7 @@ describe("Prefix System Tests", () => {
         await approvePrefix(ctx, prefix, nonVerifier);
         expect.fail("Should have failed with unauthorized verifier");
       } catch (error) {
-        expect(error.message).to.include("UnauthorizedVerifier");
+        expect(error.message).to.include("AccountNotInitialized");
       }
     });
 
@@ -319,7 +316,7 @@ describe("Prefix System Tests", () => {
         await rejectPrefix(ctx, prefix, nonVerifier);
         expect.fail("Should have failed with unauthorized verifier");
       } catch (error) {
-        expect(error.message).to.include("UnauthorizedVerifier");
+        expect(error.message).to.include("AccountNotInitialized");
       }
     });
 
@@ -1055,15 +1052,17 @@ describe("Prefix System Tests", () => {
 
       // Submit with non-signer owner but valid Ed25519 pre-instruction
       await ctx.program.methods
-        .submitPrefixWithFee(prefix, "https://test.com", metadataHash, [])
-        .accountsStrict({
-          owner: nonSignerOwner.publicKey,
-          feeRegistry: ctx.feeRegistryPDA,
-          treasury: ctx.treasuryPDA,
-          prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
-          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
-          systemProgram: SystemProgram.programId,
-        })
+        .submitPrefixWithFee(
+          prefix,
+          "https://test.com",
+          metadataHash,
+          [],
+          INTENT_PAYMENT,
+          null
+        )
+        .accountsStrict(
+          await submitAccounts(ctx, prefix, nonSignerOwner.publicKey)
+        )
         .preInstructions([ed25519Ix])
         .signers([nonSignerOwner]) // nonSignerOwner pays the fee and signs the Ed25519
         .rpc();
@@ -1083,15 +1082,17 @@ describe("Prefix System Tests", () => {
 
       try {
         await ctx.program.methods
-          .submitPrefixWithFee(prefix, "https://test.com", metadataHash, [])
-          .accountsStrict({
-            owner: nonSignerOwner.publicKey,
-            feeRegistry: ctx.feeRegistryPDA,
-            treasury: ctx.treasuryPDA,
-            prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
-            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
-            systemProgram: SystemProgram.programId,
-          })
+          .submitPrefixWithFee(
+            prefix,
+            "https://test.com",
+            metadataHash,
+            [],
+            INTENT_PAYMENT,
+            null
+          )
+          .accountsStrict(
+            await submitAccounts(ctx, prefix, nonSignerOwner.publicKey)
+          )
           .signers([]) // Owner is not a signer, should fail due to payer constraint
           .rpc();
         expect.fail("Should have failed without owner as signer");
@@ -1116,15 +1117,17 @@ describe("Prefix System Tests", () => {
 
       try {
         await ctx.program.methods
-          .submitPrefixWithFee(prefix, "https://test.com", metadataHash, [])
-          .accountsStrict({
-            owner: nonSignerOwner.publicKey,
-            feeRegistry: ctx.feeRegistryPDA,
-            treasury: ctx.treasuryPDA,
-            prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
-            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
-            systemProgram: SystemProgram.programId,
-          })
+          .submitPrefixWithFee(
+            prefix,
+            "https://test.com",
+            metadataHash,
+            [],
+            INTENT_PAYMENT,
+            null
+          )
+          .accountsStrict(
+            await submitAccounts(ctx, prefix, nonSignerOwner.publicKey)
+          )
           .preInstructions([ed25519Ix])
           .signers([nonSignerOwner])
           .rpc();
@@ -1151,15 +1154,17 @@ describe("Prefix System Tests", () => {
 
       try {
         await ctx.program.methods
-          .submitPrefixWithFee(prefix, "https://test.com", metadataHash, [])
-          .accountsStrict({
-            owner: nonSignerOwner.publicKey,
-            feeRegistry: ctx.feeRegistryPDA,
-            treasury: ctx.treasuryPDA,
-            prefixAccount: await derivePrefixPDA(ctx.program.programId, prefix),
-            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
-            systemProgram: SystemProgram.programId,
-          })
+          .submitPrefixWithFee(
+            prefix,
+            "https://test.com",
+            metadataHash,
+            [],
+            INTENT_PAYMENT,
+            null
+          )
+          .accountsStrict(
+            await submitAccounts(ctx, prefix, nonSignerOwner.publicKey)
+          )
           .preInstructions([ed25519Ix])
           .signers([nonSignerOwner])
           .rpc();
@@ -0,0 +1,939 @@
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import {
+  TestContext,
+  airdrop,
+  submitPrefixWithFee,
+  resubmitPrefix,
+  appealRejection,
+  resolveAppeal,
+  setAppealBond,
+  deriveAppealPDA,
+  getTreasuryBalance,
+  DEFAULT_APPEAL_BOND,
+  approvePrefix,
+  rejectPrefix,
+  requestPrefixInfo,
+  extendReviewWindow,
+  updatePrefixMetadata,
+  setExpiryExtensionPolicy,
+  sweepExpiredPrefix,
+  setSweepPolicy,
+  updateProtocolConfig,
+  DEFAULT_SWEEP_REWARD,
+  DEFAULT_PROTOCOL_CONFIG,
+  DEFAULT_REJECTED_GRACE_PERIOD,
+  DEFAULT_NEEDS_INFO_EXTENSION,
+  DEFAULT_MAX_EXPIRY_EXTENSION,
+  addVerifier,
+  removeVerifier,
+  setReviewQuorum,
+  setClaimWindow,
+  claimReview,
+  releaseReview,
+  getVerifierRecord,
+  derivePrefixPDA,
+  DEFAULT_CLAIM_WINDOW,
+  getReviewState,
+  getReviewConfig,
+  getFeeRegistry,
+  updateFee,
+  fetchPrefixAccount,
+} from "./helpers/setup";
+import { getSharedTestContext } from "./helpers/shared-setup";
+
+describe("Review Tests", () => {
+  let ctx: TestContext;
+  let owner: Keypair;
+  let verifier: Keypair;
+  let verifier2: Keypair;
+  let verifier3: Keypair;
+
+  before(async () => {
+    const shared = await getSharedTestContext();
+    ctx = shared.ctx;
+    owner = shared.owner;
+    verifier = shared.verifier;
+
+    await airdrop(ctx.provider, owner.publicKey, 10);
+
+    verifier2 = Keypair.generate();
+    verifier3 = Keypair.generate();
+    await addVerifier(ctx, verifier2.publicKey, "second reviewer");
+    await addVerifier(ctx, verifier3.publicKey, "third reviewer");
+  });
+
+  afterEach(async () => {
+    // Reset review settings after each test
+    try {
+      await setReviewQuorum(ctx, 1, []);
+      await setClaimWindow(ctx, DEFAULT_CLAIM_WINDOW);
+    } catch (error) {
+      // Ignore errors if already at correct state
+    }
+  });
+
+  describe("1️⃣ Review Quorum", () => {
+    it("Admin can set the default quorum and length tiers", async () => {
+      await setReviewQuorum(ctx, 2, [{ minLen: 3, maxLen: 4, threshold: 3 }]);
+
+      const reviewConfig = await getReviewConfig(ctx);
+      expect(reviewConfig.defaultQuorum).to.equal(2);
+      expect(reviewConfig.quorumTiers).to.have.length(1);
+      expect(reviewConfig.quorumTiers[0].threshold).to.equal(3);
+    });
+
+    it("Should fail when non-admin sets the quorum", async () => {
+      const nonAdmin = Keypair.generate();
+      await airdrop(ctx.provider, nonAdmin.publicKey, 1);
+
+      try {
+        await setReviewQuorum({ ...ctx, admin: nonAdmin }, 2);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+
+    it("Should fail with a zero or oversized quorum", async () => {
+      try {
+        await setReviewQuorum(ctx, 0);
+        expect.fail("Should have failed with zero quorum");
+      } catch (error) {
+        expect(error.message).to.include("InvalidQuorum");
+      }
+
+      try {
+        await setReviewQuorum(ctx, 1, [{ minLen: 5, maxLen: 3, threshold: 2 }]);
+        expect.fail("Should have failed with inverted tier bounds");
+      } catch (error) {
+        expect(error.message).to.include("InvalidQuorum");
+      }
+    });
+
+    it("Prefix stays Pending until the quorum of approvals is reached", async () => {
+      const prefix = `QRA${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await setReviewQuorum(ctx, 2);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      await approvePrefix(ctx, prefix, verifier);
+      let prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("pending");
+
+      const reviewState = await getReviewState(ctx, prefix);
+      expect(reviewState.approvals.map((v) => v.toString())).to.deep.equal([
+        verifier.publicKey.toString(),
+      ]);
+
+      await approvePrefix(ctx, prefix, verifier2, Array(32).fill(2), [
+        verifier.publicKey,
+      ]);
+      prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("active");
+      expect(prefixAccount.decidedBy.toString()).to.equal(
+        verifier2.publicKey.toString()
+      );
+    });
+
+    it("Prefix is rejected once the quorum of rejections is reached", async () => {
+      const prefix = `QRR${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await setReviewQuorum(ctx, 2);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      await rejectPrefix(ctx, prefix, verifier);
+      let prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("pending");
+
+      await rejectPrefix(ctx, prefix, verifier2, "Invalid metadata", { other: {} }, null, [
+        verifier.publicKey,
+      ]);
+      prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("rejected");
+    });
+
+    it("Should fail when an earlier voter's record is not passed", async () => {
+      const prefix = `QRV${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await setReviewQuorum(ctx, 2);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await approvePrefix(ctx, prefix, verifier);
+
+      try {
+        await approvePrefix(ctx, prefix, verifier2);
+        expect.fail("Should have failed without the earlier voter's record");
+      } catch (error) {
+        expect(error.message).to.include("VoterRecordRequired");
+      }
+
+      // Nothing was recorded, so the same verifier can vote again with the record
+      await approvePrefix(ctx, prefix, verifier2, Array(32).fill(2), [verifier.publicKey]);
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("active");
+    });
+
+    it("Votes of removed verifiers are dropped before tallying", async () => {
+      const prefix = `QRP${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const departed = Keypair.generate();
+      await addVerifier(ctx, departed.publicKey, "departing reviewer");
+      await setReviewQuorum(ctx, 2);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      await approvePrefix(ctx, prefix, departed);
+      await removeVerifier(ctx, departed.publicKey);
+      await approvePrefix(ctx, prefix, verifier, Array(32).fill(2), [departed.publicKey]);
+
+      expect((await fetchPrefixAccount(ctx, prefix)).status).to.have.property("pending");
+      const reviewState = await getReviewState(ctx, prefix);
+      expect(reviewState.approvals.map((v) => v.toString())).to.deep.equal([
+        verifier.publicKey.toString(),
+      ]);
+    });
+
+    it("Changing metadata while Pending starts the votes over", async () => {
+      const prefix = `QRM${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await setReviewQuorum(ctx, 2);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await approvePrefix(ctx, prefix, verifier);
+      const round = (await fetchPrefixAccount(ctx, prefix)).reviewRound;
+
+      await updatePrefixMetadata(ctx, prefix, owner, "https://example.com/changed", Array(32).fill(7));
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.reviewRound).to.equal(round + 1);
+
+      // The approval cast on the old content no longer counts
+      await approvePrefix(ctx, prefix, verifier2);
+      expect((await fetchPrefixAccount(ctx, prefix)).status).to.have.property("pending");
+      const reviewState = await getReviewState(ctx, prefix);
+      expect(reviewState.approvals.map((v) => v.toString())).to.deep.equal([
+        verifier2.publicKey.toString(),
+      ]);
+    });
+
+    it("Should fail when the same verifier votes twice in a round", async () => {
+      const prefix = `QRD${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await setReviewQuorum(ctx, 2);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await approvePrefix(ctx, prefix, verifier);
+
+      try {
+        await rejectPrefix(ctx, prefix, verifier);
+        expect.fail("Should have failed with a second vote");
+      } catch (error) {
+        expect(error.message).to.include("AlreadyVoted");
+      }
+    });
+
+    it("Length tiers override the default quorum", async () => {
+      const shortPrefix = `Q${Date.now().toString().slice(-2)}`;
+      const longPrefix = `QRT${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await setReviewQuorum(ctx, 1, [{ minLen: 3, maxLen: 3, threshold: 2 }]);
+
+      await submitPrefixWithFee(ctx, shortPrefix, owner);
+      await submitPrefixWithFee(ctx, longPrefix, owner);
+
+      await approvePrefix(ctx, shortPrefix, verifier);
+      await approvePrefix(ctx, longPrefix, verifier);
+
+      expect((await fetchPrefixAccount(ctx, shortPrefix)).status).to.have.property("pending");
+      expect((await fetchPrefixAccount(ctx, longPrefix)).status).to.have.property("active");
+    });
+  });
+
+  describe("2️⃣ Review Claims", () => {
+    // Fresh verifiers keep the shared ones free of lingering claims
+    async function newVerifier(label: string) {
+      const fresh = Keypair.generate();
+      await airdrop(ctx.provider, fresh.publicKey, 1);
+      await addVerifier(ctx, fresh.publicKey, label);
+      return fresh;
+    }
+
+    it("Claiming records the holder on the prefix and the verifier record", async () => {
+      const prefix = `CLM${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const holder = await newVerifier("claim holder");
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      await claimReview(ctx, prefix, holder);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.claimedBy.toString()).to.equal(holder.publicKey.toString());
+      expect(prefixAccount.claimExpiresAt.toNumber()).to.be.at.most(
+        prefixAccount.expiryAt.toNumber()
+      );
+
+      const record = await getVerifierRecord(ctx, holder.publicKey);
+      const prefixPDA = await derivePrefixPDA(ctx.program.programId, prefix);
+      expect(record.claimedPrefix.toString()).to.equal(prefixPDA.toString());
+      expect(record.claimExpiresAt.toNumber()).to.equal(
+        prefixAccount.claimExpiresAt.toNumber()
+      );
+    });
+
+    it("Should block other verifiers while a claim is live", async () => {
+      const prefix = `CLB${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const holder = await newVerifier("claim holder");
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await claimReview(ctx, prefix, holder);
+
+      try {
+        await claimReview(ctx, prefix, verifier);
+        expect.fail("Should have failed with an existing claim");
+      } catch (error) {
+        expect(error.message).to.include("ReviewAlreadyClaimed");
+      }
+
+      try {
+        await approvePrefix(ctx, prefix, verifier);
+        expect.fail("Should have failed to vote on a claimed prefix");
+      } catch (error) {
+        expect(error.message).to.include("ReviewAlreadyClaimed");
+      }
+
+      await releaseReview(ctx, prefix, holder);
+    });
+
+    it("Voting by the holder releases the claim", async () => {
+      const prefix = `CLV${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const holder = await newVerifier("claim holder");
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await claimReview(ctx, prefix, holder);
+
+      await approvePrefix(ctx, prefix, holder);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("active");
+      expect(prefixAccount.claimedBy).to.be.null;
+      const record = await getVerifierRecord(ctx, holder.publicKey);
+      expect(record.claimedPrefix).to.be.null;
+    });
+
+    it("Only the holder can release a claim", async () => {
+      const prefix = `CLR${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const holder = await newVerifier("claim holder");
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await claimReview(ctx, prefix, holder);
+
+      try {
+        await releaseReview(ctx, prefix, verifier);
+        expect.fail("Should have failed to release another verifier's claim");
+      } catch (error) {
+        expect(error.message).to.include("ReviewNotClaimed");
+      }
+
+      await releaseReview(ctx, prefix, holder);
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.claimedBy).to.be.null;
+
+      // Once released anyone may pick the prefix up
+      await claimReview(ctx, prefix, verifier);
+      await releaseReview(ctx, prefix, verifier);
+    });
+
+    it("Should fail when a verifier claims a second prefix", async () => {
+      const prefixA = `CLA${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const prefixB = `CLC${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const holder = await newVerifier("claim holder");
+      await submitPrefixWithFee(ctx, prefixA, owner);
+      await submitPrefixWithFee(ctx, prefixB, owner);
+      await claimReview(ctx, prefixA, holder);
+
+      try {
+        await claimReview(ctx, prefixB, holder);
+        expect.fail("Should have failed with a live claim elsewhere");
+      } catch (error) {
+        expect(error.message).to.include("VerifierClaimActive");
+      }
+
+      await releaseReview(ctx, prefixA, holder);
+      await claimReview(ctx, prefixB, holder);
+      await releaseReview(ctx, prefixB, holder);
+    });
+
+    it("Claims lapse after the claim window", async () => {
+      const prefix = `CLE${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const holder = await newVerifier("claim holder");
+      await setClaimWindow(ctx, 1);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await claimReview(ctx, prefix, holder);
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      await approvePrefix(ctx, prefix, verifier);
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("active");
+    });
+
+    it("Removing a verifier with a live claim requires and clears the claimed prefix", async () => {
+      const prefix = `CLX${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const holder = await newVerifier("claim holder");
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await claimReview(ctx, prefix, holder);
+
+      try {
+        await removeVerifier(ctx, holder.publicKey);
+        expect.fail("Should have failed without the claimed prefix");
+      } catch (error) {
+        expect(error.message).to.include("ClaimedPrefixRequired");
+      }
+
+      await removeVerifier(ctx, holder.publicKey, prefix);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.claimedBy).to.be.null;
+      const record = await getVerifierRecord(ctx, holder.publicKey);
+      expect(record.active).to.be.false;
+      expect(record.claimedPrefix).to.be.null;
+    });
+
+    it("Admin can set the claim window within bounds", async () => {
+      await setClaimWindow(ctx, 60 * 60);
+      expect((await getReviewConfig(ctx)).claimWindow.toNumber()).to.equal(60 * 60);
+
+      for (const claimWindow of [0, 7 * 24 * 60 * 60 + 1]) {
+        try {
+          await setClaimWindow(ctx, claimWindow);
+          expect.fail("Should have failed with an invalid claim window");
+        } catch (error) {
+          expect(error.message).to.include("InvalidClaimWindow");
+        }
+      }
+
+      const nonAdmin = Keypair.generate();
+      await airdrop(ctx.provider, nonAdmin.publicKey, 1);
+      try {
+        await setClaimWindow({ ...ctx, admin: nonAdmin }, 60 * 60);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("3️⃣ Rejection Reasons", () => {
+    it("Stores the reason code and evidence hash on the prefix", async () => {
+      const prefix = `RJC${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const evidence = Array(32).fill(7);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      await rejectPrefix(ctx, prefix, verifier, "Looks like a known brand", { trademark: {} }, evidence);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("rejected");
+      expect(prefixAccount.rejectionReason).to.have.property("trademark");
+      expect(prefixAccount.rejectionEvidence).to.deep.equal(evidence);
+      expect(prefixAccount.decidedBy.toString()).to.equal(verifier.publicKey.toString());
+    });
+
+    it("Evidence hash is optional", async () => {
+      const prefix = `RJN${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      await rejectPrefix(ctx, prefix, verifier, "", { hashMismatch: {} });
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.rejectionReason).to.have.property("hashMismatch");
+      expect(prefixAccount.rejectionEvidence).to.be.null;
+    });
+
+    it("Should fail with a rejection note longer than 200 characters", async () => {
+      const prefix = `RJL${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      try {
+        await rejectPrefix(ctx, prefix, verifier, "N".repeat(201), { other: {} });
+        expect.fail("Should have failed with a long note");
+      } catch (error) {
+        expect(error.message).to.include("RejectionNoteTooLong");
+      }
+    });
+
+    it("A pending prefix carries no rejection reason", async () => {
+      const prefix = `RJP${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.rejectionReason).to.be.null;
+      expect(prefixAccount.rejectionEvidence).to.be.null;
+    });
+  });
+
+  describe("4️⃣ Resubmission", () => {
+    it("Owner can resubmit a rejected prefix in place", async () => {
+      const prefix = `RSB${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await rejectPrefix(ctx, prefix, verifier, "Wrong hash", { hashMismatch: {} }, Array(32).fill(9));
+
+      const before = await fetchPrefixAccount(ctx, prefix);
+      await resubmitPrefix(ctx, prefix, owner);
+
+      const after = await fetchPrefixAccount(ctx, prefix);
+      expect(after.status).to.have.property("pending");
+      expect(after.metadataUri).to.equal("https://example.com/metadata-v2");
+      expect(after.metadataHash).to.deep.equal(Array(32).fill(3));
+      expect(after.resubmissionCount).to.equal(before.resubmissionCount + 1);
+      expect(after.reviewRound).to.equal(before.reviewRound + 1);
+      expect(after.rejectionReason).to.be.null;
+      expect(after.rejectionEvidence).to.be.null;
+      expect(after.decidedBy).to.be.null;
+      expect(after.feePaid.toString()).to.equal(before.feePaid.toString());
+      expect(after.createdAt.toString()).to.equal(before.createdAt.toString());
+    });
+
+    it("Verifiers can vote again in the new review round", async () => {
+      const prefix = `RSV${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await rejectPrefix(ctx, prefix, verifier);
+      await resubmitPrefix(ctx, prefix, owner);
+
+      await approvePrefix(ctx, prefix, verifier);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("active");
+      const reviewState = await getReviewState(ctx, prefix);
+      expect(reviewState.rejections).to.have.length(0);
+    });
+
+    it("Owner only tops up the fee difference", async () => {
+      const prefix = `RST${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const originalFee = (await getFeeRegistry(ctx)).currentFee.toNumber();
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await rejectPrefix(ctx, prefix, verifier);
+
+      await updateFee(ctx, originalFee + 1_000_000);
+      try {
+        await resubmitPrefix(ctx, prefix, owner);
+      } finally {
+        await updateFee(ctx, originalFee);
+      }
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.feePaid.toNumber()).to.equal(originalFee + 1_000_000);
+    });
+
+    it("Should fail to resubmit a prefix that is not rejected", async () => {
+      const prefix = `RSP${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      try {
+        await resubmitPrefix(ctx, prefix, owner);
+        expect.fail("Should have failed on a pending prefix");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixStatus");
+      }
+    });
+
+    it("Should fail when a non-owner resubmits", async () => {
+      const prefix = `RSO${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const stranger = Keypair.generate();
+      await airdrop(ctx.provider, stranger.publicKey, 1);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await rejectPrefix(ctx, prefix, verifier);
+
+      try {
+        await resubmitPrefix(ctx, prefix, stranger);
+        expect.fail("Should have failed with a non-owner");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedOwnerAction");
+      }
+    });
+  });
+
+  describe("5️⃣ Appeals", () => {
+    it("Owner can appeal a rejection and the bond is escrowed", async () => {
+      const prefix = `APF${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await rejectPrefix(ctx, prefix, verifier);
+
+      await appealRejection(ctx, prefix, owner, Array(32).fill(5));
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("underAppeal");
+
+      const appeal = await ctx.program.account.appeal.fetch(
+        deriveAppealPDA(ctx.program.programId, prefix)
+      );
+      expect(appeal.owner.toString()).to.equal(owner.publicKey.toString());
+      expect(appeal.bond.toNumber()).to.equal(DEFAULT_APPEAL_BOND);
+      expect(appeal.evidenceHash).to.deep.equal(Array(32).fill(5));
+    });
+
+    it("Admin upholding an appeal activates the prefix and returns the bond", async () => {
+      const prefix = `APU${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await rejectPrefix(ctx, prefix, verifier, "Trademark", { trademark: {} });
+      await appealRejection(ctx, prefix, owner);
+
+      const ownerBefore = await ctx.connection.getBalance(owner.publicKey);
+      await resolveAppeal(ctx, prefix, ctx.admin, owner.publicKey, true);
+      const ownerAfter = await ctx.connection.getBalance(owner.publicKey);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("active");
+      expect(prefixAccount.rejectionReason).to.be.null;
+      expect(prefixAccount.appealEvidence).to.deep.equal(Array(32).fill(4));
+      expect(prefixAccount.decidedBy.toString()).to.equal(ctx.admin.publicKey.toString());
+      expect(ownerAfter - ownerBefore).to.be.greaterThan(DEFAULT_APPEAL_BOND);
+
+      const appealInfo = await ctx.connection.getAccountInfo(
+        deriveAppealPDA(ctx.program.programId, prefix)
+      );
+      expect(appealInfo).to.be.null;
+    });
+
+    it("Denying an appeal forfeits the bond to the treasury", async () => {
+      const prefix = `APD${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await rejectPrefix(ctx, prefix, verifier);
+      await appealRejection(ctx, prefix, owner);
+
+      const treasuryBefore = await getTreasuryBalance(ctx);
+      await resolveAppeal(ctx, prefix, verifier2, owner.publicKey, false);
+      const treasuryAfter = await getTreasuryBalance(ctx);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("rejected");
+      expect(prefixAccount.decidedBy.toString()).to.equal(verifier2.publicKey.toString());
+      expect(treasuryAfter - treasuryBefore).to.equal(DEFAULT_APPEAL_BOND);
+    });
+
+    it("Should fail when a rejecting verifier resolves the appeal", async () => {
+      const prefix = `APC${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await rejectPrefix(ctx, prefix, verifier);
+      await appealRejection(ctx, prefix, owner);
+
+      try {
+        await resolveAppeal(ctx, prefix, verifier, owner.publicKey, true);
+        expect.fail("Should have failed with a conflicted resolver");
+      } catch (error) {
+        expect(error.message).to.include("ConflictedAppealResolver");
+      }
+    });
+
+    it("Should fail to appeal a prefix that is not rejected", async () => {
+      const prefix = `APP${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      try {
+        await appealRejection(ctx, prefix, owner);
+        expect.fail("Should have failed on a pending prefix");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixStatus");
+      }
+    });
+
+    it("Admin can change the appeal bond", async () => {
+      const prefix = `APB${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await setAppealBond(ctx, 0);
+      try {
+        expect((await getReviewConfig(ctx)).appealBond.toNumber()).to.equal(0);
+
+        await submitPrefixWithFee(ctx, prefix, owner);
+        await rejectPrefix(ctx, prefix, verifier);
+        await appealRejection(ctx, prefix, owner);
+
+        const appeal = await ctx.program.account.appeal.fetch(
+          deriveAppealPDA(ctx.program.programId, prefix)
+        );
+        expect(appeal.bond.toNumber()).to.equal(0);
+      } finally {
+        await setAppealBond(ctx, DEFAULT_APPEAL_BOND);
+      }
+
+      const nonAdmin = Keypair.generate();
+      await airdrop(ctx.provider, nonAdmin.publicKey, 1);
+      try {
+        await setAppealBond({ ...ctx, admin: nonAdmin }, 0);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("6️⃣ Needs Info", () => {
+    const DAY = 24 * 60 * 60;
+
+    it("Requesting info moves the prefix to NeedsInfo and extends its expiry", async () => {
+      const prefix = `NIR${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      const before = await fetchPrefixAccount(ctx, prefix);
+
+      await requestPrefixInfo(ctx, prefix, verifier, Array(32).fill(6));
+
+      const after = await fetchPrefixAccount(ctx, prefix);
+      expect(after.status).to.have.property("needsInfo");
+      expect(after.infoRequestHash).to.deep.equal(Array(32).fill(6));
+      expect(after.expiryAt.toNumber()).to.equal(
+        before.expiryAt.toNumber() + DEFAULT_NEEDS_INFO_EXTENSION
+      );
+      expect(after.expiryExtensionUsed.toNumber()).to.equal(DEFAULT_NEEDS_INFO_EXTENSION);
+    });
+
+    it("Should fail to vote while waiting on the owner", async () => {
+      const prefix = `NIV${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await requestPrefixInfo(ctx, prefix, verifier);
+
+      try {
+        await approvePrefix(ctx, prefix, verifier2);
+        expect.fail("Should have failed while NeedsInfo");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixStatus");
+      }
+    });
+
+    it("Owner's metadata update resumes the review in a new round", async () => {
+      const prefix = `NIU${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await requestPrefixInfo(ctx, prefix, verifier);
+      const before = await fetchPrefixAccount(ctx, prefix);
+
+      await updatePrefixMetadata(ctx, prefix, owner, "https://example.com/answer", Array(32).fill(8));
+
+      const after = await fetchPrefixAccount(ctx, prefix);
+      expect(after.status).to.have.property("pending");
+      expect(after.reviewRound).to.equal(before.reviewRound + 1);
+      expect(after.infoRequestHash).to.deep.equal(Array(32).fill(0));
+      expect(after.expiryAt.toNumber()).to.equal(before.expiryAt.toNumber());
+
+      await approvePrefix(ctx, prefix, verifier);
+      expect((await fetchPrefixAccount(ctx, prefix)).status).to.have.property("active");
+    });
+
+    it("Extensions stop at the lifetime extension budget", async () => {
+      const prefix = `NIB${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await setExpiryExtensionPolicy(ctx, 3 * DAY, 4 * DAY);
+      try {
+        await submitPrefixWithFee(ctx, prefix, owner);
+        const submitted = await fetchPrefixAccount(ctx, prefix);
+
+        await requestPrefixInfo(ctx, prefix, verifier);
+        await updatePrefixMetadata(ctx, prefix, owner, "https://example.com/answer", Array(32).fill(8));
+        await requestPrefixInfo(ctx, prefix, verifier);
+
+        const after = await fetchPrefixAccount(ctx, prefix);
+        expect(after.expiryExtensionUsed.toNumber()).to.equal(4 * DAY);
+        expect(after.expiryAt.toNumber()).to.equal(submitted.expiryAt.toNumber() + 4 * DAY);
+
+        // With the budget spent, another request could only wipe the votes
+        await updatePrefixMetadata(ctx, prefix, owner, "https://example.com/again", Array(32).fill(9));
+        try {
+          await requestPrefixInfo(ctx, prefix, verifier);
+          expect.fail("Should have failed with the extension budget spent");
+        } catch (error) {
+          expect(error.message).to.include("ExtensionBudgetExhausted");
+        }
+      } finally {
+        await setExpiryExtensionPolicy(ctx, DEFAULT_NEEDS_INFO_EXTENSION, DEFAULT_MAX_EXPIRY_EXTENSION);
+      }
+    });
+
+    it("Should fail with an invalid extension policy", async () => {
+      try {
+        await setExpiryExtensionPolicy(ctx, 5 * DAY, 2 * DAY);
+        expect.fail("Should have failed with a per-request extension above the budget");
+      } catch (error) {
+        expect(error.message).to.include("InvalidExtensionPolicy");
+      }
+
+      const nonAdmin = Keypair.generate();
+      await airdrop(ctx.provider, nonAdmin.publicKey, 1);
+      try {
+        await setExpiryExtensionPolicy({ ...ctx, admin: nonAdmin }, DAY, DAY);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("7️⃣ Sweeping", () => {
+    let caller: Keypair;
+
+    before(async () => {
+      caller = Keypair.generate();
+      await airdrop(ctx.provider, caller.publicKey, 1);
+    });
+
+    afterEach(async () => {
+      // Reset sweep settings after each test
+      try {
+        await setSweepPolicy(ctx, DEFAULT_SWEEP_REWARD, DEFAULT_REJECTED_GRACE_PERIOD);
+        await updateProtocolConfig(ctx);
+      } catch (error) {
+        // Ignore errors if already at correct state
+      }
+    });
+
+    it("Anyone can sweep an expired pending prefix for a reward", async () => {
+      const prefix = `SWP${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await updateProtocolConfig(ctx, { minReviewWindow: 1 });
+      await submitPrefixWithFee(ctx, prefix, owner, undefined, undefined, [], undefined, 1);
+      const feePaid = (await fetchPrefixAccount(ctx, prefix)).feePaid.toNumber();
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      const callerBefore = await ctx.connection.getBalance(caller.publicKey);
+      const ownerBefore = await ctx.connection.getBalance(owner.publicKey);
+      await sweepExpiredPrefix(ctx, prefix, caller, owner.publicKey);
+      const callerAfter = await ctx.connection.getBalance(caller.publicKey);
+      const ownerAfter = await ctx.connection.getBalance(owner.publicKey);
+
+      const reward = Math.min(DEFAULT_SWEEP_REWARD, feePaid);
+      expect(callerAfter - callerBefore).to.equal(reward);
+      // The owner also gets the rent of the closed accounts back
+      expect(ownerAfter - ownerBefore).to.be.greaterThan(feePaid - reward);
+
+      const prefixPDA = await derivePrefixPDA(ctx.program.programId, prefix);
+      expect(await ctx.connection.getAccountInfo(prefixPDA)).to.be.null;
+    });
+
+    it("Rejected prefixes become sweepable after the grace period", async () => {
+      const prefix = `SWR${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await rejectPrefix(ctx, prefix, verifier);
+
+      try {
+        await sweepExpiredPrefix(ctx, prefix, caller, owner.publicKey);
+        expect.fail("Should have failed within the grace period");
+      } catch (error) {
+        expect(error.message).to.include("SweepNotAllowed");
+      }
+
+      await setSweepPolicy(ctx, 0, 0);
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      const callerBefore = await ctx.connection.getBalance(caller.publicKey);
+      await sweepExpiredPrefix(ctx, prefix, caller, owner.publicKey);
+      expect(await ctx.connection.getBalance(caller.publicKey)).to.equal(callerBefore);
+    });
+
+    it("Should fail to sweep a live pending or active prefix", async () => {
+      const pending = `SWL${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const active = `SWA${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, pending, owner);
+      await submitPrefixWithFee(ctx, active, owner);
+      await approvePrefix(ctx, active, verifier);
+
+      for (const prefix of [pending, active]) {
+        try {
+          await sweepExpiredPrefix(ctx, prefix, caller, owner.publicKey);
+          expect.fail("Should have failed on a live prefix");
+        } catch (error) {
+          expect(error.message).to.include("SweepNotAllowed");
+        }
+      }
+    });
+
+    it("Should fail when the refund is routed to someone other than the owner", async () => {
+      const prefix = `SWO${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      try {
+        await sweepExpiredPrefix(ctx, prefix, caller, caller.publicKey);
+        expect.fail("Should have failed with the wrong owner");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedOwnerAction");
+      }
+    });
+
+    it("Should fail with a negative grace period or a non-admin signer", async () => {
+      try {
+        await setSweepPolicy(ctx, DEFAULT_SWEEP_REWARD, -1);
+        expect.fail("Should have failed with a negative grace period");
+      } catch (error) {
+        expect(error.message).to.include("InvalidProtocolConfig");
+      }
+
+      try {
+        await setSweepPolicy({ ...ctx, admin: caller }, 0, 0);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("8️⃣ Review Window", () => {
+    const DAY = 24 * 60 * 60;
+
+    it("Submitters can pick a review window within the configured bounds", async () => {
+      const prefix = `RWS${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const before = Math.floor(Date.now() / 1000);
+      await submitPrefixWithFee(ctx, prefix, owner, undefined, undefined, [], undefined, 2 * DAY);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.expiryAt.toNumber()).to.be.closeTo(before + 2 * DAY, 60);
+    });
+
+    it("The maximum window applies when none is requested", async () => {
+      const prefix = `RWD${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.expiryAt.toNumber() - prefixAccount.createdAt.toNumber()).to.equal(
+        DEFAULT_PROTOCOL_CONFIG.maxExpiryDuration
+      );
+    });
+
+    it("Should fail with a review window outside the bounds", async () => {
+      const prefix = `RWB${Date.now().toString().slice(-6)}`.substring(0, 12);
+
+      try {
+        await submitPrefixWithFee(ctx, prefix, owner, undefined, undefined, [], undefined, 60);
+        expect.fail("Should have failed with a short window");
+      } catch (error) {
+        expect(error.message).to.include("ReviewWindowTooShort");
+      }
+
+      try {
+        await submitPrefixWithFee(
+          ctx, prefix, owner, undefined, undefined, [], undefined,
+          DEFAULT_PROTOCOL_CONFIG.maxExpiryDuration + 1
+        );
+        expect.fail("Should have failed with a long window");
+      } catch (error) {
+        expect(error.message).to.include("ReviewWindowTooLong");
+      }
+    });
+
+    it("Verifiers can extend a review within the extension budget", async () => {
+      const prefix = `RWE${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner, undefined, undefined, [], undefined, 2 * DAY);
+      const before = await fetchPrefixAccount(ctx, prefix);
+
+      await extendReviewWindow(ctx, prefix, verifier, DAY);
+
+      const after = await fetchPrefixAccount(ctx, prefix);
+      expect(after.expiryAt.toNumber()).to.equal(before.expiryAt.toNumber() + DAY);
+      expect(after.expiryExtensionUsed.toNumber()).to.equal(DAY);
+
+      try {
+        await extendReviewWindow(ctx, prefix, verifier, DEFAULT_MAX_EXPIRY_EXTENSION);
+        expect.fail("Should have failed past the extension budget");
+      } catch (error) {
+        expect(error.message).to.include("ReviewWindowTooLong");
+      }
+
+      try {
+        await extendReviewWindow(ctx, prefix, verifier, 0);
+        expect.fail("Should have failed with a zero extension");
+      } catch (error) {
+        expect(error.message).to.include("ReviewWindowTooShort");
+      }
+    });
+
+    it("Should fail to extend a decided prefix", async () => {
+      const prefix = `RWA${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await approvePrefix(ctx, prefix, verifier);
+
+      try {
+        await extendReviewWindow(ctx, prefix, verifier, DAY);
+        expect.fail("Should have failed on an active prefix");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixStatus");
+      }
+    });
+  });
+});
@@ -10,6 +10,7 @@ import {
   validateMetadataHash 
 } from "../../sdk/src/validation";
 import { PrefixSystemClientError } from "../../sdk/src/errors";
+import { RejectionReason } from "../../sdk/src/types";
 
 describe("SDK Tests", () => {
   let sdk: PrefixSystemClient;
@@ -103,13 +104,13 @@ describe("SDK Tests", () => {
       const newVerifier = Keypair.generate();
       await airdrop(newVerifier.publicKey, 1);
       
-      const tx = await sdk.addVerifier(admin.publicKey, newVerifier.publicKey);
+      const tx = await sdk.addVerifier(admin.publicKey, newVerifier.publicKey, "sdk verifier");
       await provider.sendAndConfirm(tx, [admin]);
 
-      const verifiersList = await sdk.getVerifiersList();
-      expect(verifiersList.verifiers.map((v) => v.toString())).to.include(
-        newVerifier.publicKey.toString()
-      );
+      const record = await sdk.getVerifierRecord(newVerifier.publicKey);
+      expect(record).to.not.be.null;
+      expect(record!.active).to.be.true;
+      expect(record!.label).to.equal("sdk verifier");
     });
 
     it("Should remove verifier", async () => {
@@ -124,10 +125,9 @@ describe("SDK Tests", () => {
       const tx = await sdk.removeVerifier(admin.publicKey, verifierToRemove.publicKey);
       await provider.sendAndConfirm(tx, [admin]);
 
-      const verifiersList = await sdk.getVerifiersList();
-      expect(verifiersList.verifiers.map((v) => v.toString())).to.not.include(
-        verifierToRemove.publicKey.toString()
-      );
+      const record = await sdk.getVerifierRecord(verifierToRemove.publicKey);
+      expect(record).to.not.be.null;
+      expect(record!.active).to.be.false;
     });
   });
 
@@ -239,11 +239,18 @@ describe("SDK Tests", () => {
 
     it("Should reject prefix", async () => {
       const reason = "Invalid metadata";
-      const tx = await sdk.rejectPrefix(verifier.publicKey, testPrefix, reason);
+      const tx = await sdk.rejectPrefix(
+        verifier.publicKey,
+        testPrefix,
+        RejectionReason.SchemaInvalid,
+        null,
+        reason
+      );
       await provider.sendAndConfirm(tx, [verifier]);
 
       const prefixAccount = await sdk.getPrefixAccount(testPrefix);
       expect(prefixAccount.status).to.deep.equal({ rejected: {} });
+      expect(prefixAccount.rejectionReason).to.deep.equal(RejectionReason.SchemaInvalid);
     });
   });
 
@@ -491,6 +498,9 @@ describe("SDK Tests", () => {
         expect(() => validatePrefix("A1B2C3D4E5F6")).to.not.throw();
         expect(() => validatePrefix("XYZ")).to.not.throw();
         expect(() => validatePrefix("123")).to.not.throw();
+        expect(() => validatePrefix("TEST-123")).to.not.throw();
+        expect(() => validatePrefix("TEST_123")).to.not.throw();
+        expect(() => validatePrefix("AB-CD-EF")).to.not.throw();
       });
 
       it("Should reject prefixes that are too short", () => {
@@ -506,8 +516,9 @@ describe("SDK Tests", () => {
 
       it("Should reject prefixes with invalid characters", () => {
         expect(() => validatePrefix("abc")).to.throw(PrefixSystemClientError, "Invalid prefix: must be alphanumeric and between 3 and 12 characters");
-        expect(() => validatePrefix("TEST-123")).to.throw(PrefixSystemClientError, "Invalid prefix: must be alphanumeric and between 3 and 12 characters");
-        expect(() => validatePrefix("TEST_123")).to.throw(PrefixSystemClientError, "Invalid prefix: must be alphanumeric and between 3 and 12 characters");
+        expect(() => validatePrefix("-TEST")).to.throw(PrefixSystemClientError, "Invalid prefix: must be alphanumeric and between 3 and 12 characters");
+        expect(() => validatePrefix("TEST-")).to.throw(PrefixSystemClientError, "Invalid prefix: must be alphanumeric and between 3 and 12 characters");
+        expect(() => validatePrefix("TEST--123")).to.throw(PrefixSystemClientError, "Invalid prefix: must be alphanumeric and between 3 and 12 characters");
         expect(() => validatePrefix("TEST.123")).to.throw(PrefixSystemClientError, "Invalid prefix: must be alphanumeric and between 3 and 12 characters");
         expect(() => validatePrefix("TEST 123")).to.throw(PrefixSystemClientError, "Invalid prefix: must be alphanumeric and between 3 and 12 characters");
         expect(() => validatePrefix("TEST@123")).to.throw(PrefixSystemClientError, "Invalid prefix: must be alphanumeric and between 3 and 12 characters");
@@ -0,0 +1,522 @@
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { PrefixSystemClient } from "../../sdk/src/client";
+import {
+  TestContext,
+  airdrop,
+  submitPrefixWithFee,
+  approvePrefix,
+  reverifyPrefix,
+  pausePrefix,
+  resumePrefix,
+  deactivatePrefix,
+  reactivatePrefix,
+  updatePrefixMetadata,
+  emergencyFreeze,
+  unfreezePrefix,
+  updatePrefixAuthority,
+  suspendPrefix,
+  extendSuspension,
+  endSuspension,
+  setVerificationValidity,
+  fetchPrefixAccount,
+  revokePrefix,
+  listPrefix,
+  derivePrefixPDA,
+  deriveAuditLogPDA,
+  deriveListingPDA,
+  deriveSkeletonKeyedPDA,
+  TOMBSTONE_SEED,
+  DEFAULT_VERIFICATION_VALIDITY,
+} from "./helpers/setup";
+import { getSharedTestContext } from "./helpers/shared-setup";
+
+const I64_MAX = "9223372036854775807";
+const DAY = 24 * 60 * 60;
+
+describe("Status Tests", () => {
+  let ctx: TestContext;
+  let admin: Keypair;
+  let sdk: PrefixSystemClient;
+  let owner: Keypair;
+  let verifier: Keypair;
+
+  before(async () => {
+    const shared = await getSharedTestContext();
+    ctx = shared.ctx;
+    owner = shared.owner;
+    verifier = shared.verifier;
+    admin = shared.admin;
+    sdk = PrefixSystemClient.initForTesting(ctx.connection, admin);
+
+    await airdrop(ctx.provider, owner.publicKey, 10);
+  });
+
+  afterEach(async () => {
+    // Reset status settings after each test
+    try {
+      await setVerificationValidity(ctx, DEFAULT_VERIFICATION_VALIDITY);
+    } catch (error) {
+      // Ignore errors if already at correct state
+    }
+  });
+
+  // Submits and approves a fresh prefix
+  async function activePrefix(tag: string) {
+    const prefix = `${tag}${Date.now().toString().slice(-6)}`.substring(0, 12);
+    await submitPrefixWithFee(ctx, prefix, owner);
+    await approvePrefix(ctx, prefix, verifier);
+    return prefix;
+  }
+
+  describe("1️⃣ Verification Validity", () => {
+    it("Approval sets verified_until one validity period out", async () => {
+      const prefix = `VUA${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      const before = Math.floor(Date.now() / 1000);
+      await approvePrefix(ctx, prefix, verifier);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.verifiedUntil.toNumber()).to.be.closeTo(
+        before + DEFAULT_VERIFICATION_VALIDITY,
+        60
+      );
+      expect(await sdk.isPrefixTrusted(prefix)).to.be.true;
+    });
+
+    it("A zero validity keeps approvals trusted indefinitely", async () => {
+      const prefix = `VUZ${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await setVerificationValidity(ctx, 0);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await approvePrefix(ctx, prefix, verifier);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.verifiedUntil.toString()).to.equal(I64_MAX);
+    });
+
+    it("A lapsed verification is untrusted until re-verified", async () => {
+      const prefix = `VUL${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await setVerificationValidity(ctx, 1);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await approvePrefix(ctx, prefix, verifier);
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+      expect(await sdk.isPrefixTrusted(prefix)).to.be.false;
+      // The status itself stays Active; only trust lapses
+      expect((await fetchPrefixAccount(ctx, prefix)).status).to.have.property("active");
+
+      await setVerificationValidity(ctx, DEFAULT_VERIFICATION_VALIDITY);
+      await reverifyPrefix(ctx, prefix, verifier);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.verifiedUntil.toNumber()).to.be.greaterThan(
+        Math.floor(Date.now() / 1000)
+      );
+      expect(await sdk.isPrefixTrusted(prefix)).to.be.true;
+    });
+
+    it("Should fail to re-verify a prefix that is not active", async () => {
+      const prefix = `VUP${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      try {
+        await reverifyPrefix(ctx, prefix, verifier);
+        expect.fail("Should have failed on a pending prefix");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixStatus");
+      }
+    });
+
+    it("Should fail with a negative validity or a non-admin signer", async () => {
+      try {
+        await setVerificationValidity(ctx, -1);
+        expect.fail("Should have failed with a negative validity");
+      } catch (error) {
+        expect(error.message).to.include("InvalidVerificationValidity");
+      }
+
+      const nonAdmin = Keypair.generate();
+      await airdrop(ctx.provider, nonAdmin.publicKey, 1);
+      try {
+        await setVerificationValidity({ ...ctx, admin: nonAdmin }, 60);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("2️⃣ Suspension", () => {
+    it("Suspension records its reason and lifts trust without changing status", async () => {
+      const prefix = await activePrefix("SPA");
+      const before = Math.floor(Date.now() / 1000);
+
+      await suspendPrefix(ctx, prefix, DAY, { impersonation: {} }, Array(32).fill(3));
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("active");
+      expect(prefixAccount.suspendedUntil.toNumber()).to.be.closeTo(before + DAY, 60);
+      expect(prefixAccount.suspensionReason).to.have.property("impersonation");
+      expect(prefixAccount.suspensionEvidence).to.deep.equal(Array(32).fill(3));
+      expect(prefixAccount.suspendedBy.toString()).to.equal(admin.publicKey.toString());
+      expect(await sdk.isPrefixTrusted(prefix)).to.be.false;
+
+      try {
+        await suspendPrefix(ctx, prefix, DAY);
+        expect.fail("Should have failed while already suspended");
+      } catch (error) {
+        expect(error.message).to.include("PrefixAlreadySuspended");
+      }
+    });
+
+    it("Admin can extend and end a suspension", async () => {
+      const prefix = await activePrefix("SPE");
+      await suspendPrefix(ctx, prefix, DAY);
+      const suspended = await fetchPrefixAccount(ctx, prefix);
+
+      await extendSuspension(ctx, prefix, DAY);
+      const extended = await fetchPrefixAccount(ctx, prefix);
+      expect(extended.suspendedUntil.toNumber()).to.equal(
+        suspended.suspendedUntil.toNumber() + DAY
+      );
+
+      try {
+        await extendSuspension(ctx, prefix, 30 * DAY);
+        expect.fail("Should have failed past the maximum suspension");
+      } catch (error) {
+        expect(error.message).to.include("InvalidSuspensionDuration");
+      }
+
+      await endSuspension(ctx, prefix);
+      const ended = await fetchPrefixAccount(ctx, prefix);
+      expect(ended.suspendedUntil.toNumber()).to.equal(0);
+      expect(ended.suspensionReason).to.be.null;
+      expect(await sdk.isPrefixTrusted(prefix)).to.be.true;
+    });
+
+    it("Suspensions lapse on their own", async () => {
+      const prefix = await activePrefix("SPL");
+      await suspendPrefix(ctx, prefix, 1);
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      expect(await sdk.isPrefixTrusted(prefix)).to.be.true;
+      try {
+        await endSuspension(ctx, prefix);
+        expect.fail("Should have failed after the suspension lapsed");
+      } catch (error) {
+        expect(error.message).to.include("PrefixNotSuspended");
+      }
+    });
+
+    it("Should fail with an invalid duration or on a non-active prefix", async () => {
+      const prefix = await activePrefix("SPD");
+      for (const duration of [0, 30 * DAY + 1]) {
+        try {
+          await suspendPrefix(ctx, prefix, duration);
+          expect.fail("Should have failed with an invalid duration");
+        } catch (error) {
+          expect(error.message).to.include("InvalidSuspensionDuration");
+        }
+      }
+
+      const pending = `SPP${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, pending, owner);
+      try {
+        await suspendPrefix(ctx, pending, DAY);
+        expect.fail("Should have failed on a pending prefix");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixStatus");
+      }
+    });
+
+    it("Should fail when non-admin suspends", async () => {
+      const prefix = await activePrefix("SPN");
+
+      try {
+        await suspendPrefix({ ...ctx, admin: owner }, prefix, DAY);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("3️⃣ Owner Pause", () => {
+    it("Owner can pause and resume their prefix", async () => {
+      const prefix = await activePrefix("OPR");
+
+      await pausePrefix(ctx, prefix, owner);
+      expect((await fetchPrefixAccount(ctx, prefix)).status).to.have.property("ownerPaused");
+      expect(await sdk.isPrefixTrusted(prefix)).to.be.false;
+
+      await resumePrefix(ctx, prefix, owner);
+      expect((await fetchPrefixAccount(ctx, prefix)).status).to.have.property("active");
+      expect(await sdk.isPrefixTrusted(prefix)).to.be.true;
+    });
+
+    it("Should fail to update metadata while paused", async () => {
+      const prefix = await activePrefix("OPM");
+      await pausePrefix(ctx, prefix, owner);
+
+      try {
+        await updatePrefixMetadata(ctx, prefix, owner, "https://example.com/paused", Array(32).fill(9));
+        expect.fail("Should have failed while paused");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixStatus");
+      }
+    });
+
+    it("Owner cannot resume a prefix the admin deactivated", async () => {
+      const prefix = await activePrefix("OPD");
+      await pausePrefix(ctx, prefix, owner);
+      await deactivatePrefix(ctx, prefix);
+
+      try {
+        await resumePrefix(ctx, prefix, owner);
+        expect.fail("Should have failed on an inactive prefix");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixStatus");
+      }
+    });
+
+    it("Should fail when a non-owner pauses or resumes", async () => {
+      const prefix = await activePrefix("OPN");
+      const stranger = Keypair.generate();
+      await airdrop(ctx.provider, stranger.publicKey, 1);
+
+      try {
+        await pausePrefix(ctx, prefix, stranger);
+        expect.fail("Should have failed for a non-owner");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedOwnerAction");
+      }
+
+      await pausePrefix(ctx, prefix, owner);
+      try {
+        await resumePrefix(ctx, prefix, stranger);
+        expect.fail("Should have failed for a non-owner");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedOwnerAction");
+      }
+    });
+
+    it("Should fail to pause a prefix that is not active", async () => {
+      const prefix = `OPP${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+
+      try {
+        await pausePrefix(ctx, prefix, owner);
+        expect.fail("Should have failed on a pending prefix");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixStatus");
+      }
+    });
+  });
+
+  describe("4️⃣ Emergency Freeze", () => {
+    // Active prefix signed for by two authority keys
+    async function keyedPrefix(tag: string, keys: Keypair[]) {
+      const prefix = `${tag}${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(
+        ctx, prefix, owner, undefined, undefined, keys.map((k) => k.publicKey)
+      );
+      await approvePrefix(ctx, prefix, verifier);
+      return prefix;
+    }
+
+    it("Any authority key can freeze the prefix", async () => {
+      const hotKey = Keypair.generate();
+      const backupKey = Keypair.generate();
+      const prefix = await keyedPrefix("FZA", [hotKey, backupKey]);
+
+      await emergencyFreeze(ctx, prefix, backupKey);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("frozen");
+      expect(prefixAccount.frozenBy.toString()).to.equal(backupKey.publicKey.toString());
+      expect(await sdk.isPrefixTrusted(prefix)).to.be.false;
+    });
+
+    it("Owner-paused prefixes can be frozen too", async () => {
+      const hotKey = Keypair.generate();
+      const prefix = await keyedPrefix("FZP", [hotKey]);
+      await pausePrefix(ctx, prefix, owner);
+
+      await emergencyFreeze(ctx, prefix, hotKey);
+      expect((await fetchPrefixAccount(ctx, prefix)).status).to.have.property("frozen");
+    });
+
+    it("Unfreezing requires rotating the freezing key out first", async () => {
+      const hotKey = Keypair.generate();
+      const backupKey = Keypair.generate();
+      const prefix = await keyedPrefix("FZU", [hotKey, backupKey]);
+      await emergencyFreeze(ctx, prefix, hotKey);
+
+      try {
+        await unfreezePrefix(ctx, prefix, owner);
+        expect.fail("Should have failed with the freezing key still authorized");
+      } catch (error) {
+        expect(error.message).to.include("FreezingKeyStillAuthorized");
+      }
+
+      await updatePrefixAuthority(ctx, prefix, owner, [backupKey.publicKey]);
+      await unfreezePrefix(ctx, prefix, owner);
+
+      const prefixAccount = await fetchPrefixAccount(ctx, prefix);
+      expect(prefixAccount.status).to.have.property("active");
+      expect(prefixAccount.frozenBy).to.be.null;
+    });
+
+    it("Admin reactivation restores the frozen or paused status", async () => {
+      const hotKey = Keypair.generate();
+      const frozen = await keyedPrefix("FZR", [hotKey]);
+      await emergencyFreeze(ctx, frozen, hotKey);
+      await deactivatePrefix(ctx, frozen);
+      await reactivatePrefix(ctx, frozen);
+
+      // The leaked key is still authorized, so only the owner's unfreeze can follow
+      let prefixAccount = await fetchPrefixAccount(ctx, frozen);
+      expect(prefixAccount.status).to.have.property("frozen");
+      expect(prefixAccount.frozenBy.toString()).to.equal(hotKey.publicKey.toString());
+      expect(prefixAccount.deactivatedFrom).to.be.null;
+      expect(await sdk.isPrefixTrusted(frozen)).to.be.false;
+
+      const paused = await activePrefix("FZQ");
+      await pausePrefix(ctx, paused, owner);
+      await deactivatePrefix(ctx, paused);
+      prefixAccount = await fetchPrefixAccount(ctx, paused);
+      expect(prefixAccount.deactivatedFrom).to.have.property("ownerPaused");
+      await reactivatePrefix(ctx, paused);
+      expect((await fetchPrefixAccount(ctx, paused)).status).to.have.property("ownerPaused");
+    });
+
+    it("Should fail when a non-authority key freezes", async () => {
+      const hotKey = Keypair.generate();
+      const prefix = await keyedPrefix("FZN", [hotKey]);
+
+      try {
+        await emergencyFreeze(ctx, prefix, owner);
+        expect.fail("Should have failed with an unknown key");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAuthorityKey");
+      }
+    });
+
+    it("Should fail when a non-owner unfreezes", async () => {
+      const hotKey = Keypair.generate();
+      const prefix = await keyedPrefix("FZO", [hotKey]);
+      await emergencyFreeze(ctx, prefix, hotKey);
+      await updatePrefixAuthority(ctx, prefix, owner, []);
+
+      try {
+        await unfreezePrefix(ctx, prefix, hotKey);
+        expect.fail("Should have failed for a non-owner");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedOwnerAction");
+      }
+    });
+  });
+
+  describe("5️⃣ Revocation", () => {
+    function tombstonePDA(prefix: string) {
+      return deriveSkeletonKeyedPDA(ctx.program.programId, TOMBSTONE_SEED, prefix);
+    }
+
+    it("Revoking closes the prefix and blocks the name and its confusables", async () => {
+      const prefix = await activePrefix("RVO");
+      await revokePrefix(ctx, prefix, owner.publicKey, "Impersonation");
+
+      expect(await ctx.connection.getAccountInfo(await derivePrefixPDA(ctx.program.programId, prefix))).to.be.null;
+      // The audit log stays open so the revocation remains on record
+      expect(
+        await ctx.connection.getAccountInfo(deriveAuditLogPDA(ctx.program.programId, prefix))
+      ).to.not.be.null;
+      expect(await sdk.isPrefixTrusted(prefix)).to.be.false;
+
+      const tombstone = await ctx.program.account.tombstone.fetch(tombstonePDA(prefix));
+      expect(tombstone.prefix).to.equal(prefix);
+      expect(tombstone.previousOwner.toString()).to.equal(owner.publicKey.toString());
+      expect(tombstone.reason).to.equal("Impersonation");
+      expect(tombstone.blockedUntil.toString()).to.equal(I64_MAX);
+      expect(tombstone.revokedBy.toString()).to.equal(admin.publicKey.toString());
+
+      try {
+        await submitPrefixWithFee(ctx, prefix, owner);
+        expect.fail("Should have failed on a revoked name");
+      } catch (error) {
+        expect(error.message).to.include("PrefixRevoked");
+      }
+
+      try {
+        await submitPrefixWithFee(ctx, prefix.replace("RVO", "RV0"), owner);
+        expect.fail("Should have failed on a confusable of a revoked name");
+      } catch (error) {
+        expect(error.message).to.include("PrefixRevoked");
+      }
+    });
+
+    it("A name can be registered again once its cooldown lapses", async () => {
+      const prefix = await activePrefix("RVC");
+      await revokePrefix(ctx, prefix, owner.publicKey, "Dispute", 2);
+
+      const tombstone = await ctx.program.account.tombstone.fetch(tombstonePDA(prefix));
+      expect(tombstone.blockedUntil.toNumber() - tombstone.createdAt.toNumber()).to.equal(2);
+
+      try {
+        await submitPrefixWithFee(ctx, prefix, owner);
+        expect.fail("Should have failed during the cooldown");
+      } catch (error) {
+        expect(error.message).to.include("PrefixRevoked");
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      await submitPrefixWithFee(ctx, prefix, owner);
+      expect((await fetchPrefixAccount(ctx, prefix)).status).to.deep.equal({ pending: {} });
+    });
+
+    it("Revoking a listed prefix closes the listing", async () => {
+      const prefix = await activePrefix("RVL");
+      await listPrefix(ctx, prefix, owner, 1_000_000_000);
+
+      try {
+        await revokePrefix(ctx, prefix, owner.publicKey);
+        expect.fail("Should have failed without the listing");
+      } catch (error) {
+        expect(error.message).to.include("PrefixListed");
+      }
+
+      await revokePrefix(ctx, prefix, owner.publicKey, "Impersonation", null, owner.publicKey);
+      expect(
+        await ctx.connection.getAccountInfo(deriveListingPDA(ctx.program.programId, prefix))
+      ).to.be.null;
+    });
+
+    it("Should fail with a bad reason, a zero cooldown or a non-admin signer", async () => {
+      const prefix = await activePrefix("RVE");
+
+      try {
+        await revokePrefix(ctx, prefix, owner.publicKey, "x".repeat(101));
+        expect.fail("Should have failed with an oversized reason");
+      } catch (error) {
+        expect(error.message).to.include("RevocationReasonTooLong");
+      }
+
+      try {
+        await revokePrefix(ctx, prefix, owner.publicKey, "Impersonation", 0);
+        expect.fail("Should have failed with a zero cooldown");
+      } catch (error) {
+        expect(error.message).to.include("InvalidRevocationCooldown");
+      }
+
+      try {
+        await revokePrefix({ ...ctx, admin: owner }, prefix, owner.publicKey);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+});
@@ -0,0 +1,389 @@
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import {
+  TestContext,
+  airdrop,
+  addVerifier,
+  removeVerifier,
+  migrateVerifier,
+  getVerifierRecord,
+  deriveVerifierRecordPDA,
+  deriveVerifierVaultPDA,
+  depositVerifierBond,
+  setVerifierBondPolicy,
+  slashVerifier,
+  resignVerifier,
+  withdrawVerifierBond,
+  submitPrefixWithFee,
+  approvePrefix,
+  rejectPrefix,
+  claimReview,
+  releaseReview,
+  getTreasuryBalance,
+  setVerifierScope,
+  INTENT_PAYMENT,
+  INTENT_STAKE,
+  DEFAULT_UNBONDING_PERIOD,
+} from "./helpers/setup";
+import { getSharedTestContext } from "./helpers/shared-setup";
+
+describe("Verifier Tests", () => {
+  let ctx: TestContext;
+  let owner: Keypair;
+  let verifier: Keypair;
+
+  before(async () => {
+    const shared = await getSharedTestContext();
+    ctx = shared.ctx;
+    owner = shared.owner;
+    verifier = shared.verifier;
+
+    await airdrop(ctx.provider, owner.publicKey, 10);
+  });
+
+  afterEach(async () => {
+    // Reset bond policy after each test
+    try {
+      await setVerifierBondPolicy(ctx, 0, DEFAULT_UNBONDING_PERIOD);
+    } catch (error) {
+      // Ignore errors if already at correct state
+    }
+  });
+
+  // Funded verifier so it can post a bond
+  async function newVerifier(label: string) {
+    const fresh = Keypair.generate();
+    await airdrop(ctx.provider, fresh.publicKey, 5);
+    await addVerifier(ctx, fresh.publicKey, label);
+    return fresh;
+  }
+
+  describe("1️⃣ Verifier Records", () => {
+    it("Should store each verifier in its own record PDA", async () => {
+      const newVerifier = Keypair.generate();
+      await addVerifier(ctx, newVerifier.publicKey, "records");
+
+      const recordPDA = deriveVerifierRecordPDA(
+        ctx.program.programId,
+        newVerifier.publicKey
+      );
+      const info = await ctx.connection.getAccountInfo(recordPDA);
+      expect(info).to.not.be.null;
+      expect(info!.owner.toString()).to.equal(ctx.program.programId.toString());
+
+      const record = await getVerifierRecord(ctx, newVerifier.publicKey);
+      expect(record.verifier.toString()).to.equal(newVerifier.publicKey.toString());
+      expect(record.label).to.equal("records");
+      expect(record.active).to.be.true;
+      expect(record.addedAt.toNumber()).to.be.greaterThan(0);
+      expect(record.removedAt).to.be.null;
+    });
+
+    it("Removing a verifier keeps its record with removed_at set", async () => {
+      const newVerifier = Keypair.generate();
+      await addVerifier(ctx, newVerifier.publicKey);
+      await removeVerifier(ctx, newVerifier.publicKey);
+
+      const record = await getVerifierRecord(ctx, newVerifier.publicKey);
+      expect(record.active).to.be.false;
+      expect(record.removedAt).to.not.be.null;
+      expect(record.removedAt.toNumber()).to.be.at.least(record.addedAt.toNumber());
+    });
+
+    it("Should fail with a label longer than 32 characters", async () => {
+      const newVerifier = Keypair.generate();
+
+      try {
+        await addVerifier(ctx, newVerifier.publicKey, "L".repeat(33));
+        expect.fail("Should have failed with a long label");
+      } catch (error) {
+        expect(error.message).to.include("InvalidVerifierLabel");
+      }
+    });
+
+    it("Should fail to migrate when no legacy verifiers list exists", async () => {
+      try {
+        await migrateVerifier(ctx, verifier.publicKey);
+        expect.fail("Should have failed without a legacy list");
+      } catch (error) {
+        expect(error.message).to.include("AccountNotInitialized");
+      }
+    });
+  });
+
+  describe("2️⃣ Verifier Bonds", () => {
+    const SOL = 1_000_000_000;
+
+    it("Depositing adds to the record bond and the vault", async () => {
+      const bonded = await newVerifier("bonded");
+      await depositVerifierBond(ctx, bonded, SOL);
+
+      const record = await getVerifierRecord(ctx, bonded.publicKey);
+      expect(record.bond.toNumber()).to.equal(SOL);
+      const vault = await ctx.connection.getBalance(
+        deriveVerifierVaultPDA(ctx.program.programId, bonded.publicKey)
+      );
+      expect(vault).to.be.at.least(SOL);
+    });
+
+    it("Verifiers below the minimum bond cannot vote", async () => {
+      const bonded = await newVerifier("bonded");
+      const prefix = `BND${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await setVerifierBondPolicy(ctx, SOL);
+
+      try {
+        await approvePrefix(ctx, prefix, bonded);
+        expect.fail("Should have failed without a bond");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientVerifierBond");
+      }
+
+      await depositVerifierBond(ctx, bonded, SOL);
+      await approvePrefix(ctx, prefix, bonded);
+    });
+
+    it("Admin can slash a bond into the treasury", async () => {
+      const bonded = await newVerifier("slashed");
+      await depositVerifierBond(ctx, bonded, SOL);
+
+      const treasuryBefore = await getTreasuryBalance(ctx);
+      await slashVerifier(ctx, bonded.publicKey, SOL / 4);
+      const treasuryAfter = await getTreasuryBalance(ctx);
+
+      expect(treasuryAfter - treasuryBefore).to.equal(SOL / 4);
+      const record = await getVerifierRecord(ctx, bonded.publicKey);
+      expect(record.bond.toNumber()).to.equal((SOL * 3) / 4);
+    });
+
+    it("Should fail to slash more than the bond or with a long reason", async () => {
+      const bonded = await newVerifier("slashed");
+      await depositVerifierBond(ctx, bonded, SOL);
+
+      try {
+        await slashVerifier(ctx, bonded.publicKey, SOL + 1);
+        expect.fail("Should have failed with an oversized slash");
+      } catch (error) {
+        expect(error.message).to.include("InvalidSlashAmount");
+      }
+
+      try {
+        await slashVerifier(ctx, bonded.publicKey, 1, "R".repeat(201));
+        expect.fail("Should have failed with a long reason");
+      } catch (error) {
+        expect(error.message).to.include("SlashReasonTooLong");
+      }
+    });
+
+    it("Should fail when non-admin slashes", async () => {
+      const bonded = await newVerifier("slashed");
+      await depositVerifierBond(ctx, bonded, SOL);
+
+      try {
+        await slashVerifier({ ...ctx, admin: bonded }, bonded.publicKey, 1);
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+
+    it("Bond stays locked until the verifier leaves and unbonding passes", async () => {
+      const bonded = await newVerifier("unbonding");
+      await depositVerifierBond(ctx, bonded, SOL);
+
+      try {
+        await withdrawVerifierBond(ctx, bonded);
+        expect.fail("Should have failed while active");
+      } catch (error) {
+        expect(error.message).to.include("BondStillLocked");
+      }
+
+      await resignVerifier(ctx, bonded);
+      const record = await getVerifierRecord(ctx, bonded.publicKey);
+      expect(record.active).to.be.false;
+      expect(record.removedAt).to.not.be.null;
+
+      // Still bonded, but no longer a verifier
+      const prefix = `BNR${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      try {
+        await claimReview(ctx, prefix, bonded);
+        expect.fail("Should have failed after resigning");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedVerifier");
+      }
+
+      try {
+        await withdrawVerifierBond(ctx, bonded);
+        expect.fail("Should have failed during unbonding");
+      } catch (error) {
+        expect(error.message).to.include("BondStillLocked");
+      }
+
+      await setVerifierBondPolicy(ctx, 0, 0);
+      const balanceBefore = await ctx.connection.getBalance(bonded.publicKey);
+      await withdrawVerifierBond(ctx, bonded);
+      const balanceAfter = await ctx.connection.getBalance(bonded.publicKey);
+
+      expect(balanceAfter - balanceBefore).to.be.greaterThan(SOL - 10_000);
+      expect((await getVerifierRecord(ctx, bonded.publicKey)).bond.toNumber()).to.equal(0);
+    });
+
+    it("Should fail to resign while holding a review claim", async () => {
+      const bonded = await newVerifier("claimer");
+      const prefix = `BNC${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await claimReview(ctx, prefix, bonded);
+
+      try {
+        await resignVerifier(ctx, bonded);
+        expect.fail("Should have failed with a live claim");
+      } catch (error) {
+        expect(error.message).to.include("VerifierClaimActive");
+      }
+
+      await releaseReview(ctx, prefix, bonded);
+      await resignVerifier(ctx, bonded);
+    });
+  });
+
+  describe("3️⃣ Verifier Statistics", () => {
+    it("A new verifier starts with empty statistics", async () => {
+      const fresh = await newVerifier("stats");
+
+      const record = await getVerifierRecord(ctx, fresh.publicKey);
+      expect(record.approvals.toNumber()).to.equal(0);
+      expect(record.rejections.toNumber()).to.equal(0);
+      expect(record.totalReviewSecs.toNumber()).to.equal(0);
+      expect(record.lastActionAt.toNumber()).to.equal(0);
+    });
+
+    it("Votes update approvals, rejections and review time", async () => {
+      const fresh = await newVerifier("stats");
+      const approved = `STA${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const rejected = `STR${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, approved, owner);
+      await submitPrefixWithFee(ctx, rejected, owner);
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await approvePrefix(ctx, approved, fresh);
+      await rejectPrefix(ctx, rejected, fresh);
+
+      const record = await getVerifierRecord(ctx, fresh.publicKey);
+      expect(record.approvals.toNumber()).to.equal(1);
+      expect(record.rejections.toNumber()).to.equal(1);
+      expect(record.totalReviewSecs.toNumber()).to.be.greaterThan(0);
+      expect(record.lastActionAt.toNumber()).to.be.at.least(record.addedAt.toNumber());
+    });
+
+    it("Failed votes leave the statistics untouched", async () => {
+      const fresh = await newVerifier("stats");
+      const prefix = `STF${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await submitPrefixWithFee(ctx, prefix, owner);
+      await approvePrefix(ctx, prefix, verifier);
+
+      try {
+        await approvePrefix(ctx, prefix, fresh);
+        expect.fail("Should have failed on an active prefix");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPrefixStatus");
+      }
+
+      const record = await getVerifierRecord(ctx, fresh.publicKey);
+      expect(record.approvals.toNumber()).to.equal(0);
+    });
+  });
+
+  describe("4️⃣ Verifier Scope", () => {
+    it("A new verifier may review any prefix", async () => {
+      const fresh = await newVerifier("unscoped");
+
+      const record = await getVerifierRecord(ctx, fresh.publicKey);
+      expect(record.scope.categoryMask).to.equal(0);
+      expect(record.scope.minLen).to.equal(0);
+      expect(record.scope.maxLen).to.equal(0);
+      expect(record.scope.patterns).to.have.length(0);
+    });
+
+    it("Length bounds limit which prefixes a verifier may vote on", async () => {
+      const scoped = await newVerifier("short names");
+      const shortPrefix = `S${Date.now().toString().slice(-3)}`;
+      const longPrefix = `SCL${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await setVerifierScope(ctx, scoped.publicKey, {
+        categoryMask: 0,
+        minLen: 3,
+        maxLen: 4,
+        patterns: [],
+      });
+      await submitPrefixWithFee(ctx, shortPrefix, owner);
+      await submitPrefixWithFee(ctx, longPrefix, owner);
+
+      try {
+        await approvePrefix(ctx, longPrefix, scoped);
+        expect.fail("Should have failed outside the length scope");
+      } catch (error) {
+        expect(error.message).to.include("OutOfVerifierScope");
+      }
+
+      await approvePrefix(ctx, shortPrefix, scoped);
+    });
+
+    it("Category and pattern scopes must both match", async () => {
+      const scoped = await newVerifier("stake desk");
+      const staking = `SCP${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const payments = `SCQ${Date.now().toString().slice(-6)}`.substring(0, 12);
+      const otherPattern = `SCX${Date.now().toString().slice(-6)}`.substring(0, 12);
+      await setVerifierScope(ctx, scoped.publicKey, {
+        categoryMask: INTENT_STAKE,
+        minLen: 0,
+        maxLen: 0,
+        patterns: ["SCP", "SCQ"],
+      });
+      await submitPrefixWithFee(ctx, staking, owner, undefined, undefined, [], INTENT_STAKE);
+      await submitPrefixWithFee(ctx, payments, owner, undefined, undefined, [], INTENT_PAYMENT);
+      await submitPrefixWithFee(ctx, otherPattern, owner, undefined, undefined, [], INTENT_STAKE);
+
+      for (const prefix of [payments, otherPattern]) {
+        try {
+          await rejectPrefix(ctx, prefix, scoped);
+          expect.fail("Should have failed outside the scope");
+        } catch (error) {
+          expect(error.message).to.include("OutOfVerifierScope");
+        }
+      }
+
+      await approvePrefix(ctx, staking, scoped);
+    });
+
+    it("Should fail with an invalid scope or a non-admin signer", async () => {
+      const scoped = await newVerifier("invalid scope");
+      const invalidScopes = [
+        { categoryMask: 1 << 10, minLen: 0, maxLen: 0, patterns: [] },
+        { categoryMask: 0, minLen: 6, maxLen: 4, patterns: [] },
+        { categoryMask: 0, minLen: 0, maxLen: 0, patterns: ["A", "B", "C", "D", "E"] },
+        { categoryMask: 0, minLen: 0, maxLen: 0, patterns: [""] },
+      ];
+
+      for (const scope of invalidScopes) {
+        try {
+          await setVerifierScope(ctx, scoped.publicKey, scope);
+          expect.fail("Should have failed with an invalid scope");
+        } catch (error) {
+          expect(error.message).to.include("InvalidVerifierScope");
+        }
+      }
+
+      try {
+        await setVerifierScope({ ...ctx, admin: scoped }, scoped.publicKey, {
+          categoryMask: 0,
+          minLen: 0,
+          maxLen: 0,
+          patterns: [],
+        });
+        expect.fail("Should have failed with unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+});